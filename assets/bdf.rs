@@ -0,0 +1,131 @@
+//! Reads the BDF bitmap fonts in `assets/` for the pack builder. Only what the
+//! pack needs is kept: the font's ascent, descent, default character and bits
+//! per pixel, and for each glyph its code point, advance, bounding box and
+//! pixels.
+//!
+//! Anti-aliased fonts are BDF 2.3 greymaps, with the bits per pixel (2 or 4)
+//! after the resolution on the SIZE line and each pixel a level from 0 for
+//! none of the glyph to the largest for all of it.
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Path;
+
+pub struct Font {
+    pub ascent: i32,
+    pub descent: i32,
+    pub default_char: u32,
+    pub bits_per_pixel: u32,
+    pub glyphs: Vec<Glyph>,
+}
+
+pub struct Glyph {
+    pub code: u32,
+    pub advance: i32,
+    pub width: i32,
+    pub height: i32,
+    pub x_offset: i32,
+    // From the baseline up to the bottom of the bounding box
+    pub y_offset: i32,
+    // A level per pixel, a row at a time from the top
+    pub pixels: Vec<u8>,
+}
+
+pub fn read(path: &Path) -> Font {
+    let source = fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+    let mut ascent = None;
+    let mut descent = None;
+    let mut default_char = None;
+    let mut bits_per_pixel = 1;
+    let mut glyphs = Vec::new();
+    let mut glyph: Option<Glyph> = None;
+    let mut in_bitmap = false;
+
+    for (number, line) in source.lines().enumerate() {
+        let fail = |message: &str| -> ! { panic!("{}:{}: {}", path.display(), number + 1, message) };
+        let mut words = line.split_whitespace();
+        let keyword = words.next().unwrap_or_default();
+        let values: Vec<i32> = words.filter_map(|word| word.parse().ok()).collect();
+        let value = |index: usize| values.get(index).copied().unwrap_or_else(|| fail("missing value"));
+
+        if keyword == "STARTCHAR" {
+            glyph = Some(Glyph {
+                code: u32::MAX,
+                advance: 0,
+                width: 0,
+                height: 0,
+                x_offset: 0,
+                y_offset: 0,
+                pixels: Vec::new(),
+            });
+            continue;
+        }
+        if keyword == "SIZE" {
+            bits_per_pixel = values.get(3).map_or(1, |bits| *bits as u32);
+            if ![1, 2, 4].contains(&bits_per_pixel) {
+                fail("bits per pixel must be 1, 2 or 4");
+            }
+            continue;
+        }
+        if keyword == "FONT_ASCENT" || keyword == "FONT_DESCENT" || keyword == "DEFAULT_CHAR" {
+            match keyword {
+                "FONT_ASCENT" => ascent = Some(value(0)),
+                "FONT_DESCENT" => descent = Some(value(0)),
+                _ => default_char = Some(value(0) as u32),
+            }
+            continue;
+        }
+
+        let Some(current) = glyph.as_mut() else {
+            continue;
+        };
+        match keyword {
+            // Unencoded glyphs (ENCODING -1) cannot be reached from text
+            "ENCODING" => current.code = u32::try_from(value(0)).unwrap_or(u32::MAX),
+            "DWIDTH" => current.advance = value(0),
+            "BBX" => {
+                current.width = value(0);
+                current.height = value(1);
+                current.x_offset = value(2);
+                current.y_offset = value(3);
+            }
+            "BITMAP" => in_bitmap = true,
+            "ENDCHAR" => {
+                if current.pixels.len() != (current.width * current.height) as usize {
+                    fail("bitmap does not match BBX");
+                }
+                glyphs.extend(glyph.take().filter(|glyph| glyph.code != u32::MAX));
+                in_bitmap = false;
+            }
+            row if in_bitmap => {
+                push_row(&mut current.pixels, row, current.width, bits_per_pixel)
+                    .unwrap_or_else(|| fail("bad bitmap row"));
+            }
+            _ => (),
+        }
+    }
+
+    let ascent = ascent.unwrap_or_else(|| panic!("{}: no FONT_ASCENT", path.display()));
+    let descent = descent.unwrap_or_else(|| panic!("{}: no FONT_DESCENT", path.display()));
+    let default_char = default_char.unwrap_or(u32::from(b'?'));
+
+    glyphs.sort_by_key(|glyph| glyph.code);
+    Font { ascent, descent, default_char, bits_per_pixel, glyphs }
+}
+
+// Rows are hex, MSB first and padded out to whole bytes
+fn push_row(pixels: &mut Vec<u8>, row: &str, width: i32, bits_per_pixel: u32) -> Option<()> {
+    let bits = width as usize * bits_per_pixel as usize;
+    if !row.len().is_multiple_of(2) || row.len() * 4 < bits {
+        return None;
+    }
+
+    let mask = (1u32 << bits_per_pixel) - 1;
+    for bit in (0..bits).step_by(bits_per_pixel as usize) {
+        let byte = u32::from_str_radix(row.get(bit / 8 * 2..bit / 8 * 2 + 2)?, 16).ok()?;
+        let shift = 8 - bits_per_pixel - (bit % 8) as u32;
+        pixels.push(((byte >> shift) & mask) as u8);
+    }
+    Some(())
+}
@@ -0,0 +1,343 @@
+//! Builds the asset pack that is installed at `ASSETS_FLASH_ADDRESS` in the
+//! SPI flash, `images.rs` with an `Image` constant for each PNG in `assets/`
+//! and `fonts.rs` with a `Font` constant for each BDF font there. Shared by
+//! the firmware and simulator build scripts, the layout must match
+//! `src/drivers/assets.rs`.
+//!
+//! PNGs become big-endian RGB565. Pixels that are less than half opaque are
+//! set to `KEY_COLOR` and their image is keyed, so they are skipped when it is
+//! drawn. Opaque pixels that come out as the key color have the blue LSB
+//! cleared, one step less blue, to keep them visible. Images are run-length
+//! encoded whenever that makes them smaller, the packets are described in
+//! `lcd_api.rs`.
+//!
+//! All values are little-endian:
+//!   header    magic "AST1", asset count, pack size, CRC-32 of the rest
+//!   index     24 bytes per asset: name (zero padded to 16 bytes), offset of
+//!             the asset from the start of the pack, size of its data
+//!   assets    8 byte header (width, height, pixel format, compression and 2
+//!             reserved bytes) then the data, each on a 4 byte boundary
+//!
+//! The data size in the index is after compression.
+//!
+//! A font is as wide as its widest advance and as tall as a line. Its data is:
+//!   header    first code point (u16), number of code points (u16), code point
+//!             drawn for those the font lacks (u16), ascent (u8), descent (u8)
+//!   glyphs    10 bytes per code point: offset of the bitmap from the start of
+//!             the data (u32), width, height, x offset from the pen (i8), rows
+//!             from the top of the line, advance and a reserved byte. An
+//!             advance of 0 means the font has no glyph for it.
+//!   bitmaps   1, 2 or 4 bits per pixel as the format says, MSB first, bits
+//!             run on across rows, each glyph starting on a byte. Anti-aliased
+//!             pixels go from 0 for none of the glyph to all ones for all of it.
+
+#[allow(dead_code)]
+#[path = "../src/drivers/crc.rs"]
+mod crc;
+#[path = "bdf.rs"]
+mod bdf;
+#[path = "fonts.rs"]
+mod fonts;
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAGIC: u32 = 0x3154_5341;
+const HEADER_SIZE: usize = 16;
+const ENTRY_SIZE: usize = 24;
+const NAME_SIZE: usize = 16;
+const KEY_COLOR: u16 = 0xF81F;
+const RLE_MAX: usize = 128;
+const FONT_HEADER_SIZE: usize = 8;
+const GLYPH_SIZE: usize = 10;
+
+#[derive(Clone, Copy)]
+enum Format {
+    Rgb565 = 0,
+    Mono1 = 1,
+    Font1 = 2,
+    Font2 = 3,
+    Font4 = 4,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Compression {
+    None = 0,
+    Rle = 1,
+}
+
+pub struct Pack {
+    pub data: Vec<u8>,
+    pub images: String,
+    pub fonts: String,
+}
+
+struct Asset {
+    name: String,
+    width: u16,
+    height: u16,
+    format: Format,
+    compression: Compression,
+    data: Vec<u8>,
+}
+
+pub fn build(dir: &Path) -> Pack {
+    let mut assets = Vec::new();
+    let mut images = String::new();
+    let mut fonts = String::new();
+
+    for path in get_files(dir, "png") {
+        let name = get_name(&path);
+        let (width, height, data, keyed) = read_png(&path);
+        let key = if keyed { format!("Some({:#06X})", KEY_COLOR) } else { String::from("None") };
+
+        images.push_str("#[allow(dead_code)]\n");
+        images.push_str(&format!(
+            "pub const {}: Image = Image::new(b\"{}\", {}, {}, {});\n",
+            name.to_uppercase(), name, width, height, key
+        ));
+        let rle = encode_rle(&data);
+        let (compression, data) = if rle.len() < data.len() {
+            (Compression::Rle, rle)
+        } else {
+            (Compression::None, data)
+        };
+        assets.push(Asset { name, width, height, format: Format::Rgb565, compression, data });
+    }
+
+    for path in get_files(dir, "bdf") {
+        let name = get_name(&path);
+        let font = bdf::read(&path);
+
+        fonts.push_str("#[allow(dead_code)]\n");
+        fonts.push_str(&format!(
+            "pub const {}: Font = Font::new(b\"{}\", {}, {});\n",
+            name.to_uppercase(), name, font.ascent, font.descent
+        ));
+        assets.push(encode_font(&path, name, &font));
+    }
+
+    assets.push(Asset {
+        name: String::from("time_digits"),
+        width: 40,
+        height: 640,
+        format: Format::Mono1,
+        compression: Compression::None,
+        data: fonts::TIME_DIGITS.concat(),
+    });
+
+    Pack { data: encode(&assets), images, fonts }
+}
+
+fn encode(assets: &[Asset]) -> Vec<u8> {
+    let mut pack = vec![0u8; HEADER_SIZE + assets.len() * ENTRY_SIZE];
+
+    for (i, asset) in assets.iter().enumerate() {
+        assert!(asset.name.len() <= NAME_SIZE, "asset name {} is too long", asset.name);
+        if asset.compression == Compression::None {
+            assert_eq!(asset.data.len(), get_data_size(asset), "asset {} has the wrong size", asset.name);
+        }
+
+        while !pack.len().is_multiple_of(4) {
+            pack.push(0);
+        }
+        let offset = pack.len();
+        pack.extend_from_slice(&asset.width.to_le_bytes());
+        pack.extend_from_slice(&asset.height.to_le_bytes());
+        pack.extend_from_slice(&[asset.format as u8, asset.compression as u8, 0, 0]);
+        pack.extend_from_slice(&asset.data);
+
+        let entry = &mut pack[HEADER_SIZE + (i * ENTRY_SIZE)..][..ENTRY_SIZE];
+        entry[..asset.name.len()].copy_from_slice(asset.name.as_bytes());
+        entry[16..20].copy_from_slice(&(offset as u32).to_le_bytes());
+        entry[20..24].copy_from_slice(&(asset.data.len() as u32).to_le_bytes());
+    }
+
+    let crc = crc::get_crc32(&pack[HEADER_SIZE..]);
+    let size = pack.len() as u32;
+    pack[0..4].copy_from_slice(&MAGIC.to_le_bytes());
+    pack[4..8].copy_from_slice(&(assets.len() as u32).to_le_bytes());
+    pack[8..12].copy_from_slice(&size.to_le_bytes());
+    pack[12..16].copy_from_slice(&crc.to_le_bytes());
+    pack
+}
+
+// Every code point from the first glyph to the last gets an entry, so the
+// firmware can find a glyph without searching
+fn encode_font(path: &Path, name: String, font: &bdf::Font) -> Asset {
+    let fail = |code: u32, message: &str| -> ! { panic!("{}: U+{:04X} {}", path.display(), code, message) };
+    let (first, last) = match (font.glyphs.first(), font.glyphs.last()) {
+        (Some(first), Some(last)) => (first.code, last.code),
+        _ => panic!("{}: no glyphs", path.display()),
+    };
+    let count = last - first + 1;
+    let line = font.ascent + font.descent;
+    if last > 0xFFFF || !(first..=last).contains(&font.default_char) {
+        panic!("{}: code points must be up to U+FFFF and include DEFAULT_CHAR", path.display());
+    }
+    if !(1..=0xFF).contains(&line) || font.ascent < 0 || font.descent < 0 {
+        panic!("{}: bad FONT_ASCENT or FONT_DESCENT", path.display());
+    }
+
+    let mut data = vec![0u8; FONT_HEADER_SIZE + count as usize * GLYPH_SIZE];
+    data[0..2].copy_from_slice(&(first as u16).to_le_bytes());
+    data[2..4].copy_from_slice(&(count as u16).to_le_bytes());
+    data[4..6].copy_from_slice(&(font.default_char as u16).to_le_bytes());
+    data[6] = font.ascent as u8;
+    data[7] = font.descent as u8;
+
+    let mut width = 0;
+    for glyph in &font.glyphs {
+        let top = font.ascent - (glyph.y_offset + glyph.height);
+        if top < 0 || top + glyph.height > line {
+            fail(glyph.code, "reaches outside of FONT_ASCENT and FONT_DESCENT");
+        }
+        if !(1..=0xFF).contains(&glyph.advance) || glyph.width > 0xFF || i8::try_from(glyph.x_offset).is_err() {
+            fail(glyph.code, "is too large");
+        }
+        width = width.max(glyph.advance);
+
+        let offset = data.len() as u32;
+        let bits = font.bits_per_pixel as usize;
+        for pixels in glyph.pixels.chunks(8 / bits) {
+            let mut byte = 0u8;
+            for (i, level) in pixels.iter().enumerate() {
+                byte |= level << (8 - bits - (i * bits));
+            }
+            data.push(byte);
+        }
+
+        let entry = &mut data[FONT_HEADER_SIZE + (glyph.code - first) as usize * GLYPH_SIZE..][..GLYPH_SIZE];
+        entry[0..4].copy_from_slice(&offset.to_le_bytes());
+        entry[4] = glyph.width as u8;
+        entry[5] = glyph.height as u8;
+        entry[6] = glyph.x_offset as i8 as u8;
+        entry[7] = top as u8;
+        entry[8] = glyph.advance as u8;
+    }
+
+    Asset {
+        name,
+        width: width as u16,
+        height: line as u16,
+        format: match font.bits_per_pixel {
+            1 => Format::Font1,
+            2 => Format::Font2,
+            _ => Format::Font4,
+        },
+        compression: Compression::None,
+        data,
+    }
+}
+
+// Repeated pixels become runs, anything else is copied out in literals
+fn encode_rle(data: &[u8]) -> Vec<u8> {
+    let pixels: Vec<&[u8]> = data.chunks(2).collect();
+    let mut encoded = Vec::new();
+    let mut literal_start = 0;
+    let mut i = 0;
+
+    while i < pixels.len() {
+        let run = pixels[i..].iter().take(RLE_MAX).take_while(|pixel| **pixel == pixels[i]).count();
+
+        // Two equal pixels cost no more as a run, three save a byte even when
+        // they cut a literal short
+        if run >= 3 || (run == 2 && literal_start == i) {
+            push_literal(&mut encoded, &pixels[literal_start..i]);
+            encoded.push(0x80 | (run - 1) as u8);
+            encoded.extend_from_slice(pixels[i]);
+            i += run;
+            literal_start = i;
+        } else {
+            i += 1;
+        }
+    }
+    push_literal(&mut encoded, &pixels[literal_start..]);
+    encoded
+}
+
+fn push_literal(encoded: &mut Vec<u8>, pixels: &[&[u8]]) {
+    for literal in pixels.chunks(RLE_MAX) {
+        encoded.push((literal.len() - 1) as u8);
+        for pixel in literal {
+            encoded.extend_from_slice(pixel);
+        }
+    }
+}
+
+// Sorted, so the pack comes out the same every time
+fn get_files(dir: &Path, extension: &str) -> Vec<PathBuf> {
+    let entries = fs::read_dir(dir).unwrap_or_else(|e| panic!("{}: {}", dir.display(), e));
+    let mut paths: Vec<PathBuf> = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == extension))
+        .collect();
+    paths.sort();
+    paths
+}
+
+// The file name without its extension names the asset, and in capitals its constant
+fn get_name(path: &Path) -> String {
+    let name = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+    let valid = name.len() <= NAME_SIZE
+        && name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid {
+        panic!("{}: name assets with up to {} of a-z, 0-9 and _", path.display(), NAME_SIZE);
+    }
+    name.to_string()
+}
+
+// Returns the width, height, RGB565 pixels and whether any are transparent
+fn read_png(path: &Path) -> (u16, u16, Vec<u8>, bool) {
+    let file = fs::File::open(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    let mut decoder = png::Decoder::new(file);
+    // Palettes, low bit depths and tRNS come out as 8 bit RGB(A) or gray(A)
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+    let mut data = Vec::with_capacity(info.width as usize * info.height as usize * 2);
+    let mut keyed = false;
+    for pixel in buffer[..info.buffer_size()].chunks(info.color_type.samples()) {
+        let (r, g, b, a) = match *pixel {
+            [l] => (l, l, l, 0xFF),
+            [l, a] => (l, l, l, a),
+            [r, g, b] => (r, g, b, 0xFF),
+            [r, g, b, a] => (r, g, b, a),
+            _ => unreachable!(),
+        };
+
+        let color = if a < 0x80 {
+            keyed = true;
+            KEY_COLOR
+        } else {
+            match get_rgb565(r, g, b) {
+                KEY_COLOR => KEY_COLOR - 1,
+                color => color,
+            }
+        };
+        data.extend_from_slice(&color.to_be_bytes());
+    }
+
+    let width = u16::try_from(info.width).expect("image too wide");
+    let height = u16::try_from(info.height).expect("image too tall");
+    (width, height, data, keyed)
+}
+
+fn get_rgb565(r: u8, g: u8, b: u8) -> u16 {
+    let scale = |value: u8, max: u32| ((value as u32 * max + 127) / 255) as u16;
+    (scale(r, 31) << 11) | (scale(g, 63) << 5) | scale(b, 31)
+}
+
+fn get_data_size(asset: &Asset) -> usize {
+    let pixels = asset.width as usize * asset.height as usize;
+    match asset.format {
+        Format::Rgb565 => pixels * 2,
+        // Bits run on from one row to the next
+        Format::Mono1 => pixels.div_ceil(8),
+        Format::Font1 | Format::Font2 | Format::Font4 => asset.data.len(),
+    }
+}
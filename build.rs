@@ -7,11 +7,24 @@
 //! Cargo re-run the build script whenever `memory.x` is changed,
 //! updating `memory.x` ensures a rebuild of the application with the
 //! new memory settings.
+//!
+//! It also writes `config.x` with the EasyDMA RAM window and the settings
+//! flash pages from `src/config.rs`, so the linker checks the `.dma` buffers
+//! and the program size against the same bounds the firmware uses at run time.
+//!
+//! Finally it builds `assets.bin`, the images and fonts the firmware reads from
+//! the SPI flash, from the PNGs, BDF fonts and glyphs in `assets/`. Only a
+//! build with the `install-assets` feature carries it, but every build gets
+//! `images.rs` with the size of each image and `fonts.rs` with the line metrics
+//! of each font.
 
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+#[path = "assets/pack.rs"]
+mod pack;
 
 fn main() {
     // Put `memory.x` in our output directory and ensure it's
@@ -21,11 +34,52 @@ fn main() {
         .unwrap()
         .write_all(include_bytes!("memory.x"))
         .unwrap();
+
+    let config = fs::read_to_string("src/config.rs").unwrap();
+    let mut linker_config = File::create(out.join("config.x")).unwrap();
+    for name in ["SPIM_DMA_MIN", "SPIM_DMA_MAX", "NVMC_SETTINGS_ADDRESS", "NVMC_SETTINGS_PAGES"] {
+        writeln!(linker_config, "{} = {:#010X};", name, get_u32_const(&config, name)).unwrap();
+    }
     println!("cargo:rustc-link-search={}", out.display());
 
+    let assets = pack::build(Path::new("assets"));
+    let assets_size = get_u32_const(&config, "ASSETS_FLASH_SIZE");
+    if assets.data.len() > assets_size as usize {
+        panic!("assets.bin is {} bytes, ASSETS_FLASH_SIZE is {}", assets.data.len(), assets_size);
+    }
+    fs::write(out.join("assets.bin"), &assets.data).unwrap();
+    fs::write(out.join("images.rs"), &assets.images).unwrap();
+    fs::write(out.join("fonts.rs"), &assets.fonts).unwrap();
+
     // By default, Cargo will re-run a build script whenever
     // any file in the project changes. By specifying `memory.x`
     // here, we ensure the build script is only re-run when
     // `memory.x` is changed.
     println!("cargo:rerun-if-changed=memory.x");
+    println!("cargo:rerun-if-changed=src/config.rs");
+    println!("cargo:rerun-if-changed=src/drivers/crc.rs");
+    println!("cargo:rerun-if-changed=assets");
+}
+
+// Finds `pub const NAME: u32 = <value>;` and parses the value
+fn get_u32_const(source: &str, name: &str) -> u32 {
+    let prefix = format!("pub const {}: u32", name);
+    let line = source
+        .lines()
+        .find(|l| l.trim_start().starts_with(&prefix))
+        .unwrap_or_else(|| panic!("src/config.rs does not define {}", name));
+
+    let value = line
+        .split('=')
+        .nth(1)
+        .and_then(|v| v.split(';').next())
+        .unwrap_or_else(|| panic!("src/config.rs: cannot read {}", name))
+        .trim()
+        .replace('_', "");
+
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed.unwrap_or_else(|_| panic!("src/config.rs: {} is not a number", name))
 }
@@ -1,10 +1,36 @@
 MEMORY
 {
   /* NOTE 1 K = 1 KiBi = 1024 bytes */
-  FLASH : ORIGIN = 0x00000000, LENGTH = 512K
-  RAM : ORIGIN = 0x20000000, LENGTH = 60K
+  /* The last 8K are left for the settings pages, see NVMC_SETTINGS_ADDRESS */
+  FLASH : ORIGIN = 0x00000000, LENGTH = 504K
+  RAM : ORIGIN = 0x20000000, LENGTH = 64K
 }
 
+/* SPIM_DMA_MIN, SPIM_DMA_MAX, NVMC_SETTINGS_ADDRESS and NVMC_SETTINGS_PAGES, */
+/* generated from src/config.rs by build.rs */
+INCLUDE config.x
+
+/* Page erases for the settings must never reach the program */
+ASSERT(ORIGIN(FLASH) + LENGTH(FLASH) <= NVMC_SETTINGS_ADDRESS &&
+  NVMC_SETTINGS_ADDRESS + (NVMC_SETTINGS_PAGES * 4096) <= 0x00080000, "
+ERROR(memory.x): FLASH overlaps the NVMC settings pages");
+
+/* EasyDMA can only reach data RAM. Buffers handed to a peripheral are marked */
+/* with `#[link_section = ".dma"]` so the linker places them here, next to the */
+/* other statics instead of at fixed addresses the stack could grow into. */
+SECTIONS {
+  .dma (NOLOAD) : ALIGN(4)
+  {
+    _sdma = .;
+    *(.dma .dma.*);
+    . = ALIGN(4);
+    _edma = .;
+  } > RAM
+} INSERT AFTER .bss;
+
+ASSERT(_sdma >= SPIM_DMA_MIN && _edma <= SPIM_DMA_MAX, "
+ERROR(memory.x): .dma buffers are outside of the EasyDMA RAM window");
+
 /* This is where the call stack will be allocated. */
 /* The stack is of the full descending type. */
 /* You may want to use this variable to locate the call stack and static
@@ -0,0 +1,22 @@
+//! Builds `assets.bin`, `images.rs` and `fonts.rs` from the shared asset
+//! sources. The simulated SPI flash starts out erased, so the simulator is
+//! built with `install-assets` and writes the pack at every start, as a
+//! freshly flashed watch would.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[path = "../assets/pack.rs"]
+mod pack;
+
+fn main() {
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let assets = pack::build(Path::new("../assets"));
+    fs::write(out.join("assets.bin"), &assets.data).unwrap();
+    fs::write(out.join("images.rs"), &assets.images).unwrap();
+    fs::write(out.join("fonts.rs"), &assets.fonts).unwrap();
+
+    println!("cargo:rerun-if-changed=../assets");
+    println!("cargo:rerun-if-changed=../src/drivers/crc.rs");
+}
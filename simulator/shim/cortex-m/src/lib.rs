@@ -0,0 +1,46 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// cortex-m-sim::lib.rs
+// Host stand-in for the small part of the cortex-m crate that the app and
+// drivers use. The simulator is single threaded, so a critical section is
+// simply a function call and `wfi` hands control back to the simulator.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use std::sync::OnceLock;
+
+//==============================================================================
+// Variables
+//==============================================================================
+static WFI_HOOK: OnceLock<fn()> = OnceLock::new();
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn set_wfi_hook(hook: fn()) {
+	let _ = WFI_HOOK.set(hook);
+}
+
+pub mod asm {
+	// Busy waits take no simulated time
+	pub fn delay(_cycles: u32) {}
+
+	pub fn wfi() {
+		if let Some(hook) = super::WFI_HOOK.get() {
+			hook();
+		}
+	}
+}
+
+pub mod interrupt {
+	pub use bare_metal::{CriticalSection, Mutex};
+
+	pub fn free<F, R>(f: F) -> R
+	where
+		F: FnOnce(&CriticalSection) -> R,
+	{
+		f(&unsafe { CriticalSection::new() })
+	}
+}
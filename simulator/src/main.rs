@@ -0,0 +1,65 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::main.rs
+// Host build of the watch. The app, drivers and config are the firmware
+// sources; only the mcu layer is replaced by simulated backends. The ST7789
+// command stream is rendered into a framebuffer and saved as PPM frames.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+#[path = "../../src/app/mod.rs"]
+mod app;
+use app::info;
+#[allow(dead_code)]
+#[path = "../../src/config.rs"]
+mod config;
+#[path = "../../src/drivers/mod.rs"]
+mod drivers;
+mod mcu;
+mod sim;
+
+//==============================================================================
+// Main
+//==============================================================================
+fn main() {
+	let options = match sim::Options::from_args() {
+		Ok(options) => options,
+		Err(e) => {
+			eprintln!("{}", e);
+			std::process::exit(2);
+		}
+	};
+	if let Err(e) = sim::init(options) {
+		eprintln!("{}", e);
+		std::process::exit(1);
+	}
+
+	init();
+
+	let mut device_info = info::DeviceInfo::take().unwrap();
+	drivers::settings::load(&mut device_info);
+
+	loop {
+		task_handler(&mut device_info);
+	};
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn init() {
+	mcu::init();
+	drivers::init();
+}
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+fn task_handler(d: &mut info::DeviceInfo) {
+	mcu::task_handler(d);
+	drivers::task_handler(d);
+
+	app::task_handler(d);
+}
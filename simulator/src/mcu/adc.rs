@@ -0,0 +1,32 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::adc.rs
+// The battery ADC returns whatever raw value the script last set.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::Cell;
+use cortex_m::interrupt::{free, Mutex};
+
+//==============================================================================
+// Variables
+//==============================================================================
+// Roughly 3.9 V through the battery divider
+static ADC_RAW: Mutex<Cell<u16>> = Mutex::new(Cell::new(221));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn get_busy() -> bool {
+	false
+}
+
+pub fn read_adc() -> u16 {
+	free(|cs| ADC_RAW.borrow(cs).get())
+}
+
+pub fn set_adc(raw: u16) {
+	free(|cs| ADC_RAW.borrow(cs).set(raw));
+}
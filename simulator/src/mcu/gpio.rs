@@ -0,0 +1,89 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::gpio.rs
+// Output pins are latched into OUT so the LCD backend can follow CS and DCX.
+// Input pins read from IN, which the script drives through `drive_input`.
+// Releasing the flash chip select ends the command the flash model is on.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::Cell;
+use cortex_m::interrupt::{free, Mutex};
+use super::input;
+use crate::config;
+use crate::sim::flash;
+
+use nrf52832_pac::p0::pin_cnf::DIR_A as DIR;
+use nrf52832_pac::p0::pin_cnf::PULL_A as PULL;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Copy, Clone, PartialEq)]
+pub enum PinState{
+	PinLow,
+	PinHigh
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+static OUT: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
+// Every input idles high, as if pulled up
+static IN: Mutex<Cell<u32>> = Mutex::new(Cell::new(0xFFFF_FFFF));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn drive_input(pin: u8, state: PinState) {
+	let previous = get_pin_state(pin);
+	free(|cs| {
+		let bits = IN.borrow(cs).get();
+		IN.borrow(cs).set(match state {
+			PinState::PinLow => bits & !(1 << pin),
+			PinState::PinHigh => bits | (1 << pin),
+		});
+	});
+
+	if previous != state {
+		input::pin_changed(pin, state);
+	}
+}
+
+pub fn get_output_state(pin: u8) -> PinState {
+	match free(|cs| OUT.borrow(cs).get()) & (1 << pin) {
+		0 => PinState::PinLow,
+		_ => PinState::PinHigh
+	}
+}
+
+pub fn get_pin_state(pin: u8) -> PinState {
+	match free(|cs| IN.borrow(cs).get()) & (1 << pin) {
+		0 => PinState::PinLow,
+		_ => PinState::PinHigh
+	}
+}
+
+#[allow(dead_code)]
+pub fn pin_disable(_pin: u8) {}
+
+pub fn pin_setup(pin: u8, _dir: DIR, state: PinState, _pull: PULL){
+	set_pin_state(pin, state);
+}
+
+pub fn set_pin_state(pin: u8, state: PinState){
+	if pin == config::FLASH_CS_PIN && state == PinState::PinHigh && get_output_state(pin) == PinState::PinLow {
+		flash::deselect();
+	}
+
+	free(|cs| {
+		let bits = OUT.borrow(cs).get();
+		OUT.borrow(cs).set(match state {
+			PinState::PinLow => bits & !(1 << pin),
+			PinState::PinHigh => bits | (1 << pin),
+		});
+	});
+}
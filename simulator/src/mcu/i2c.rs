@@ -0,0 +1,108 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::i2c.rs
+// The only device on the simulated bus is the touch controller. Its register
+// file is filled in by the script before the touch interrupt is raised.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use crate::config;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+pub enum I2cError {
+	AddressNack,
+	DataNack,
+	Handler,
+	Overrun,
+	Receive,
+	Stop,
+	Timeout,
+	Transmit
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const TOUCH_REGISTER_LEN: usize = 256;
+
+static TOUCH_REGISTERS: Mutex<RefCell<[u8; TOUCH_REGISTER_LEN]>> =
+	Mutex::new(RefCell::new([0; TOUCH_REGISTER_LEN]));
+static REGISTER_POINTER: Mutex<RefCell<usize>> = Mutex::new(RefCell::new(0));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn get_error_string(error: I2cError) -> &'static str {
+	match error {
+		I2cError::AddressNack => 	"i2c: address nack",
+		I2cError::DataNack => 		"i2c: data nack",
+		I2cError::Handler => 		"i2c: not initialized",
+		I2cError::Overrun => 		"i2c: rx overrun",
+		I2cError::Receive => 		"i2c: receive failed",
+		I2cError::Stop => 			"i2c: stop failed",
+		I2cError::Timeout => 		"i2c: timeout, bus reset",
+		I2cError::Transmit => 		"i2c: transmit failed",
+	}
+}
+
+#[allow(dead_code)]
+pub fn read(address: u8, buffer: &mut [u8]) -> Result<(), I2cError> {
+	if address != config::TOUCH_I2C_ADDRESS {
+		return Err(I2cError::AddressNack);
+	}
+
+	free(|cs| {
+		let registers = TOUCH_REGISTERS.borrow(cs).borrow();
+		let mut pointer = REGISTER_POINTER.borrow(cs).borrow_mut();
+		for byte in buffer.iter_mut() {
+			*byte = registers[*pointer % TOUCH_REGISTER_LEN];
+			*pointer += 1;
+		}
+	});
+
+	Ok(())
+}
+
+#[allow(dead_code)]
+pub fn write(address: u8, data: &[u8]) -> Result<(), I2cError> {
+	if address != config::TOUCH_I2C_ADDRESS {
+		return Err(I2cError::AddressNack);
+	}
+
+	// The first byte selects the register, the rest are written from there
+	if let Some((register, data)) = data.split_first() {
+		free(|cs| {
+			let mut registers = TOUCH_REGISTERS.borrow(cs).borrow_mut();
+			let mut pointer = REGISTER_POINTER.borrow(cs).borrow_mut();
+			*pointer = *register as usize;
+			for byte in data {
+				registers[*pointer % TOUCH_REGISTER_LEN] = *byte;
+				*pointer += 1;
+			}
+		});
+	}
+
+	Ok(())
+}
+
+pub fn write_then_read(address: u8, tx_buffer: &[u8], rx_buffer: &mut [u8]) -> Result<(), I2cError> {
+	write(address, tx_buffer)?;
+	read(address, rx_buffer)
+}
+
+pub fn set_touch_registers(register: usize, data: &[u8]) {
+	free(|cs| {
+		let mut registers = TOUCH_REGISTERS.borrow(cs).borrow_mut();
+		for (i, byte) in data.iter().enumerate() {
+			registers[(register + i) % TOUCH_REGISTER_LEN] = *byte;
+		}
+	});
+}
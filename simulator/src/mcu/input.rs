@@ -0,0 +1,90 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::input.rs
+// Mirrors the GPIOTE event map of the firmware. Edges come from
+// `gpio::drive_input` instead of the GPIOTE interrupt.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use heapless::{Deque, Vec};
+use nrf52832_pac::gpiote::config::POLARITY_A as POLARITY;
+use super::gpio;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy)]
+pub struct PinConfig {
+	pub pin: u8,
+	pub polarity: nrf52832_pac::gpiote::config::POLARITY_A,
+	#[allow(dead_code)]
+	pub pull: nrf52832_pac::p0::pin_cnf::PULL_A,
+	pub callback: &'static dyn Fn(),
+	pub real_time_callback: bool
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const EVENT_LEN: usize = 8;
+const INPUT_QUEUE_LEN: usize = 16;
+
+thread_local! {
+	// Callbacks are not Send, so the map lives with the single sim thread
+	static EVENT_MAP: RefCell<Vec<PinConfig, EVENT_LEN>> = const { RefCell::new(Vec::new()) };
+}
+static QUEUE: Mutex<RefCell<Deque<usize, INPUT_QUEUE_LEN>>> =
+	Mutex::new(RefCell::new(Deque::new()));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn init_pin(config: PinConfig) {
+	EVENT_MAP.with(|map| {
+		let mut map = map.borrow_mut();
+		if map.iter().all(|c| c.pin != config.pin) {
+			let _ = map.push(config);
+		}
+	});
+}
+
+pub fn pin_changed(pin: u8, state: gpio::PinState) {
+	let event = EVENT_MAP.with(|map| {
+		map.borrow().iter().position(|c| {
+			c.pin == pin && match c.polarity {
+				POLARITY::HITOLO => state == gpio::PinState::PinLow,
+				POLARITY::LOTOHI => state == gpio::PinState::PinHigh,
+				POLARITY::TOGGLE => true,
+				_ => false,
+			}
+		})
+	});
+
+	if let Some(e) = event {
+		let config = EVENT_MAP.with(|map| map.borrow()[e]);
+
+		// Real time callbacks run straight from the "interrupt"
+		if config.real_time_callback {
+			(config.callback)();
+		}
+		else {
+			free(|cs| {
+				let _ = QUEUE.borrow(cs).borrow_mut().push_back(e);
+			});
+		}
+	}
+}
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler() {
+	while let Some(e) = free(|cs| QUEUE.borrow(cs).borrow_mut().pop_front()) {
+		let config = EVENT_MAP.with(|map| map.borrow()[e]);
+		(config.callback)();
+	}
+}
@@ -0,0 +1,82 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::mod.rs
+// Host backends with the same interface as the firmware mcu module. The real
+// drivers and app are built on top of these unchanged.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+pub mod adc;
+pub mod gpio;
+pub mod i2c;
+pub mod input;
+#[path = "../../../src/mcu/instant.rs"]
+mod instant;
+pub mod nvmc;
+pub mod rtc;
+#[path = "../../../src/mcu/soft_timer.rs"]
+pub mod soft_timer;
+pub mod spi;
+pub mod spim;
+pub mod timer;
+
+use crate::app::info;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+pub enum McuState {
+	AdcBusy,
+	SpiBusy,
+	TimerBusy,
+
+	Idle
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn init() {
+	nvmc::init();
+	rtc::init();
+	timer::init();
+}
+
+pub fn get_busy() -> McuState {
+	if adc::get_busy() {
+		return McuState::AdcBusy;
+	}
+	if spim::get_busy() {
+		return McuState::SpiBusy;
+	}
+	if timer::get_busy() || soft_timer::get_busy() {
+		return McuState::TimerBusy;
+	}
+	McuState::Idle
+}
+
+pub fn restart() {
+	println!("restart requested by the app");
+	crate::sim::finish();
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(_d: &info::DeviceInfo) {
+	input::task_handler();
+	soft_timer::task_handler();
+}
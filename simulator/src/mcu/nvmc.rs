@@ -0,0 +1,154 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::nvmc.rs
+// The settings pages of internal flash, held in memory. Writes can only clear
+// bits like the real flash. With `--flash FILE` the pages are read from the
+// file at start up and written back after every change, so settings survive
+// from one run to the next.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::config;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+pub enum NvmcError {
+	Alignment,
+	NotInitialized,
+	OutOfRange
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+pub const PAGE_SIZE: u32 = 4096;
+pub const ERASED_WORD: u32 = 0xFFFF_FFFF;
+
+const REGION_START: u32 = config::NVMC_SETTINGS_ADDRESS;
+const REGION_WORDS: usize = ((config::NVMC_SETTINGS_PAGES * PAGE_SIZE) / 4) as usize;
+
+static FLASH: Mutex<RefCell<Vec<u32>>> = Mutex::new(RefCell::new(Vec::new()));
+static FLASH_FILE: Mutex<RefCell<Option<PathBuf>>> = Mutex::new(RefCell::new(None));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn init() {
+	free(|cs| {
+		let mut flash = FLASH.borrow(cs).borrow_mut();
+		if flash.is_empty() {
+			flash.resize(REGION_WORDS, ERASED_WORD);
+		}
+	});
+}
+
+// A missing file starts out erased and is created on the first write
+pub fn load(path: &Path) -> Result<(), String> {
+	let mut words = vec![ERASED_WORD; REGION_WORDS];
+
+	if path.exists() {
+		let bytes = fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+		for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+			*word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+		}
+	}
+
+	free(|cs| {
+		*FLASH.borrow(cs).borrow_mut() = words;
+		*FLASH_FILE.borrow(cs).borrow_mut() = Some(path.to_path_buf());
+	});
+	Ok(())
+}
+
+#[allow(dead_code)]
+pub fn get_error_string(error: NvmcError) -> &'static str {
+	match error {
+		NvmcError::Alignment => 		"nvmc: address not aligned",
+		NvmcError::NotInitialized => 	"nvmc: not initialized",
+		NvmcError::OutOfRange => 		"nvmc: address outside of settings pages",
+	}
+}
+
+pub fn erase_page(address: u32) -> Result<(), NvmcError> {
+	if !address.is_multiple_of(PAGE_SIZE) {
+		return Err(NvmcError::Alignment);
+	}
+	let index = get_index(address, PAGE_SIZE)?;
+
+	free(|cs| {
+		let mut flash = FLASH.borrow(cs).borrow_mut();
+		flash[index..index + (PAGE_SIZE / 4) as usize].fill(ERASED_WORD);
+	});
+
+	save();
+	Ok(())
+}
+
+pub fn read_word(address: u32) -> u32 {
+	match get_index(address, 4) {
+		Ok(index) => free(|cs| FLASH.borrow(cs).borrow()[index]),
+		Err(_) => ERASED_WORD
+	}
+}
+
+#[allow(dead_code)]
+pub fn write_word(address: u32, value: u32) -> Result<(), NvmcError> {
+	write_words(address, &[value])
+}
+
+pub fn write_words(address: u32, values: &[u32]) -> Result<(), NvmcError> {
+	if !address.is_multiple_of(4) {
+		return Err(NvmcError::Alignment);
+	}
+	let index = get_index(address, values.len() as u32 * 4)?;
+
+	free(|cs| {
+		let mut flash = FLASH.borrow(cs).borrow_mut();
+		for (word, value) in flash[index..].iter_mut().zip(values.iter()) {
+			*word &= *value;
+		}
+	});
+
+	save();
+	Ok(())
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn get_index(address: u32, len: u32) -> Result<usize, NvmcError> {
+	let end = address.checked_add(len).ok_or(NvmcError::OutOfRange)?;
+	if address < REGION_START || end > REGION_START + (REGION_WORDS as u32 * 4) {
+		return Err(NvmcError::OutOfRange);
+	}
+
+	let len = free(|cs| FLASH.borrow(cs).borrow().len());
+	if len == 0 {
+		return Err(NvmcError::NotInitialized);
+	}
+
+	Ok(((address - REGION_START) / 4) as usize)
+}
+
+fn save() {
+	let (path, bytes) = free(|cs| {
+		let path = FLASH_FILE.borrow(cs).borrow().clone();
+		let bytes: Vec<u8> = FLASH.borrow(cs).borrow().iter().flat_map(|w| w.to_le_bytes()).collect();
+		(path, bytes)
+	});
+
+	if let Some(path) = path {
+		if let Err(e) = fs::write(&path, bytes) {
+			eprintln!("{}: {}", path.display(), e);
+		}
+	}
+}
@@ -0,0 +1,73 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::rtc.rs
+// Virtual RTC. Time only moves when the simulator advances TICKS, either from
+// a blocking delay or while the app sleeps in `wfi`. Like the firmware there
+// is no periodic wake: the software timer deadline is fired by the simulator
+// as soon as virtual time reaches it.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::Cell;
+use cortex_m::interrupt::{free, Mutex};
+use super::soft_timer;
+
+pub use super::instant::{Duration, Instant};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+
+
+//==============================================================================
+// Variables
+//==============================================================================
+pub const TICKS_PER_SECOND: u64 = 4096;
+
+static TICKS: Mutex<Cell<u64>> = Mutex::new(Cell::new(0));
+static DEADLINE: Mutex<Cell<Option<Instant>>> = Mutex::new(Cell::new(None));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn init() {
+	free(|cs| TICKS.borrow(cs).set(0));
+}
+
+pub fn get_deadline() -> Option<Instant> {
+	free(|cs| DEADLINE.borrow(cs).get())
+}
+
+pub fn get_ticks() -> u64 {
+	free(|cs| TICKS.borrow(cs).get())
+}
+
+pub fn run_deadline() {
+	free(|cs| DEADLINE.borrow(cs).set(None));
+	soft_timer::expire_handler();
+}
+
+pub fn set_deadline(deadline: Option<Instant>) {
+	free(|cs| DEADLINE.borrow(cs).set(deadline));
+}
+
+pub fn set_ticks(ticks: u64) {
+	free(|cs| TICKS.borrow(cs).set(ticks));
+}
+
+#[allow(dead_code)]
+pub fn get_timestamp() -> u32 {
+	(get_ticks() / TICKS_PER_SECOND) as u32
+}
+
+#[allow(dead_code)]
+pub fn get_timediff(seconds: u32) -> u32 {
+	get_timestamp().saturating_sub(seconds)
+}
+
+#[allow(dead_code)]
+pub fn get_timestamp_fraction() -> u32 {
+	(get_ticks() % TICKS_PER_SECOND) as u32
+}
@@ -0,0 +1,51 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::spi.rs
+// Bytes clocked out while the LCD is selected are fed to the ST7789 model,
+// and while the flash is selected to the NOR flash model.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use crate::config;
+use crate::mcu::gpio;
+use crate::sim::{display, flash};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+pub enum SpiError{
+	HANDLER,
+	RECEIVE,
+	TRANSMIT,
+}
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn write(buf: &[u8]) -> Result<(), SpiError> {
+	if let gpio::PinState::PinLow = gpio::get_output_state(config::LCD_CS_PIN) {
+		let is_data = gpio::get_output_state(config::LCD_DCX_PIN) == gpio::PinState::PinHigh;
+		display::write(buf, is_data);
+	}
+	if let gpio::PinState::PinLow = gpio::get_output_state(config::FLASH_CS_PIN) {
+		flash::transfer(&mut buf.to_vec());
+	}
+
+	Ok(())
+}
+
+// Nothing but the flash drives MISO, otherwise it reads as zeros
+pub fn transfer(buf: &mut [u8]) -> Result<(), SpiError> {
+	if let gpio::PinState::PinLow = gpio::get_output_state(config::FLASH_CS_PIN) {
+		flash::transfer(buf);
+	}
+	else {
+		write(buf)?;
+		buf.fill(0);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,52 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::spim.rs
+// Shares the bus model with `spi`; DMA transfers complete immediately, so
+// queued writes release chip select straight away and the bus is never busy.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use super::{gpio, spi};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+pub enum SpimError{
+	Handler,
+	Receive,
+	Transmit,
+}
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn flush() -> Result<(), SpimError> {
+	Ok(())
+}
+
+pub fn get_busy() -> bool {
+	false
+}
+
+pub fn wait_idle() {}
+
+#[allow(dead_code)]
+pub fn transfer(block: &mut [u8]) -> Result<(), SpimError> {
+	spi::transfer(block).map_err(|_| SpimError::Transmit)
+}
+
+#[allow(dead_code)]
+pub fn write(tx_block: &[u8]) -> Result<(), SpimError> {
+	spi::write(tx_block).map_err(|_| SpimError::Transmit)
+}
+
+pub fn write_async(tx_block: &[u8], cs_pin: u8) -> Result<(), SpimError> {
+	gpio::set_pin_state(cs_pin, gpio::PinState::PinLow);
+	write(tx_block)?;
+	gpio::set_pin_state(cs_pin, gpio::PinState::PinHigh);
+	Ok(())
+}
@@ -0,0 +1,26 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::mcu::timer.rs
+// A blocking delay advances virtual time, running any script events that fall
+// inside it, just as interrupts would fire during the firmware's busy wait.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use crate::sim;
+use super::rtc;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn init() {}
+
+pub fn get_busy() -> bool {
+	false
+}
+
+pub fn delay(milliseconds: u32) {
+	let ticks = (milliseconds as u64 * rtc::TICKS_PER_SECOND) / 1000;
+	sim::sleep_until(rtc::get_ticks() + ticks);
+}
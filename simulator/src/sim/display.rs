@@ -0,0 +1,160 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::sim::display.rs
+// Model of the ST7789 command stream. COLUMN_ADDRESS and ROW_ADDRESS set the
+// window, MEMORY_WRITE starts streaming big-endian RGB565 pixels into it.
+// The visible 240x240 area is a drivers::lcd FrameBuffer that can be saved
+// as PPM.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use crate::drivers::lcd::framebuffer::FrameBuffer;
+use crate::drivers::lcd::lcd_api::Display;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+struct St7789 {
+	command: u8,
+	args: [u8; 4],
+	arg_count: usize,
+	x_start: u16,
+	x_end: u16,
+	y_start: u16,
+	y_end: u16,
+	dirty: bool,
+	framebuffer: Option<FrameBuffer<'static>>,
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+pub const WIDTH: usize = 240;
+pub const HEIGHT: usize = 240;
+
+const COLUMN_ADDRESS: u8 = 0x2A;
+const ROW_ADDRESS: u8 = 0x2B;
+const MEMORY_WRITE: u8 = 0x2C;
+const MEMORY_WRITE_CONTINUE: u8 = 0x3C;
+
+static DISPLAY: Mutex<RefCell<St7789>> = Mutex::new(RefCell::new(St7789 {
+	command: 0,
+	args: [0; 4],
+	arg_count: 0,
+	x_start: 0,
+	x_end: (WIDTH - 1) as u16,
+	y_start: 0,
+	y_end: (HEIGHT - 1) as u16,
+	dirty: false,
+	framebuffer: None,
+}));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn init() {
+	let pixels: &'static mut [u16] = Box::leak(vec![0; WIDTH * HEIGHT].into_boxed_slice());
+	free(move |cs| {
+		DISPLAY.borrow(cs).borrow_mut().framebuffer =
+			Some(FrameBuffer::new(WIDTH as u16, HEIGHT as u16, pixels));
+	});
+}
+
+pub fn take_dirty() -> bool {
+	free(|cs| {
+		let mut display = DISPLAY.borrow(cs).borrow_mut();
+		let dirty = display.dirty;
+		display.dirty = false;
+		dirty
+	})
+}
+
+pub fn write(bytes: &[u8], is_data: bool) {
+	free(|cs| {
+		let mut display = DISPLAY.borrow(cs).borrow_mut();
+		for byte in bytes {
+			if is_data {
+				display.data(*byte);
+			}
+			else {
+				display.command(*byte);
+			}
+		}
+	});
+}
+
+pub fn write_ppm(path: &Path) -> io::Result<()> {
+	let mut file = BufWriter::new(File::create(path)?);
+	write!(file, "P6\n{} {}\n255\n", WIDTH, HEIGHT)?;
+
+	let pixels: Vec<u16> = free(|cs| {
+		DISPLAY.borrow(cs).borrow().framebuffer.as_ref()
+			.map(|f| f.get_pixels().to_vec())
+			.unwrap_or_default()
+	});
+	for pixel in pixels.iter() {
+		let r = ((pixel >> 11) & 0x1F) as u8;
+		let g = ((pixel >> 5) & 0x3F) as u8;
+		let b = (pixel & 0x1F) as u8;
+		file.write_all(&[(r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2)])?;
+	}
+
+	file.flush()
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+impl St7789 {
+	fn command(&mut self, command: u8) {
+		self.command = command;
+		self.arg_count = 0;
+
+		if command == MEMORY_WRITE {
+			let (x, y) = (self.x_start, self.y_start);
+			let width = self.x_end.saturating_sub(x) + 1;
+			let height = self.y_end.saturating_sub(y) + 1;
+			if let Some(framebuffer) = self.framebuffer.as_mut() {
+				framebuffer.set_window(x, width, y, height);
+			}
+		}
+	}
+
+	fn data(&mut self, byte: u8) {
+		match self.command {
+			COLUMN_ADDRESS | ROW_ADDRESS => {
+				if self.arg_count < self.args.len() {
+					self.args[self.arg_count] = byte;
+					self.arg_count += 1;
+				}
+
+				if self.arg_count == self.args.len() {
+					let start = u16::from_be_bytes([self.args[0], self.args[1]]);
+					let end = u16::from_be_bytes([self.args[2], self.args[3]]);
+					if self.command == COLUMN_ADDRESS {
+						self.x_start = start;
+						self.x_end = end;
+					}
+					else {
+						self.y_start = start;
+						self.y_end = end;
+					}
+				}
+			},
+			MEMORY_WRITE | MEMORY_WRITE_CONTINUE => {
+				if let Some(framebuffer) = self.framebuffer.as_mut() {
+					framebuffer.write_pixels(&[byte]);
+					self.dirty = true;
+				}
+			},
+			_ => (),
+		}
+	}
+}
@@ -0,0 +1,146 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::sim::flash.rs
+// Model of the XT25F32B SPI NOR flash. Each chip select starts a new command,
+// the bytes that follow are its address and data. Programs and erases finish
+// instantly, so the busy bit is never set, and need the write enable latch
+// like the real chip. Programming ANDs into what is there and wraps within the
+// 256B page.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use crate::config;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+struct NorFlash {
+	memory: Vec<u8>,
+	command: Option<u8>,
+	address: u32,
+	index: usize,
+	write_enabled: bool,
+	powered_down: bool
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const JEDEC_ID: [u8; 3] = [0x0B, 0x40, 0x16];
+const STATUS_WRITE_ENABLED: u8 = 0x02;
+
+const WRITE_ENABLE: u8 = 0x06;
+const READ_STATUS: u8 = 0x05;
+const READ_DATA: u8 = 0x03;
+const PAGE_PROGRAM: u8 = 0x02;
+const SECTOR_ERASE: u8 = 0x20;
+const BLOCK_ERASE: u8 = 0xD8;
+const READ_JEDEC_ID: u8 = 0x9F;
+const POWER_DOWN: u8 = 0xB9;
+const RELEASE_POWER_DOWN: u8 = 0xAB;
+
+static FLASH: Mutex<RefCell<NorFlash>> = Mutex::new(RefCell::new(NorFlash {
+	memory: Vec::new(),
+	command: None,
+	address: 0,
+	index: 0,
+	write_enabled: false,
+	powered_down: false
+}));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn init() {
+	free(|cs| FLASH.borrow(cs).borrow_mut().memory = vec![0xFF; config::FLASH_SIZE as usize]);
+}
+
+// Chip select released, commands that act on the whole sequence end here
+pub fn deselect() {
+	free(|cs| FLASH.borrow(cs).borrow_mut().deselect());
+}
+
+// Bytes clocked out are replaced by the bytes clocked back in
+pub fn transfer(bytes: &mut [u8]) {
+	free(|cs| {
+		let mut flash = FLASH.borrow(cs).borrow_mut();
+		for byte in bytes.iter_mut() {
+			*byte = flash.clock(*byte);
+		}
+	});
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+impl NorFlash {
+	fn clock(&mut self, mosi: u8) -> u8 {
+		let index = self.index;
+		self.index += 1;
+
+		let command = match self.command {
+			Some(command) => command,
+			None => {
+				self.command = Some(mosi);
+				self.address = 0;
+				return 0xFF;
+			}
+		};
+
+		// Only the release command gets through deep power-down
+		if self.powered_down {
+			return 0xFF;
+		}
+
+		// Bytes 1 to 3 carry the address, most significant first
+		if matches!(command, READ_DATA | PAGE_PROGRAM | SECTOR_ERASE | BLOCK_ERASE) && index <= 3 {
+			self.address = (self.address << 8) | mosi as u32;
+			return 0xFF;
+		}
+
+		match command {
+			READ_STATUS => if self.write_enabled { STATUS_WRITE_ENABLED } else { 0x00 },
+			READ_JEDEC_ID => JEDEC_ID.get(index - 1).copied().unwrap_or(0xFF),
+			READ_DATA => {
+				let address = (self.address as usize + (index - 4)) % self.memory.len();
+				self.memory[address]
+			},
+			PAGE_PROGRAM => {
+				if self.write_enabled {
+					let page = self.address & !0xFF;
+					let address = (page | ((self.address + (index - 4) as u32) & 0xFF)) as usize;
+					self.memory[address] &= mosi;
+				}
+				0xFF
+			},
+			_ => 0xFF
+		}
+	}
+
+	fn deselect(&mut self) {
+		let command = self.command.take();
+		let complete = self.index;
+		self.index = 0;
+
+		match command {
+			Some(RELEASE_POWER_DOWN) => self.powered_down = false,
+			_ if self.powered_down => (),
+			Some(WRITE_ENABLE) => self.write_enabled = true,
+			Some(POWER_DOWN) => self.powered_down = true,
+			Some(PAGE_PROGRAM) => self.write_enabled = false,
+			Some(erase @ (SECTOR_ERASE | BLOCK_ERASE)) if complete == 4 => {
+				if self.write_enabled {
+					let size = if erase == SECTOR_ERASE { 4096 } else { 65536 };
+					let start = (self.address as usize & !(size - 1)) % self.memory.len();
+					self.memory[start..start + size].fill(0xFF);
+				}
+				self.write_enabled = false;
+			},
+			_ => ()
+		}
+	}
+}
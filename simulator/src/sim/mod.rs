@@ -0,0 +1,187 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::sim::mod.rs
+// Virtual time and frame capture. Time only moves forward while the app is
+// blocked in `timer::delay` or asleep in `wfi`. Whenever it is about to move
+// and the display has changed, the framebuffer is saved as the next frame.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+pub mod display;
+pub mod flash;
+pub mod script;
+
+use core::cell::Cell;
+use cortex_m::interrupt::{free, Mutex};
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::sync::OnceLock;
+use crate::mcu::{nvmc, rtc};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+pub struct Options {
+	pub script: Option<PathBuf>,
+	pub out_dir: PathBuf,
+	pub duration_ms: Option<u64>,
+	pub flash: Option<PathBuf>,
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const DEFAULT_DURATION_MS: u64 = 10_000;
+const SCRIPT_TAIL_MS: u64 = 1_000;
+const USAGE: &str = "usage: simulator [--script FILE] [--out DIR] [--duration MS] [--flash FILE]";
+
+static OPTIONS: OnceLock<Options> = OnceLock::new();
+static END_TICKS: Mutex<Cell<u64>> = Mutex::new(Cell::new(0));
+static FRAME_COUNT: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+impl Options {
+	pub fn from_args() -> Result<Self, String> {
+		let mut options = Options {
+			script: None,
+			out_dir: PathBuf::from("frames"),
+			duration_ms: None,
+			flash: None,
+		};
+
+		let mut args = std::env::args().skip(1);
+		while let Some(arg) = args.next() {
+			let mut value = || args.next().ok_or(format!("{} needs a value\n{}", arg, USAGE));
+			match arg.as_str() {
+				"--script" => options.script = Some(PathBuf::from(value()?)),
+				"--out" => options.out_dir = PathBuf::from(value()?),
+				"--duration" => options.duration_ms = Some(
+					value()?.parse().map_err(|_| format!("--duration expects milliseconds\n{}", USAGE))?
+				),
+				"--flash" => options.flash = Some(PathBuf::from(value()?)),
+				_ => return Err(USAGE.to_string()),
+			}
+		}
+
+		Ok(options)
+	}
+}
+
+pub fn init(options: Options) -> Result<(), String> {
+	if let Some(script) = &options.script {
+		script::load(script)?;
+	}
+	if let Some(flash) = &options.flash {
+		nvmc::load(flash)?;
+	}
+
+	display::init();
+	flash::init();
+
+	fs::create_dir_all(&options.out_dir)
+		.map_err(|e| format!("{}: {}", options.out_dir.display(), e))?;
+
+	// Without an explicit duration, run a little past the last scripted event
+	let duration_ms = options.duration_ms.unwrap_or_else(|| {
+		script::get_last_time_ms()
+			.map(|t| t + SCRIPT_TAIL_MS)
+			.unwrap_or(DEFAULT_DURATION_MS)
+	});
+	free(|cs| END_TICKS.borrow(cs).set(ms_to_ticks(duration_ms)));
+
+	let _ = OPTIONS.set(options);
+	cortex_m::set_wfi_hook(idle);
+
+	Ok(())
+}
+
+pub fn finish() -> ! {
+	flush_frame();
+	println!("simulation finished at {} ms, {} frames",
+		ticks_to_ms(rtc::get_ticks()), free(|cs| FRAME_COUNT.borrow(cs).get()));
+	process::exit(0);
+}
+
+pub fn sleep_until(ticks: u64) {
+	flush_frame();
+
+	// Run every script event and timer deadline that falls before the wake up
+	// time, in order
+	loop {
+		let event = script::get_next_time_ms().map(ms_to_ticks);
+		let deadline = rtc::get_deadline().map(|d| d.as_ticks());
+		let next = match (event, deadline) {
+			(Some(e), Some(d)) => e.min(d),
+			(Some(e), None) => e,
+			(None, Some(d)) => d,
+			(None, None) => break
+		};
+		if next > ticks {
+			break;
+		}
+
+		rtc::set_ticks(next.max(rtc::get_ticks()));
+		if deadline == Some(next) {
+			rtc::run_deadline();
+		}
+		else {
+			script::run_next();
+		}
+		flush_frame();
+	}
+
+	if ticks >= free(|cs| END_TICKS.borrow(cs).get()) {
+		rtc::set_ticks(free(|cs| END_TICKS.borrow(cs).get()));
+		finish();
+	}
+
+	rtc::set_ticks(ticks.max(rtc::get_ticks()));
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn flush_frame() {
+	if !display::take_dirty() {
+		return;
+	}
+
+	let frame = free(|cs| {
+		let frame = FRAME_COUNT.borrow(cs).get();
+		FRAME_COUNT.borrow(cs).set(frame + 1);
+		frame
+	});
+
+	if let Some(options) = OPTIONS.get() {
+		let path = options.out_dir.join(format!("frame_{:05}.ppm", frame));
+		match display::write_ppm(&path) {
+			Ok(()) => println!("{:>8} ms  frame {}", ticks_to_ms(rtc::get_ticks()), path.display()),
+			Err(e) => eprintln!("{}: {}", path.display(), e),
+		}
+	}
+}
+
+fn idle() {
+	// Sleep until the next timer deadline or scripted interrupt, or to the end
+	let mut wake = free(|cs| END_TICKS.borrow(cs).get());
+	if let Some(deadline) = rtc::get_deadline() {
+		wake = wake.min(deadline.as_ticks().max(rtc::get_ticks()));
+	}
+	if let Some(next) = script::get_next_time_ms().map(ms_to_ticks) {
+		wake = wake.min(next);
+	}
+	sleep_until(wake);
+}
+
+fn ms_to_ticks(ms: u64) -> u64 {
+	(ms * rtc::TICKS_PER_SECOND).div_ceil(1000)
+}
+
+fn ticks_to_ms(ticks: u64) -> u64 {
+	(ticks * 1000) / rtc::TICKS_PER_SECOND
+}
@@ -0,0 +1,174 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// simulator::sim::script.rs
+// Scripted input. Each non-empty line is `<time_ms> <device> [args...]`,
+// anything after a '#' is a comment:
+//
+//	500   button  press
+//	700   button  release
+//	1000  touch   slide_down
+//	1500  touch   tap 120 80
+//	2000  battery 3.62
+//	2000  charger connected
+//	5000  quit
+//
+// Touch gestures: none, slide_down, slide_up, slide_left, slide_right, tap,
+// double_tap, long_press. The x/y position is optional and defaults to the
+// centre of the screen.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+use crate::config;
+use crate::mcu::{adc, gpio, i2c};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy, Debug)]
+enum Action {
+	Battery(u16),
+	Button(bool),
+	Charger(bool),
+	Quit,
+	Touch { gesture: u8, x: u16, y: u16 },
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ScriptEvent {
+	time_ms: u64,
+	action: Action,
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+static EVENTS: Mutex<RefCell<VecDeque<ScriptEvent>>> = Mutex::new(RefCell::new(VecDeque::new()));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn load(path: &Path) -> Result<(), String> {
+	let text = fs::read_to_string(path)
+		.map_err(|e| format!("{}: {}", path.display(), e))?;
+
+	let mut events: Vec<ScriptEvent> = Vec::new();
+	for (number, line) in text.lines().enumerate() {
+		let line = line.split('#').next().unwrap_or("").trim();
+		if line.is_empty() {
+			continue;
+		}
+
+		let event = parse_line(line)
+			.map_err(|e| format!("{}:{}: {}", path.display(), number + 1, e))?;
+		events.push(event);
+	}
+
+	// Keep file order for events that share a timestamp
+	events.sort_by_key(|e| e.time_ms);
+	free(|cs| EVENTS.borrow(cs).replace(events.into()));
+
+	Ok(())
+}
+
+pub fn get_last_time_ms() -> Option<u64> {
+	free(|cs| EVENTS.borrow(cs).borrow().back().map(|e| e.time_ms))
+}
+
+pub fn get_next_time_ms() -> Option<u64> {
+	free(|cs| EVENTS.borrow(cs).borrow().front().map(|e| e.time_ms))
+}
+
+pub fn run_next() {
+	if let Some(event) = free(|cs| EVENTS.borrow(cs).borrow_mut().pop_front()) {
+		println!("{:>8} ms  {:?}", event.time_ms, event.action);
+		run(event.action);
+	}
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn parse_line(line: &str) -> Result<ScriptEvent, String> {
+	let mut words = line.split_whitespace();
+	let time_ms = words.next()
+		.and_then(|w| w.parse::<u64>().ok())
+		.ok_or("expected a time in milliseconds")?;
+	let device = words.next().ok_or("expected a device")?;
+	let args: Vec<&str> = words.collect();
+
+	let action = match (device, args.as_slice()) {
+		("battery", [volts]) => {
+			let volts: f32 = volts.parse().map_err(|_| "battery expects a voltage")?;
+			Action::Battery((volts * 100.0) as u16)
+		},
+		("button", ["press"]) => Action::Button(true),
+		("button", ["release"]) => Action::Button(false),
+		("charger", ["connected"]) => Action::Charger(true),
+		("charger", ["disconnected"]) => Action::Charger(false),
+		("quit", []) => Action::Quit,
+		("touch", [gesture]) => Action::Touch { gesture: parse_gesture(gesture)?, x: 120, y: 120 },
+		("touch", [gesture, x, y]) => Action::Touch {
+			gesture: parse_gesture(gesture)?,
+			x: x.parse().map_err(|_| "touch x must be a number")?,
+			y: y.parse().map_err(|_| "touch y must be a number")?,
+		},
+		_ => return Err(format!("unknown command '{}'", line)),
+	};
+
+	Ok(ScriptEvent { time_ms, action })
+}
+
+fn parse_gesture(name: &str) -> Result<u8, String> {
+	// CST816S gesture register values
+	match name {
+		"none" => Ok(0x0),
+		"slide_down" => Ok(0x1),
+		"slide_up" => Ok(0x2),
+		"slide_left" => Ok(0x3),
+		"slide_right" => Ok(0x4),
+		"tap" => Ok(0x5),
+		"double_tap" => Ok(0xB),
+		"long_press" => Ok(0xC),
+		_ => Err(format!("unknown gesture '{}'", name)),
+	}
+}
+
+fn run(action: Action) {
+	match action {
+		Action::Battery(centivolts) => {
+			// Inverse of drivers::battery::get_battery_voltage
+			adc::set_adc(((centivolts as u32 * 1137) / 2000) as u16);
+		},
+		Action::Button(pressed) => {
+			let state = if pressed { gpio::PinState::PinLow } else { gpio::PinState::PinHigh };
+			gpio::drive_input(config::PUSH_BUTTON_IN_PIN, state);
+		},
+		Action::Charger(connected) => {
+			let state = if connected { gpio::PinState::PinLow } else { gpio::PinState::PinHigh };
+			gpio::drive_input(config::CHARGER_CONNECTED_PIN, state);
+		},
+		Action::Quit => super::finish(),
+		Action::Touch { gesture, x, y } => {
+			// Register image read by drivers::touch, then pulse the interrupt line
+			i2c::set_touch_registers(0, &[
+				0x00,
+				gesture,
+				0x01,
+				0x40 | ((x >> 8) as u8 & 0x0F),
+				x as u8,
+				(y >> 8) as u8 & 0x0F,
+				y as u8,
+				0x00,
+			]);
+			gpio::drive_input(config::TOUCH_INT_PIN, gpio::PinState::PinLow);
+			gpio::drive_input(config::TOUCH_INT_PIN, gpio::PinState::PinHigh);
+		},
+	}
+}
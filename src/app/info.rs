@@ -14,9 +14,11 @@ use app::page;
 // Enums, Structs, and Types
 //==============================================================================
 pub struct DeviceInfoChangeFlags{
+	pub alarm: bool,
 	pub battery_voltage: bool,
 	pub button_press: bool,
 	pub charger_state: bool,
+	pub countdown: bool,
 	pub display_state: bool,
 	pub time_change: bool,
 	pub touch_event: bool,
@@ -34,8 +36,10 @@ pub struct DeviceInfo {
 	pub flags: DeviceInfoFlags,
 
 	pub app_page: page::AppPage,
+	pub backlight: drivers::lcd::lcd_api::BacklightBrightness,
 	pub battery_voltage: u16,
 	pub battery_level: drivers::battery::BatteryLevel,
+	pub date: drivers::clock::Date,
 	pub display_state: app::DisplayState,
 	pub time: drivers::clock::Time,
 	pub touch: drivers::touch::TouchEvent
@@ -48,9 +52,11 @@ static mut DEVICE_INFO: bool = false;
 
 const DEVICE_INFO_DEFAULTS: DeviceInfo = DeviceInfo {
 	change_flags: DeviceInfoChangeFlags {
+		alarm: false,
 		battery_voltage: false,
 		button_press: false,
 		charger_state: false,
+		countdown: false,
 		display_state: false,
 		time_change: false,
 		touch_event: false
@@ -62,14 +68,12 @@ const DEVICE_INFO_DEFAULTS: DeviceInfo = DeviceInfo {
 		military_time: false,
 	},
 	app_page: page::AppPage::Startup,
+	backlight: drivers::lcd::lcd_api::BacklightBrightness::Brightness4,
 	battery_level: drivers::battery::BatteryLevel::Level4,
 	battery_voltage: 0,
+	date: drivers::clock::Date::EPOCH,
 	display_state: app::DisplayState::On,
-	time: drivers::clock::Time {
-		hours: 0,
-		minutes: 0, 
-		seconds: 0
-	},
+	time: drivers::clock::Time::MIDNIGHT,
 	touch: drivers::touch::TouchEvent {
 		gesture: drivers::touch::Gesture::Unknown,
 		event: drivers::touch::Event::Unknown,
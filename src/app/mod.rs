@@ -43,9 +43,11 @@ fn clear_flags(d: &mut info::DeviceInfo) {
 }
 
 fn get_unhandled_flags(flags: &info::DeviceInfoChangeFlags) -> bool {
-	if flags.battery_voltage ||
+	if flags.alarm ||
+		flags.battery_voltage ||
 		flags.button_press ||
 		flags.charger_state ||
+		flags.countdown ||
 		flags.display_state ||
 		flags.time_change ||
 		flags.touch_event {
@@ -92,17 +94,31 @@ pub fn task_handler(d: &mut info::DeviceInfo) {
 	// First, clear all previously set flags
 	clear_flags(d);
 
+	// A ringing alarm or finished countdown takes over whatever page is showing
+	if d.change_flags.alarm || d.change_flags.countdown {
+		d.app_page = if d.change_flags.alarm { page::AppPage::Alarm } else { page::AppPage::TimerAlert };
+		d.display_state = DisplayState::On;
+		d.change_flags.display_state = true;
+		page::change_page(d);
+	}
+
 	// Call the task handler for the current page
 	match d.app_page {
+		page::AppPage::Alarm => page::alarm::task_handler(d),
 		page::AppPage::Home => page::home::task_handler(d),
 		page::AppPage::Log => page::log::task_handler(d),
 		page::AppPage::Notifications => page::notifications::task_handler(d),
 		page::AppPage::Settings => page::settings::task_handler(d),
+		page::AppPage::SetAlarm => page::set_alarm::task_handler(d),
+		page::AppPage::SetTime => page::set_time::task_handler(d),
 		page::AppPage::Startup => {
 			page::startup::print_page();
 			d.app_page = page::AppPage::Home;
 			page::change_page(d);
 		},
+		page::AppPage::Stopwatch => page::stopwatch::task_handler(d),
+		page::AppPage::Timer => page::timer::task_handler(d),
+		page::AppPage::TimerAlert => page::timer_alert::task_handler(d),
 	}
 
 	// if d.change_flags.display_state {
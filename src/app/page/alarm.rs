@@ -0,0 +1,117 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// app::page::alarm.rs
+// Shown while an alarm is ringing. The button or the snooze area snoozes it,
+// the dismiss area turns it off. Either way the watch goes back home.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use crate::app::{info, page};
+use crate::drivers::alarm;
+use crate::drivers::lcd::{font, lcd_api};
+use crate::drivers::touch::Gesture;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+
+
+//==============================================================================
+// Variables
+//==============================================================================
+const BACKGROUND_COLOR: lcd_api::Color = lcd_api::Color::Black;
+const TEXT_COLOR: lcd_api::Color = lcd_api::Color::White;
+const SNOOZE_COLOR: lcd_api::Color = lcd_api::Color::Navy;
+const DISMISS_COLOR: lcd_api::Color = lcd_api::Color::Rust;
+
+const TIME_SCALE: u16 = 6;
+const TIME_Y: u16 = 30;
+const LABEL_Y: u16 = 90;
+
+// Touch areas, each runs across the whole width of the screen
+const SNOOZE_Y: u16 = 124;
+const DISMISS_Y: u16 = 184;
+const AREA_HEIGHT: u16 = 52;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn start_page(d: &info::DeviceInfo) {
+	let alarm = match alarm::get_ringing() {
+		Some(alarm) => alarm,
+		None => return
+	};
+
+	// "07:30" or " 7:30"
+	let mut hours = alarm.time.hours;
+	if !d.flags.military_time {
+		hours %= 12;
+		if hours == 0 {
+			hours = 12;
+		}
+	}
+	let time: [u8; 5] = [
+		if hours >= 10 || d.flags.military_time { b'0' + (hours / 10) } else { b' ' },
+		b'0' + (hours % 10),
+		b':',
+		b'0' + (alarm.time.minutes / 10),
+		b'0' + (alarm.time.minutes % 10)
+	];
+	font::write_minimal_line(&time, get_centered_x(time.len(), TIME_SCALE), TIME_Y, TEXT_COLOR, BACKGROUND_COLOR, TIME_SCALE);
+
+	let label = alarm.get_label();
+	font::write_minimal_line(label, get_centered_x(label.len(), 2), LABEL_Y, TEXT_COLOR, BACKGROUND_COLOR, 2);
+
+	lcd_api::fill_rectangle(0, 240, SNOOZE_Y, AREA_HEIGHT, SNOOZE_COLOR);
+	font::write_minimal_line(b"Snooze", get_centered_x(6, 3), SNOOZE_Y + 14, TEXT_COLOR, SNOOZE_COLOR, 3);
+
+	lcd_api::fill_rectangle(0, 240, DISMISS_Y, AREA_HEIGHT, DISMISS_COLOR);
+	font::write_minimal_line(b"Dismiss", get_centered_x(7, 3), DISMISS_Y + 14, TEXT_COLOR, DISMISS_COLOR, 3);
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn get_centered_x(len: usize, scale: u16) -> u16 {
+	let width = len as u16 * ((font::MINIMAL_CHARACTER_WIDTH * scale) + scale);
+	240_u16.saturating_sub(width) / 2
+}
+
+fn leave(d: &mut info::DeviceInfo) {
+	d.app_page = page::AppPage::Home;
+	page::change_page(d);
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(d: &mut info::DeviceInfo) {
+	if d.change_flags.touch_event {
+		if let Gesture::SinglePress = d.touch.gesture {
+			let y = d.touch.y;
+			if (SNOOZE_Y..SNOOZE_Y + AREA_HEIGHT).contains(&y) {
+				alarm::snooze();
+				leave(d);
+				return;
+			}
+			else if (DISMISS_Y..DISMISS_Y + AREA_HEIGHT).contains(&y) {
+				alarm::dismiss();
+				leave(d);
+				return;
+			}
+		}
+	}
+
+	// Snooze on button release
+	if d.change_flags.button_press && !d.flags.button_pressed {
+		alarm::snooze();
+		leave(d);
+	}
+}
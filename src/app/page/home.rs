@@ -8,10 +8,10 @@
 //==============================================================================
 use crate::app::{info, page};
 use crate::drivers::clock;
-use crate::drivers::lcd::{lcd_api, font};
+use crate::drivers::lcd::{lcd_api, layout};
 use crate::drivers::touch::Gesture;
 use crate::mcu;
-use crate::mcu::rtc;
+use crate::mcu::{rtc, soft_timer};
 
 //==============================================================================
 // Enums, Structs, and Types
@@ -21,9 +21,30 @@ use crate::mcu::rtc;
 //==============================================================================
 // Variables
 //==============================================================================
-const BUTTON_HELD_RESTART_WARN: u32 = 1;
-const BUTTON_HELD_RESTART: u32 = 5;
+const BUTTON_HELD_RESTART_WARN: rtc::Duration = rtc::Duration::from_secs(1);
+const BUTTON_HELD_RESTART: rtc::Duration = rtc::Duration::from_secs(5);
 static mut SHOWING_RESTART_WARNING: bool = false;
+static mut RESTART_WARN_TIMER_ID: Option<soft_timer::TimerId> = None;
+static mut RESTART_TIMER_ID: Option<soft_timer::TimerId> = None;
+
+const RESTART_WARN_TIMER: soft_timer::TimerConfig = soft_timer::TimerConfig {
+	mode: soft_timer::TimerMode::OneShot,
+	interval: BUTTON_HELD_RESTART_WARN,
+	callback: None,
+	real_time_callback: false
+};
+const RESTART_TIMER: soft_timer::TimerConfig = soft_timer::TimerConfig {
+	mode: soft_timer::TimerMode::OneShot,
+	interval: BUTTON_HELD_RESTART,
+	callback: None,
+	real_time_callback: false
+};
+
+// Clear of the tick marks at the edge of the clock face
+const RESTART_WARNING: layout::TextBox = layout::TextBox {
+	align: layout::Align::Center,
+	..layout::TextBox::new(20, 95, 200, 48, layout::Typeface::Minimal(3))
+};
 
 //==============================================================================
 // Public Functions
@@ -35,34 +56,30 @@ pub fn start_page(d: &info::DeviceInfo) {
 //==============================================================================
 // Private Functions
 //==============================================================================
-fn button_held_handler(last_press_time: u32){
-	let time_diff = rtc::get_timediff(last_press_time);
+fn button_held_handler(){
+	unsafe { SHOWING_RESTART_WARNING = true; }
+
+	// Print the restart warning, the minimal characters are built in so it
+	// cannot fail
+	let _ = layout::write(
+		b"Hold button to restart!",
+		&RESTART_WARNING,
+		lcd_api::Color::White,
+		lcd_api::Background::Solid(lcd_api::Color::Black)
+	);
+}
 
-	if time_diff >= BUTTON_HELD_RESTART {
-		mcu::restart();
-	}
-	else if time_diff >= BUTTON_HELD_RESTART_WARN {
-		unsafe { SHOWING_RESTART_WARNING = true; }
-
-		// Print the restart warning
-		font::write_minimal_line(
-			b"Hold button",
-			20, 
-			95, 
-			lcd_api::Color::White,
-			lcd_api::Color::Black, 
-			3
-		);
-		font::write_minimal_line(
-			b"to restart!",
-			20, 
-			119, 
-			lcd_api::Color::White,
-			lcd_api::Color::Black, 
-			3
-		);
+fn stop_press_timers() {
+	unsafe {
+		if let Some(id) = RESTART_WARN_TIMER_ID {
+			soft_timer::stop(id);
+		}
+		if let Some(id) = RESTART_TIMER_ID {
+			soft_timer::stop(id);
+		}
+		RESTART_WARN_TIMER_ID = None;
+		RESTART_TIMER_ID = None;
 	}
-
 }
 
 //==============================================================================
@@ -74,13 +91,17 @@ fn button_held_handler(last_press_time: u32){
 // Task Handler
 //==============================================================================
 pub fn task_handler(d: &mut info::DeviceInfo) {
-	static mut PRESS_TIMER_RUNNING: bool = false;
-	static mut LAST_PRESS_TIME: u32 = 0;
-
 	// First, check for button hold timout
 	unsafe {
-		if PRESS_TIMER_RUNNING {
-			button_held_handler(LAST_PRESS_TIME);
+		if let Some(id) = RESTART_TIMER_ID {
+			if soft_timer::take_expired(id) > 0 {
+				mcu::restart();
+			}
+		}
+		if let Some(id) = RESTART_WARN_TIMER_ID {
+			if soft_timer::take_expired(id) > 0 {
+				button_held_handler();
+			}
 		}
 	}
 
@@ -102,21 +123,30 @@ pub fn task_handler(d: &mut info::DeviceInfo) {
 				d.app_page = page::AppPage::Log;
 				page::change_page(d);
 			},
+			Gesture::SlideLeft => {
+				d.app_page = page::AppPage::Stopwatch;
+				page::change_page(d);
+			},
+			Gesture::SlideRight => {
+				d.app_page = page::AppPage::Timer;
+				page::change_page(d);
+			},
 			_ => (),
 		}
 	}
 
 	if d.change_flags.button_press {
 		if d.flags.button_pressed {
+			stop_press_timers();
 			unsafe { 
-				PRESS_TIMER_RUNNING = true;
-				LAST_PRESS_TIME = rtc::get_timestamp();
+				RESTART_WARN_TIMER_ID = soft_timer::start(RESTART_WARN_TIMER).ok();
+				RESTART_TIMER_ID = soft_timer::start(RESTART_TIMER).ok();
 			}
 		}
 		else {
 			unsafe {
 				// Clear restart prompt if button is released
-				PRESS_TIMER_RUNNING = false;
+				stop_press_timers();
 				
 				// Pretend to change page back to home to force update all
 				if SHOWING_RESTART_WARNING {
@@ -6,11 +6,17 @@
 //==============================================================================
 // Crates and Mods
 //==============================================================================
+pub mod alarm;
 pub mod home;
 pub mod log;
 pub mod notifications;
+pub mod set_alarm;
+pub mod set_time;
 pub mod settings;
 pub mod startup;
+pub mod stopwatch;
+pub mod timer;
+pub mod timer_alert;
 
 use crate::drivers::lcd::lcd_api;
 use super::info;
@@ -19,12 +25,19 @@ use super::info;
 // Enums, Structs, and Types
 //==============================================================================
 #[allow(dead_code)]
+#[derive(Clone, Copy)]
 pub enum AppPage {
+	Alarm,
 	Home,
 	Notifications,
 	Log,
 	Settings,
+	SetAlarm,
+	SetTime,
 	Startup,
+	Stopwatch,
+	Timer,
+	TimerAlert,
 }
 
 //==============================================================================
@@ -41,15 +54,21 @@ pub fn change_page(d: &mut info::DeviceInfo) {
 	lcd_api::fill_background(lcd_api::Color::Black);
 
 	match d.app_page {
+		AppPage::Alarm => alarm::start_page(d),
 		AppPage::Home => home::start_page(d),
 		AppPage::Notifications => notifications::start_page(),
 		AppPage::Log => log::start_page(),
 		AppPage::Settings => settings::start_page(d),
+		AppPage::SetAlarm => set_alarm::start_page(d),
+		AppPage::SetTime => set_time::start_page(d),
+		AppPage::Stopwatch => stopwatch::start_page(),
+		AppPage::Timer => timer::start_page(),
+		AppPage::TimerAlert => timer_alert::start_page(),
 		_ => (),
 	}
 
 	// Restore brightness to show new page
-	lcd_api::set_backlight(lcd_api::BacklightBrightness::Brightness4);
+	lcd_api::set_backlight(d.backlight);
 }
 
 //==============================================================================
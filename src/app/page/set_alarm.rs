@@ -0,0 +1,342 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// app::page::set_alarm.rs
+// Alarm editor, opened from the settings page. Works like the time editor:
+// swiping up or down changes the selected field, a button press confirms it
+// and moves on to the next one. Confirming the last field stores the alarm
+// and returns to settings.
+//
+// Changing the alarm number shows that alarm. An empty one starts at the
+// current time and switched off, and is only stored once it is switched on.
+// Labels are picked from LABELS; one set some other way is shown until
+// another is picked.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::Cell;
+use cortex_m::interrupt::{free, Mutex};
+use crate::app::{info, page};
+use crate::drivers::{alarm, clock};
+use crate::drivers::lcd::{font, lcd_api};
+use crate::drivers::touch::Gesture;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy, PartialEq)]
+enum Field {
+	Slot,
+	Enabled,
+	Hours,
+	Minutes,
+	Repeat,
+	Label
+}
+
+#[derive(Clone, Copy)]
+struct Editor {
+	field: Field,
+	military_time: bool,
+	slot: u8,
+	present: bool,
+	enabled: bool,
+	hours: u8,
+	minutes: u8,
+	repeat: u8,
+	label: [u8; alarm::ALARM_LABEL_LEN]
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const FIELDS: [Field; 6] = [
+	Field::Slot,
+	Field::Enabled,
+	Field::Hours,
+	Field::Minutes,
+	Field::Repeat,
+	Field::Label
+];
+
+// Repeats a swipe steps through, an alarm set some other way shows as custom
+const REPEATS: [u8; 4] = [
+	alarm::REPEAT_ONCE,
+	alarm::REPEAT_WEEKDAYS,
+	alarm::REPEAT_WEEKENDS,
+	alarm::REPEAT_DAILY
+];
+
+// The first is given to new alarms
+const LABELS: [&[u8]; 8] = [
+	b"Alarm",
+	b"Wake up",
+	b"Work",
+	b"School",
+	b"Medicine",
+	b"Meeting",
+	b"Workout",
+	b"Bedtime"
+];
+
+static EDITOR: Mutex<Cell<Editor>> = Mutex::new(Cell::new(Editor {
+	field: Field::Slot,
+	military_time: false,
+	slot: 0,
+	present: false,
+	enabled: false,
+	hours: 0,
+	minutes: 0,
+	repeat: alarm::REPEAT_ONCE,
+	label: [0; alarm::ALARM_LABEL_LEN]
+}));
+
+const BACKGROUND_COLOR: lcd_api::Color = lcd_api::Color::Black;
+const TEXT_COLOR: lcd_api::Color = lcd_api::Color::White;
+const SELECTED_COLOR: lcd_api::Color = lcd_api::Color::Yellow;
+const HINT_COLOR: lcd_api::Color = lcd_api::Color::Gray;
+
+const ROW_Y: u16 = 48;
+const ROW_HEIGHT: u16 = 26;
+const LABEL_X: u16 = 20;
+const VALUE_X: u16 = 120;
+const VALUE_LEN: usize = 10;
+const TEXT_SCALE: u16 = 2;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn start_page(d: &info::DeviceInfo) {
+	let mut editor = Editor {
+		field: Field::Slot,
+		military_time: d.flags.military_time,
+		slot: 0,
+		present: false,
+		enabled: false,
+		hours: 0,
+		minutes: 0,
+		repeat: alarm::REPEAT_ONCE,
+		label: [0; alarm::ALARM_LABEL_LEN]
+	};
+	load_slot(&mut editor, 0);
+	free(|cs| EDITOR.borrow(cs).set(editor));
+
+	font::write_minimal_line(b"Alarms", 66, 3, TEXT_COLOR, BACKGROUND_COLOR, 3);
+	for field in FIELDS.iter() {
+		write_row(&editor, *field);
+	}
+	font::write_minimal_line(b"Swipe: change", 42, 204, HINT_COLOR, BACKGROUND_COLOR, TEXT_SCALE);
+	font::write_minimal_line(b"Button: next", 48, 222, HINT_COLOR, BACKGROUND_COLOR, TEXT_SCALE);
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn adjust(editor: &mut Editor, up: bool) {
+	match editor.field {
+		Field::Slot => {
+			let slot = step(editor.slot as u16, 0, alarm::ALARM_COUNT as u16 - 1, up) as u8;
+			load_slot(editor, slot);
+		},
+		Field::Enabled => editor.enabled = !editor.enabled,
+		Field::Hours => editor.hours = step(editor.hours as u16, 0, 23, up) as u8,
+		Field::Minutes => editor.minutes = step(editor.minutes as u16, 0, 59, up) as u8,
+		Field::Repeat => {
+			let index = REPEATS.iter().position(|r| *r == editor.repeat).unwrap_or(0);
+			editor.repeat = REPEATS[step(index as u16, 0, REPEATS.len() as u16 - 1, up) as usize];
+		},
+		Field::Label => {
+			let index = LABELS.iter().position(|l| *l == get_label_text(editor)).unwrap_or(0);
+			set_label(editor, LABELS[step(index as u16, 0, LABELS.len() as u16 - 1, up) as usize]);
+		}
+	}
+}
+
+fn apply(editor: &Editor) {
+	// Nothing to store for an empty alarm left switched off
+	if !editor.present && !editor.enabled {
+		return;
+	}
+
+	let index = editor.slot as usize;
+	let time = clock::Time { hours: editor.hours, minutes: editor.minutes, seconds: 0 };
+	let mut new_alarm = alarm::Alarm::new(time, editor.repeat, get_label_text(editor));
+	new_alarm.enabled = editor.enabled;
+
+	let _ = alarm::set(index, new_alarm);
+}
+
+fn get_label(field: Field) -> &'static [u8] {
+	match field {
+		Field::Slot => b"Alarm",
+		Field::Enabled => b"State",
+		Field::Hours => b"Hour",
+		Field::Minutes => b"Minute",
+		Field::Repeat => b"Repeat",
+		Field::Label => b"Label"
+	}
+}
+
+fn get_label_text(editor: &Editor) -> &[u8] {
+	let len = editor.label.iter().position(|c| *c == 0).unwrap_or(alarm::ALARM_LABEL_LEN);
+	&editor.label[..len]
+}
+
+fn get_value(editor: &Editor, field: Field) -> [u8; VALUE_LEN] {
+	let mut value = [b' '; VALUE_LEN];
+
+	match field {
+		Field::Slot => write_number(&mut value, editor.slot as u16 + 1, 1),
+		Field::Enabled => {
+			value[..3].copy_from_slice(if editor.enabled { b"On " } else { b"Off" });
+		},
+		Field::Hours => {
+			// Shown the way the clock shows it
+			if editor.military_time {
+				write_number(&mut value, editor.hours as u16, 2);
+			}
+			else {
+				let hours = if editor.hours.is_multiple_of(12) { 12 } else { editor.hours % 12 };
+				write_number(&mut value, hours as u16, 2);
+				value[3..5].copy_from_slice(if editor.hours < 12 { b"AM" } else { b"PM" });
+			}
+		},
+		Field::Minutes => write_number(&mut value, editor.minutes as u16, 2),
+		Field::Repeat => {
+			let name: &[u8] = match editor.repeat {
+				alarm::REPEAT_ONCE => b"Once",
+				alarm::REPEAT_WEEKDAYS => b"Weekdays",
+				alarm::REPEAT_WEEKENDS => b"Weekends",
+				alarm::REPEAT_DAILY => b"Daily",
+				_ => b"Custom"
+			};
+			value[..name.len()].copy_from_slice(name);
+		},
+		Field::Label => {
+			let label = get_label_text(editor);
+			let len = label.len().min(VALUE_LEN);
+			value[..len].copy_from_slice(&label[..len]);
+		}
+	}
+
+	value
+}
+
+fn get_row_y(field: Field) -> u16 {
+	let index = FIELDS.iter().position(|f| *f == field).unwrap_or(0);
+	ROW_Y + (index as u16 * ROW_HEIGHT)
+}
+
+// Shows the alarm in `slot`, or the current time for an empty one
+fn load_slot(editor: &mut Editor, slot: u8) {
+	editor.slot = slot;
+
+	match alarm::get(slot as usize) {
+		Some(existing) => {
+			editor.present = true;
+			editor.enabled = existing.enabled;
+			editor.hours = existing.time.hours;
+			editor.minutes = existing.time.minutes;
+			editor.repeat = existing.repeat;
+			editor.label = existing.label;
+		},
+		None => {
+			let time = clock::get_time();
+			editor.present = false;
+			editor.enabled = false;
+			editor.hours = time.hours;
+			editor.minutes = time.minutes;
+			editor.repeat = alarm::REPEAT_ONCE;
+			set_label(editor, LABELS[0]);
+		}
+	}
+}
+
+fn set_label(editor: &mut Editor, label: &[u8]) {
+	let len = label.len().min(alarm::ALARM_LABEL_LEN);
+	editor.label = [0; alarm::ALARM_LABEL_LEN];
+	editor.label[..len].copy_from_slice(&label[..len]);
+}
+
+fn step(value: u16, min: u16, max: u16, up: bool) -> u16 {
+	if up {
+		if value >= max { min } else { value + 1 }
+	}
+	else if value <= min {
+		max
+	}
+	else {
+		value - 1
+	}
+}
+
+fn write_number(buffer: &mut [u8], value: u16, digits: usize) {
+	let mut value = value;
+	for i in (0..digits).rev() {
+		buffer[i] = b'0' + (value % 10) as u8;
+		value /= 10;
+	}
+}
+
+fn write_row(editor: &Editor, field: Field) {
+	let y = get_row_y(field);
+	let color = if field == editor.field { SELECTED_COLOR } else { TEXT_COLOR };
+
+	font::write_minimal_line(get_label(field), LABEL_X, y, color, BACKGROUND_COLOR, TEXT_SCALE);
+	font::write_minimal_line(&get_value(editor, field), VALUE_X, y, color, BACKGROUND_COLOR, TEXT_SCALE);
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(d: &mut info::DeviceInfo) {
+	let mut editor = free(|cs| EDITOR.borrow(cs).get());
+
+	if d.change_flags.touch_event {
+		let up = match d.touch.gesture {
+			Gesture::SlideUp => Some(true),
+			Gesture::SlideDown => Some(false),
+			_ => None
+		};
+
+		if let Some(up) = up {
+			adjust(&mut editor, up);
+
+			// Another alarm changes every row
+			if editor.field == Field::Slot {
+				for field in FIELDS.iter() {
+					write_row(&editor, *field);
+				}
+			}
+			else {
+				write_row(&editor, editor.field);
+			}
+		}
+	}
+
+	// Confirm on button release, like the settings page
+	if d.change_flags.button_press && !d.flags.button_pressed {
+		let index = FIELDS.iter().position(|f| *f == editor.field).unwrap_or(0);
+
+		if index + 1 < FIELDS.len() {
+			let previous = editor.field;
+			editor.field = FIELDS[index + 1];
+			write_row(&editor, previous);
+			write_row(&editor, editor.field);
+		}
+		else {
+			apply(&editor);
+			d.app_page = page::AppPage::Settings;
+			page::change_page(d);
+			return;
+		}
+	}
+
+	free(|cs| EDITOR.borrow(cs).set(editor));
+}
@@ -0,0 +1,259 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// app::page::set_time.rs
+// Time and date editor, opened from the settings page. Swiping up or down
+// changes the selected field, a button press confirms it and moves on to the
+// next one. Confirming the last field sets the clock and returns to settings.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::Cell;
+use cortex_m::interrupt::{free, Mutex};
+use crate::app::{info, page};
+use crate::drivers::{alarm, calendar, clock};
+use crate::drivers::lcd::{font, lcd_api};
+use crate::drivers::touch::Gesture;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy, PartialEq)]
+enum Field {
+	Format,
+	Hours,
+	Minutes,
+	Day,
+	Month,
+	Year
+}
+
+#[derive(Clone, Copy)]
+struct Editor {
+	field: Field,
+	military_time: bool,
+	hours: u8,
+	minutes: u8,
+	day: u8,
+	month: u8,
+	year: u16
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const FIELDS: [Field; 6] = [
+	Field::Format,
+	Field::Hours,
+	Field::Minutes,
+	Field::Day,
+	Field::Month,
+	Field::Year
+];
+
+static EDITOR: Mutex<Cell<Editor>> = Mutex::new(Cell::new(Editor {
+	field: Field::Format,
+	military_time: false,
+	hours: 0,
+	minutes: 0,
+	day: 1,
+	month: 1,
+	year: calendar::YEAR_MIN
+}));
+
+const BACKGROUND_COLOR: lcd_api::Color = lcd_api::Color::Black;
+const TEXT_COLOR: lcd_api::Color = lcd_api::Color::White;
+const SELECTED_COLOR: lcd_api::Color = lcd_api::Color::Yellow;
+const HINT_COLOR: lcd_api::Color = lcd_api::Color::Gray;
+
+const ROW_Y: u16 = 48;
+const ROW_HEIGHT: u16 = 26;
+const LABEL_X: u16 = 20;
+const VALUE_X: u16 = 140;
+const VALUE_LEN: usize = 5;
+const TEXT_SCALE: u16 = 2;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn start_page(d: &info::DeviceInfo) {
+	let date = clock::get_date();
+	let time = clock::get_time();
+
+	let editor = Editor {
+		field: Field::Format,
+		military_time: d.flags.military_time,
+		hours: time.hours,
+		minutes: time.minutes,
+		day: date.day,
+		month: date.month,
+		year: date.year
+	};
+	free(|cs| EDITOR.borrow(cs).set(editor));
+
+	font::write_minimal_line(b"Set time", 48, 3, TEXT_COLOR, BACKGROUND_COLOR, 3);
+	for field in FIELDS.iter() {
+		write_row(&editor, *field);
+	}
+	font::write_minimal_line(b"Swipe: change", 42, 204, HINT_COLOR, BACKGROUND_COLOR, TEXT_SCALE);
+	font::write_minimal_line(b"Button: next", 48, 222, HINT_COLOR, BACKGROUND_COLOR, TEXT_SCALE);
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn adjust(editor: &mut Editor, up: bool) {
+	match editor.field {
+		Field::Format => editor.military_time = !editor.military_time,
+		Field::Hours => editor.hours = step(editor.hours as u16, 0, 23, up) as u8,
+		Field::Minutes => editor.minutes = step(editor.minutes as u16, 0, 59, up) as u8,
+		Field::Day => {
+			let days = calendar::get_days_in_month(editor.year, editor.month);
+			editor.day = step(editor.day as u16, 1, days as u16, up) as u8;
+		},
+		Field::Month => editor.month = step(editor.month as u16, 1, 12, up) as u8,
+		Field::Year => editor.year = step(editor.year, calendar::YEAR_MIN, calendar::YEAR_MAX, up)
+	}
+
+	// Keep the day valid when the month or year changes under it
+	editor.day = editor.day.min(calendar::get_days_in_month(editor.year, editor.month));
+}
+
+fn apply(d: &mut info::DeviceInfo, editor: &Editor) {
+	let date = calendar::Date::new(editor.year, editor.month, editor.day);
+	let time = calendar::Time::new(editor.hours, editor.minutes, 0);
+
+	if let (Some(date), Some(time)) = (date, time) {
+		clock::set_local_time(calendar::to_unix_time(&date, &time));
+		alarm::schedule();
+		d.date = date;
+		d.time = time;
+	}
+
+	d.flags.military_time = editor.military_time;
+}
+
+fn get_label(field: Field) -> &'static [u8] {
+	match field {
+		Field::Format => b"Format",
+		Field::Hours => b"Hour",
+		Field::Minutes => b"Minute",
+		Field::Day => b"Day",
+		Field::Month => b"Month",
+		Field::Year => b"Year"
+	}
+}
+
+fn get_value(editor: &Editor, field: Field) -> [u8; VALUE_LEN] {
+	let mut value = [b' '; VALUE_LEN];
+
+	match field {
+		Field::Format => {
+			value[..3].copy_from_slice(if editor.military_time { b"24h" } else { b"12h" });
+		},
+		Field::Hours => {
+			// Shown the way the selected format will show it
+			if editor.military_time {
+				write_number(&mut value, editor.hours as u16, 2);
+			}
+			else {
+				let hours = if editor.hours.is_multiple_of(12) { 12 } else { editor.hours % 12 };
+				write_number(&mut value, hours as u16, 2);
+				value[3..].copy_from_slice(if editor.hours < 12 { b"AM" } else { b"PM" });
+			}
+		},
+		Field::Minutes => write_number(&mut value, editor.minutes as u16, 2),
+		Field::Day => write_number(&mut value, editor.day as u16, 2),
+		Field::Month => value[..3].copy_from_slice(calendar::get_month_string(editor.month).as_bytes()),
+		Field::Year => write_number(&mut value, editor.year, 4)
+	}
+
+	value
+}
+
+fn get_row_y(field: Field) -> u16 {
+	let index = FIELDS.iter().position(|f| *f == field).unwrap_or(0);
+	ROW_Y + (index as u16 * ROW_HEIGHT)
+}
+
+fn step(value: u16, min: u16, max: u16, up: bool) -> u16 {
+	if up {
+		if value >= max { min } else { value + 1 }
+	}
+	else if value <= min {
+		max
+	}
+	else {
+		value - 1
+	}
+}
+
+fn write_number(buffer: &mut [u8], value: u16, digits: usize) {
+	let mut value = value;
+	for i in (0..digits).rev() {
+		buffer[i] = b'0' + (value % 10) as u8;
+		value /= 10;
+	}
+}
+
+fn write_row(editor: &Editor, field: Field) {
+	let y = get_row_y(field);
+	let color = if field == editor.field { SELECTED_COLOR } else { TEXT_COLOR };
+
+	font::write_minimal_line(get_label(field), LABEL_X, y, color, BACKGROUND_COLOR, TEXT_SCALE);
+	font::write_minimal_line(&get_value(editor, field), VALUE_X, y, color, BACKGROUND_COLOR, TEXT_SCALE);
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(d: &mut info::DeviceInfo) {
+	let mut editor = free(|cs| EDITOR.borrow(cs).get());
+
+	if d.change_flags.touch_event {
+		let up = match d.touch.gesture {
+			Gesture::SlideUp => Some(true),
+			Gesture::SlideDown => Some(false),
+			_ => None
+		};
+
+		if let Some(up) = up {
+			adjust(&mut editor, up);
+			write_row(&editor, editor.field);
+
+			// The hour is shown differently in 12 and 24 hour format and
+			// the day may have been pulled back to fit the month
+			match editor.field {
+				Field::Format => write_row(&editor, Field::Hours),
+				Field::Month | Field::Year => write_row(&editor, Field::Day),
+				_ => ()
+			}
+		}
+	}
+
+	// Confirm on button release, like the settings page
+	if d.change_flags.button_press && !d.flags.button_pressed {
+		let index = FIELDS.iter().position(|f| *f == editor.field).unwrap_or(0);
+
+		if index + 1 < FIELDS.len() {
+			let previous = editor.field;
+			editor.field = FIELDS[index + 1];
+			write_row(&editor, previous);
+			write_row(&editor, editor.field);
+		}
+		else {
+			apply(d, &editor);
+			d.app_page = page::AppPage::Settings;
+			page::change_page(d);
+			return;
+		}
+	}
+
+	free(|cs| EDITOR.borrow(cs).set(editor));
+}
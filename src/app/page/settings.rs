@@ -10,12 +10,20 @@
 // Crates and Mods
 //==============================================================================
 use crate::app::{info, page};
-use crate::drivers::lcd::{font, lcd_api};
+use crate::drivers::lcd::{font, layout, lcd_api, shapes};
 use crate::drivers::touch::Gesture;
 
 //==============================================================================
 // Enums, Structs, and Types
 //==============================================================================
+struct MenuEntry {
+	label: &'static [u8],
+	x: u16,
+	y: u16,
+	height: u16,
+	page: page::AppPage
+}
+
 struct BatteryIcon {
 	x: u16,
 	y: u16,
@@ -38,19 +46,38 @@ const BATTERY: BatteryIcon = BatteryIcon {
 
 const BACKGROUND_COLOR: lcd_api::Color = lcd_api::Color::Black;
 
+const TITLE: layout::TextBox = layout::TextBox {
+	align: layout::Align::Center,
+	..layout::TextBox::new(0, 3, 240, 24, layout::Typeface::Minimal(3))
+};
+
+// Tapping anywhere across the row opens the page
+const MENU: [MenuEntry; 2] = [
+	MenuEntry {
+		label: b"Set time",
+		x: 48,
+		y: 150,
+		height: 24,
+		page: page::AppPage::SetTime
+	},
+	MenuEntry {
+		label: b"Alarms",
+		x: 66,
+		y: 184,
+		height: 24,
+		page: page::AppPage::SetAlarm
+	}
+];
+const MENU_SCALE: u16 = 3;
+const MENU_COLOR: lcd_api::Color = lcd_api::Color::White;
+
 //==============================================================================
 // Public Functions
 //==============================================================================
 #[allow(dead_code)]
 pub fn start_page(d: &mut info::DeviceInfo) {
-	font::write_minimal_line(
-		b"Settings",
-		56,
-		3,
-		lcd_api::Color::White,
-		lcd_api::Color::Black,
-		3
-	);
+	// Minimal characters are built in, so this cannot fail
+	let _ = layout::write(b"Settings", &TITLE, lcd_api::Color::White, lcd_api::Background::Solid(BACKGROUND_COLOR));
 	write_all_icons(d);
 	
 }
@@ -60,6 +87,14 @@ pub fn start_page(d: &mut info::DeviceInfo) {
 //==============================================================================
 pub fn write_all_icons(d: &mut info::DeviceInfo) {
 	write_battery_icon(BATTERY.x, BATTERY.y, d);
+
+	for entry in MENU.iter() {
+		font::write_minimal_line(entry.label, entry.x, entry.y, MENU_COLOR, BACKGROUND_COLOR, MENU_SCALE);
+	}
+}
+
+fn get_menu_entry(y: u16) -> Option<&'static MenuEntry> {
+	MENU.iter().find(|entry| y >= entry.y && y < entry.y + entry.height)
 }
 
 fn write_battery_icon(x: u16, y: u16, d: &mut info::DeviceInfo) {
@@ -72,20 +107,17 @@ fn write_battery_icon(x: u16, y: u16, d: &mut info::DeviceInfo) {
 	let fill = if charging { BATTERY.charging_color } else { BACKGROUND_COLOR };
 
 	// Draw outline
-	lcd_api::fill_rectangle(x + 12, 48, y, 5, outline);
-	lcd_api::fill_rectangle(x + 12, 5, y + 5, 24, outline);
-	lcd_api::fill_rectangle(x + 55, 5, y + 5, 24, outline);
-	lcd_api::fill_rectangle(x + 12, 48, y + 29, 5, outline);
+	shapes::draw_rounded_rectangle(x + 12, 48, y, 34, 6, 5, outline);
 	if charging {
-		lcd_api::fill_rectangle(x, 4, y + 12, 10, BATTERY.charging_color);
-		lcd_api::fill_rectangle(x + 4, 8, y + 2, 30, BATTERY.charging_color);
+		shapes::fill_rounded_rectangle(x, 4, y + 12, 10, 2, BATTERY.charging_color);
+		shapes::fill_rounded_rectangle(x + 4, 8, y + 2, 30, 3, BATTERY.charging_color);
 	}
 	else {
 		lcd_api::fill_rectangle(x + 4, 8, y + 2, 4, BACKGROUND_COLOR);
 		lcd_api::fill_rectangle(x, 8, y + 6, 22, BACKGROUND_COLOR);
 		lcd_api::fill_rectangle(x + 4, 8, y + 28, 4, BACKGROUND_COLOR);
 	}
-	lcd_api::fill_rectangle(x + 8, 4, y + 6, 22, outline);
+	shapes::fill_rounded_rectangle(x + 8, 4, y + 6, 22, 2, outline);
 
 	// Fill battery
 	lcd_api::fill_rectangle(x + 17, 38, y + 5, 24, fill);
@@ -134,10 +166,20 @@ fn write_battery_icon(x: u16, y: u16, d: &mut info::DeviceInfo) {
 pub fn task_handler(d: &mut info::DeviceInfo) {
 	// Only change on swipe up - to close settings pulldown
 	if d.change_flags.touch_event {
-		if let Gesture::SlideUp = d.touch.gesture {
-			d.app_page = page::AppPage::Home;
-			page::change_page(d);
-			return;
+		match d.touch.gesture {
+			Gesture::SlideUp => {
+				d.app_page = page::AppPage::Home;
+				page::change_page(d);
+				return;
+			},
+			Gesture::SinglePress => {
+				if let Some(entry) = get_menu_entry(d.touch.y) {
+					d.app_page = entry.page;
+					page::change_page(d);
+					return;
+				}
+			},
+			_ => ()
 		}
 	}
 
@@ -0,0 +1,351 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// app::page::stopwatch.rs
+// Stopwatch with lap times. A button press starts and stops it, holding the
+// button while stopped resets it and a tap records a lap. Swiping up and down
+// scrolls through the laps, swiping right goes back home.
+//
+// Time comes from the RTC ticks (1/4096s, the same count behind
+// `rtc::get_timestamp_fraction`) so the stopwatch keeps running while other
+// pages are shown. The display is only refreshed by a one-shot timer that this
+// page re-arms, so nothing wakes the CPU once the page has been left.
+//
+// The display only has room for minutes up to 99, so the stopwatch stops
+// itself at MAX_ELAPSED.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use crate::app::{info, page};
+use crate::drivers::lcd::{font, lcd_api};
+use crate::drivers::touch::Gesture;
+use crate::mcu::{rtc, soft_timer};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy)]
+struct Lap {
+	number: u16,
+	split: rtc::Duration
+}
+
+struct Stopwatch {
+	running: bool,
+	start: rtc::Instant,
+	elapsed: rtc::Duration,
+	lap_start: rtc::Duration,
+	laps: [Lap; LAP_COUNT],
+	lap_total: u16,
+	scroll: usize,
+	press: Option<rtc::Instant>,
+	refresh_timer: Option<soft_timer::TimerId>,
+	digits_on_display: [u8; 4]
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const LAP_COUNT: usize = 20;
+const LAP_ROWS: usize = 6;
+const LAP_LEN: usize = 14;
+const LAP_DIGITS: usize = 5;
+
+const RESET_HOLD: rtc::Duration = rtc::Duration::from_secs(1);
+
+// 99:59.99
+const MAX_ELAPSED: rtc::Duration = rtc::Duration::from_ticks((100 * 60 * rtc::TICKS_PER_SECOND) - 1);
+
+const REFRESH_TIMER: soft_timer::TimerConfig = soft_timer::TimerConfig {
+	mode: soft_timer::TimerMode::OneShot,
+	interval: rtc::Duration::from_millis(100),
+	callback: None,
+	real_time_callback: false
+};
+
+static STOPWATCH: Mutex<RefCell<Stopwatch>> = Mutex::new(RefCell::new(Stopwatch {
+	running: false,
+	start: rtc::Instant::from_ticks(0),
+	elapsed: rtc::Duration::ZERO,
+	lap_start: rtc::Duration::ZERO,
+	laps: [Lap { number: 0, split: rtc::Duration::ZERO }; LAP_COUNT],
+	lap_total: 0,
+	scroll: 0,
+	press: None,
+	refresh_timer: None,
+	digits_on_display: [10; 4]	// Init at 10 to force write the first time
+}));
+
+const BACKGROUND_COLOR: lcd_api::Color = lcd_api::Color::Black;
+const DIGIT_COLOR: lcd_api::Color = lcd_api::Color::Gray;
+const TEXT_COLOR: lcd_api::Color = lcd_api::Color::White;
+const RUNNING_COLOR: lcd_api::Color = lcd_api::Color::Green;
+
+const DIGITS_X: [u16; 4] = [ 35, 75, 125, 165 ];
+const DIGITS_Y: u16 = 10;
+const COLON_X: u16 = 117;
+const FRACTION_X: u16 = 168;
+const FRACTION_Y: u16 = 80;
+const LAPS_X: u16 = 42;
+const LAPS_Y: u16 = 112;
+const LAP_ROW_HEIGHT: u16 = 20;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn start_page() {
+	free(|cs| STOPWATCH.borrow(cs).borrow_mut().digits_on_display = [10; 4]);
+	check_limit();
+
+	lcd_api::fill_rectangle(COLON_X, 4, DIGITS_Y + 20, 4, DIGIT_COLOR);
+	lcd_api::fill_rectangle(COLON_X, 4, DIGITS_Y + 40, 4, DIGIT_COLOR);
+	write_elapsed();
+	write_laps();
+	arm_refresh();
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn arm_refresh() {
+	let (running, previous) = free(|cs| {
+		let mut stopwatch = STOPWATCH.borrow(cs).borrow_mut();
+		(stopwatch.running, stopwatch.refresh_timer.take())
+	});
+
+	if let Some(id) = previous {
+		soft_timer::stop(id);
+	}
+
+	if running {
+		let id = soft_timer::start(REFRESH_TIMER).ok();
+		free(|cs| STOPWATCH.borrow(cs).borrow_mut().refresh_timer = id);
+	}
+}
+
+// Stops the stopwatch once it reaches the most the display can show
+fn check_limit() {
+	free(|cs| {
+		let mut stopwatch = STOPWATCH.borrow(cs).borrow_mut();
+		if stopwatch.running && get_elapsed(&stopwatch) >= MAX_ELAPSED {
+			stopwatch.elapsed = MAX_ELAPSED;
+			stopwatch.running = false;
+		}
+	});
+}
+
+fn get_elapsed(stopwatch: &Stopwatch) -> rtc::Duration {
+	let elapsed = if stopwatch.running {
+		stopwatch.elapsed + stopwatch.start.elapsed()
+	}
+	else {
+		stopwatch.elapsed
+	};
+
+	elapsed.min(MAX_ELAPSED)
+}
+
+fn get_hundredths(duration: rtc::Duration) -> u8 {
+	((duration.subsec_ticks() * 100) / rtc::TICKS_PER_SECOND) as u8
+}
+
+fn record_lap() {
+	free(|cs| {
+		let mut stopwatch = STOPWATCH.borrow(cs).borrow_mut();
+		if !stopwatch.running {
+			return;
+		}
+
+		let elapsed = get_elapsed(&stopwatch);
+		let lap = Lap {
+			number: stopwatch.lap_total.saturating_add(1),
+			split: elapsed - stopwatch.lap_start
+		};
+
+		// Newest first, the oldest lap drops off the end once the list is full
+		stopwatch.laps.copy_within(0..LAP_COUNT - 1, 1);
+		stopwatch.laps[0] = lap;
+		stopwatch.lap_total = lap.number;
+		stopwatch.lap_start = elapsed;
+		stopwatch.scroll = 0;
+	});
+
+	write_laps();
+}
+
+fn reset() {
+	free(|cs| {
+		let mut stopwatch = STOPWATCH.borrow(cs).borrow_mut();
+		stopwatch.elapsed = rtc::Duration::ZERO;
+		stopwatch.lap_start = rtc::Duration::ZERO;
+		stopwatch.lap_total = 0;
+		stopwatch.scroll = 0;
+	});
+
+	write_elapsed();
+	write_laps();
+}
+
+fn scroll(down: bool) {
+	let changed = free(|cs| {
+		let mut stopwatch = STOPWATCH.borrow(cs).borrow_mut();
+		let stored = (stopwatch.lap_total as usize).min(LAP_COUNT);
+		let max = stored.saturating_sub(LAP_ROWS);
+		let scroll = if down { (stopwatch.scroll + 1).min(max) } else { stopwatch.scroll.saturating_sub(1) };
+
+		let changed = scroll != stopwatch.scroll;
+		stopwatch.scroll = scroll;
+		changed
+	});
+
+	if changed {
+		write_laps();
+	}
+}
+
+fn toggle() {
+	free(|cs| {
+		let mut stopwatch = STOPWATCH.borrow(cs).borrow_mut();
+		if stopwatch.running {
+			stopwatch.elapsed = get_elapsed(&stopwatch);
+			stopwatch.running = false;
+		}
+		// Held at MAX_ELAPSED until it is reset
+		else if stopwatch.elapsed < MAX_ELAPSED {
+			stopwatch.start = rtc::Instant::now();
+			stopwatch.running = true;
+		}
+	});
+
+	write_elapsed();
+	arm_refresh();
+}
+
+fn write_duration(buffer: &mut [u8], duration: rtc::Duration) {
+	// "MM:SS.hh", never past MAX_ELAPSED
+	let seconds = duration.as_secs();
+	let hundredths = get_hundredths(duration);
+	let minutes = seconds / 60;
+	let seconds = seconds % 60;
+
+	buffer[0] = b'0' + (minutes / 10) as u8;
+	buffer[1] = b'0' + (minutes % 10) as u8;
+	buffer[2] = b':';
+	buffer[3] = b'0' + (seconds / 10) as u8;
+	buffer[4] = b'0' + (seconds % 10) as u8;
+	buffer[5] = b'.';
+	buffer[6] = b'0' + (hundredths / 10);
+	buffer[7] = b'0' + (hundredths % 10);
+}
+
+fn write_elapsed() {
+	let (elapsed, running, on_display) = free(|cs| {
+		let stopwatch = STOPWATCH.borrow(cs).borrow();
+		(get_elapsed(&stopwatch), stopwatch.running, stopwatch.digits_on_display)
+	});
+
+	let seconds = elapsed.as_secs();
+	let minutes = seconds / 60;
+	let seconds = seconds % 60;
+	let digits: [u8; 4] = [
+		(minutes / 10) as u8,
+		(minutes % 10) as u8,
+		(seconds / 10) as u8,
+		(seconds % 10) as u8
+	];
+
+	// Only the digits that changed, they are slow to draw
+	for i in 0..4 {
+		if digits[i] != on_display[i] {
+			font::write_time_character(digits[i], DIGITS_X[i], DIGITS_Y, DIGIT_COLOR, BACKGROUND_COLOR);
+		}
+	}
+	free(|cs| STOPWATCH.borrow(cs).borrow_mut().digits_on_display = digits);
+
+	let hundredths = get_hundredths(elapsed);
+	let fraction: [u8; 3] = [b'.', b'0' + (hundredths / 10), b'0' + (hundredths % 10)];
+	let color = if running { RUNNING_COLOR } else { TEXT_COLOR };
+	font::write_minimal_line(&fraction, FRACTION_X, FRACTION_Y, color, BACKGROUND_COLOR, 3);
+}
+
+fn write_laps() {
+	let (laps, lap_total, scroll) = free(|cs| {
+		let stopwatch = STOPWATCH.borrow(cs).borrow();
+		(stopwatch.laps, stopwatch.lap_total, stopwatch.scroll)
+	});
+	let stored = (lap_total as usize).min(LAP_COUNT);
+
+	for row in 0..LAP_ROWS {
+		let y = LAPS_Y + (row as u16 * LAP_ROW_HEIGHT);
+		let mut line = [b' '; LAP_LEN];
+
+		// "12  01:23.45", longer lap numbers push the time along
+		if let Some(lap) = laps[..stored].get(scroll + row) {
+			let digits = (1..LAP_DIGITS).find(|d| lap.number < 10u16.pow(*d as u32)).unwrap_or(LAP_DIGITS).max(2);
+			let mut number = lap.number;
+			for i in (0..digits).rev() {
+				line[i] = b'0' + (number % 10) as u8;
+				number /= 10;
+			}
+			write_duration(&mut line[(digits + 1).max(4)..], lap.split);
+		}
+
+		font::write_minimal_line(&line, LAPS_X, y, TEXT_COLOR, BACKGROUND_COLOR, 2);
+	}
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(d: &mut info::DeviceInfo) {
+	if d.change_flags.touch_event {
+		match d.touch.gesture {
+			Gesture::SinglePress => record_lap(),
+			Gesture::SlideUp => scroll(true),
+			Gesture::SlideDown => scroll(false),
+			Gesture::SlideRight => {
+				d.app_page = page::AppPage::Home;
+				page::change_page(d);
+				return;
+			},
+			_ => ()
+		}
+	}
+
+	if d.change_flags.button_press {
+		if d.flags.button_pressed {
+			free(|cs| STOPWATCH.borrow(cs).borrow_mut().press = Some(rtc::Instant::now()));
+		}
+		else {
+			let (running, press) = free(|cs| {
+				let mut stopwatch = STOPWATCH.borrow(cs).borrow_mut();
+				(stopwatch.running, stopwatch.press.take())
+			});
+			let held = press.map(|p| p.elapsed()).unwrap_or(rtc::Duration::ZERO);
+
+			if !running && held >= RESET_HOLD {
+				reset();
+			}
+			else {
+				toggle();
+			}
+		}
+	}
+
+	let refresh_timer = free(|cs| STOPWATCH.borrow(cs).borrow().refresh_timer);
+	if let Some(id) = refresh_timer {
+		if soft_timer::take_expired(id) > 0 {
+			check_limit();
+			write_elapsed();
+			arm_refresh();
+		}
+	}
+}
@@ -0,0 +1,235 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// app::page::timer.rs
+// Countdown timer page. Tapping a preset starts it straight away, otherwise
+// swiping up and down sets the minutes and the button or the start area starts
+// it. While it runs the same controls cancel it. Swiping left goes back home,
+// the countdown itself carries on in drivers::countdown.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use crate::app::{info, page};
+use crate::drivers::countdown;
+use crate::drivers::lcd::{font, lcd_api};
+use crate::drivers::touch::Gesture;
+use crate::mcu::{rtc, soft_timer};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+struct TimerPage {
+	minutes: u8,
+	refresh_timer: Option<soft_timer::TimerId>,
+	digits_on_display: [u8; 4]
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const PRESETS: [u8; 5] = [ 1, 3, 5, 10, 25 ];
+const MINUTES_MAX: u8 = 99;
+
+static TIMER_PAGE: Mutex<RefCell<TimerPage>> = Mutex::new(RefCell::new(TimerPage {
+	minutes: 5,
+	refresh_timer: None,
+	digits_on_display: [10; 4]	// Init at 10 to force write the first time
+}));
+
+const BACKGROUND_COLOR: lcd_api::Color = lcd_api::Color::Black;
+const DIGIT_COLOR: lcd_api::Color = lcd_api::Color::Gray;
+const TEXT_COLOR: lcd_api::Color = lcd_api::Color::White;
+const PRESET_COLOR: lcd_api::Color = lcd_api::Color::GrayDark;
+const START_COLOR: lcd_api::Color = lcd_api::Color::Navy;
+const CANCEL_COLOR: lcd_api::Color = lcd_api::Color::Rust;
+
+const DIGITS_X: [u16; 4] = [ 35, 75, 125, 165 ];
+const DIGITS_Y: u16 = 10;
+const COLON_X: u16 = 117;
+
+// Touch areas, the presets share a row and the start area is full width
+const PRESETS_Y: u16 = 100;
+const PRESET_WIDTH: u16 = 48;
+const PRESET_HEIGHT: u16 = 48;
+const START_Y: u16 = 172;
+const START_HEIGHT: u16 = 52;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn start_page() {
+	free(|cs| TIMER_PAGE.borrow(cs).borrow_mut().digits_on_display = [10; 4]);
+
+	lcd_api::fill_rectangle(COLON_X, 4, DIGITS_Y + 20, 4, DIGIT_COLOR);
+	lcd_api::fill_rectangle(COLON_X, 4, DIGITS_Y + 40, 4, DIGIT_COLOR);
+
+	for (i, minutes) in PRESETS.iter().enumerate() {
+		let x = i as u16 * PRESET_WIDTH;
+		let label: [u8; 2] = [
+			if *minutes >= 10 { b'0' + (minutes / 10) } else { b' ' },
+			b'0' + (minutes % 10)
+		];
+
+		lcd_api::fill_rectangle(x + 2, PRESET_WIDTH - 4, PRESETS_Y, PRESET_HEIGHT, PRESET_COLOR);
+		font::write_minimal_line(&label, x + 6, PRESETS_Y + 12, TEXT_COLOR, PRESET_COLOR, 3);
+	}
+
+	write_digits();
+	write_start();
+	arm_refresh();
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn arm_refresh() {
+	let previous = free(|cs| TIMER_PAGE.borrow(cs).borrow_mut().refresh_timer.take());
+	if let Some(id) = previous {
+		soft_timer::stop(id);
+	}
+
+	// Wake up just as the shown second changes
+	if let Some(remaining) = countdown::get_remaining() {
+		let ticks = match remaining.subsec_ticks() {
+			0 => rtc::TICKS_PER_SECOND,
+			ticks => ticks
+		};
+
+		let id = soft_timer::start(soft_timer::TimerConfig {
+			mode: soft_timer::TimerMode::OneShot,
+			interval: rtc::Duration::from_ticks(ticks),
+			callback: None,
+			real_time_callback: false
+		}).ok();
+		free(|cs| TIMER_PAGE.borrow(cs).borrow_mut().refresh_timer = id);
+	}
+}
+
+fn get_preset(x: u16, y: u16) -> Option<u8> {
+	if !(PRESETS_Y..PRESETS_Y + PRESET_HEIGHT).contains(&y) {
+		return None;
+	}
+
+	PRESETS.get((x / PRESET_WIDTH) as usize).copied()
+}
+
+fn start(minutes: u8) {
+	free(|cs| TIMER_PAGE.borrow(cs).borrow_mut().minutes = minutes);
+
+	if countdown::start(rtc::Duration::from_secs(minutes as u64 * 60)).is_ok() {
+		write_digits();
+		write_start();
+		arm_refresh();
+	}
+}
+
+fn stop() {
+	countdown::cancel();
+	write_digits();
+	write_start();
+	arm_refresh();
+}
+
+fn toggle() {
+	if countdown::get_remaining().is_some() {
+		stop();
+	}
+	else {
+		let minutes = free(|cs| TIMER_PAGE.borrow(cs).borrow().minutes);
+		start(minutes);
+	}
+}
+
+fn write_digits() {
+	// Rounded up, so it shows 00:00 only once it has run out
+	let seconds = match countdown::get_remaining() {
+		Some(remaining) => remaining.as_ticks().div_ceil(rtc::TICKS_PER_SECOND),
+		None => free(|cs| TIMER_PAGE.borrow(cs).borrow().minutes) as u64 * 60
+	};
+
+	let minutes = (seconds / 60).min(MINUTES_MAX as u64);
+	let seconds = seconds % 60;
+	let digits: [u8; 4] = [
+		(minutes / 10) as u8,
+		(minutes % 10) as u8,
+		(seconds / 10) as u8,
+		(seconds % 10) as u8
+	];
+
+	let on_display = free(|cs| TIMER_PAGE.borrow(cs).borrow().digits_on_display);
+	for i in 0..4 {
+		if digits[i] != on_display[i] {
+			font::write_time_character(digits[i], DIGITS_X[i], DIGITS_Y, DIGIT_COLOR, BACKGROUND_COLOR);
+		}
+	}
+	free(|cs| TIMER_PAGE.borrow(cs).borrow_mut().digits_on_display = digits);
+}
+
+fn write_start() {
+	let (label, color): (&[u8], lcd_api::Color) = if countdown::get_remaining().is_some() {
+		(b"Cancel", CANCEL_COLOR)
+	}
+	else {
+		(b"Start", START_COLOR)
+	};
+
+	let width = label.len() as u16 * ((font::MINIMAL_CHARACTER_WIDTH * 3) + 3);
+	lcd_api::fill_rectangle(0, 240, START_Y, START_HEIGHT, color);
+	font::write_minimal_line(label, (240 - width) / 2, START_Y + 14, TEXT_COLOR, color, 3);
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(d: &mut info::DeviceInfo) {
+	let running = countdown::get_remaining().is_some();
+
+	if d.change_flags.touch_event {
+		match d.touch.gesture {
+			Gesture::SinglePress => {
+				if let Some(minutes) = get_preset(d.touch.x, d.touch.y) {
+					start(minutes);
+				}
+				else if (START_Y..START_Y + START_HEIGHT).contains(&d.touch.y) {
+					toggle();
+				}
+			},
+			Gesture::SlideUp | Gesture::SlideDown if !running => {
+				free(|cs| {
+					let mut timer_page = TIMER_PAGE.borrow(cs).borrow_mut();
+					timer_page.minutes = match d.touch.gesture {
+						Gesture::SlideUp => if timer_page.minutes >= MINUTES_MAX { 1 } else { timer_page.minutes + 1 },
+						_ => if timer_page.minutes <= 1 { MINUTES_MAX } else { timer_page.minutes - 1 }
+					};
+				});
+				write_digits();
+			},
+			Gesture::SlideLeft => {
+				d.app_page = page::AppPage::Home;
+				page::change_page(d);
+				return;
+			},
+			_ => ()
+		}
+	}
+
+	if d.change_flags.button_press && !d.flags.button_pressed {
+		toggle();
+	}
+
+	let refresh_timer = free(|cs| TIMER_PAGE.borrow(cs).borrow().refresh_timer);
+	if let Some(id) = refresh_timer {
+		if soft_timer::take_expired(id) > 0 {
+			write_digits();
+			arm_refresh();
+		}
+	}
+}
@@ -0,0 +1,71 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// app::page::timer_alert.rs
+// Shown when the countdown runs out. A tap or the button dismisses it and
+// goes to the timer page, ready to start the same time again.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use crate::app::{info, page};
+use crate::drivers::countdown;
+use crate::drivers::lcd::{font, lcd_api};
+use crate::drivers::touch::Gesture;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+
+
+//==============================================================================
+// Variables
+//==============================================================================
+const BACKGROUND_COLOR: lcd_api::Color = lcd_api::Color::Rust;
+const TEXT_COLOR: lcd_api::Color = lcd_api::Color::White;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn start_page() {
+	lcd_api::fill_background(BACKGROUND_COLOR);
+
+	font::write_minimal_line(b"Time is up!", 21, 60, TEXT_COLOR, BACKGROUND_COLOR, 3);
+
+	// "25 min"
+	let minutes = countdown::get_duration().as_secs() / 60;
+	let line: [u8; 6] = [
+		if minutes >= 10 { b'0' + ((minutes / 10) % 10) as u8 } else { b' ' },
+		b'0' + (minutes % 10) as u8,
+		b' ',
+		b'm',
+		b'i',
+		b'n'
+	];
+	font::write_minimal_line(&line, 66, 108, TEXT_COLOR, BACKGROUND_COLOR, 3);
+
+	font::write_minimal_line(b"Tap to dismiss", 36, 190, TEXT_COLOR, BACKGROUND_COLOR, 2);
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(d: &mut info::DeviceInfo) {
+	let tapped = d.change_flags.touch_event && matches!(d.touch.gesture, Gesture::SinglePress);
+	let released = d.change_flags.button_press && !d.flags.button_pressed;
+
+	if tapped || released {
+		d.app_page = page::AppPage::Timer;
+		page::change_page(d);
+	}
+}
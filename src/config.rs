@@ -7,6 +7,7 @@
 // Crates and Mods
 //==============================================================================
 use nrf52832_pac::{twi0, spi0, spim0, uart0};
+use crate::drivers::timezone::{self, TimeZone};
 
 //==============================================================================
 // Accelerometer
@@ -19,21 +20,43 @@ use nrf52832_pac::{twi0, spi0, spim0, uart0};
 //==============================================================================
 // Battery 
 //==============================================================================
-pub const ADC_RAM_BUFFER: u32	= 0x2000_FBF0;
 pub const ADC_RAM_BUFFER_LEN: usize	= 8;
 pub const BATTERY_ADC_CHANNEL: u8 	= 7;
 pub const BATTERY_ADC_PIN: u8 		= 31;
 pub const CHARGER_CONNECTED_PIN: u8 = 12;
 
+//==============================================================================
+// Clock
+//==============================================================================
+pub const CLOCK_DEFAULT_UNIX_TIME: u64 = 1609459200;	// 2021-01-01 00:00:00 UTC
+pub const CLOCK_TIME_ZONE: TimeZone = timezone::UTC;
+pub const CLOCK_WORLD_TIME_ZONE: Option<TimeZone> = Some(timezone::NEW_YORK);
+
 //==============================================================================
 // DEBUG
 //==============================================================================
 
 
+//==============================================================================
+// Filesystem
+//==============================================================================
+// Sectors of the SPI flash given over to files, 4KB each
+pub const FS_FLASH_ADDRESS: u32 		= 0;
+pub const FS_FLASH_BLOCKS: u32 		= 768;
+
+//==============================================================================
+// Assets
+//==============================================================================
+// The last 1MB of the SPI flash holds the asset pack, after the files
+pub const ASSETS_FLASH_ADDRESS: u32 	= 0x0030_0000;
+pub const ASSETS_FLASH_SIZE: u32 		= 0x0010_0000;
+
 //==============================================================================
 // Flash
 //==============================================================================
-// pub const FLASH_CS_PIN: u8 = 3;
+// Shares SCLK, MOSI and MISO with the LCD, P0.03 is MOSI
+pub const FLASH_CS_PIN: u8 			= 5;
+pub const FLASH_SIZE: u32 			= 4 * 1024 * 1024;
 
 //==============================================================================
 // I2C
@@ -41,6 +64,7 @@ pub const CHARGER_CONNECTED_PIN: u8 = 12;
 pub const I2C_SCL_PIN: u8 			= 7;
 pub const I2C_SDA_PIN: u8 			= 6;
 pub const I2C_FREQUENCY: twi0::frequency::FREQUENCY_A = twi0::frequency::FREQUENCY_A::K100;
+pub const I2C_TIMEOUT_MS: u32 		= 20;
 
 //==============================================================================
 // LCD
@@ -52,6 +76,13 @@ pub const LCD_CS_PIN: u8 			= 25;
 pub const LCD_DCX_PIN: u8 			= 18;
 pub const LCD_RESET_PIN: u8 		= 26;
 
+//==============================================================================
+// NVMC
+//==============================================================================
+// The last pages of internal flash, kept out of FLASH in memory.x
+pub const NVMC_SETTINGS_ADDRESS: u32 = 0x0007E000;
+pub const NVMC_SETTINGS_PAGES: u32 = 2;
+
 //==============================================================================
 // Push Button
 //==============================================================================
@@ -63,6 +94,11 @@ pub const PUSH_BUTTON_OUT_PIN: u8 	= 15;
 //==============================================================================
 
 
+//==============================================================================
+// Settings
+//==============================================================================
+pub const SETTINGS_SAVE_DELAY_MS: u64 = 2000;
+
 //==============================================================================
 // SPI
 //==============================================================================
@@ -83,9 +119,6 @@ pub const SPIM_FREQUENCY: spim0::frequency::FREQUENCY_A = spim0::frequency::FREQ
 pub const SPIM_ORDER: spim0::config::ORDER_A = spim0::config::ORDER_A::MSBFIRST;
 pub const SPIM_DMA_MAX: u32 = 0x20010000;
 pub const SPIM_DMA_MIN: u32 = 0x20000000;
-pub const SPIM_DMA_RX_PTR: u32 = 0x2000FE00;
-pub const SPIM_DMA_TX_PTR_A: u32 = 0x2000FD00;
-pub const SPIM_DMA_TX_PTR_B: u32 = 0x2000FC00;
 pub const SPIM_DMA_SIZE: u32 = 256;
 
 //==============================================================================
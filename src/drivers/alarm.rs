@@ -0,0 +1,326 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::alarm.rs
+// Alarms on top of drivers::clock. Only the next alarm due is armed, as a one
+// shot software timer, so the RTC wakes the CPU for it even while the loop is
+// sleeping in `wfi`. When it is due the alarm flag is raised for the app to
+// show the ringing page, which then snoozes or dismisses it.
+//
+// An alarm without any repeat days rings once and is then disabled.
+//
+// Alarms are set from the alarm editor page and kept across restarts by
+// drivers::settings, which puts them back with `set` when it loads.
+//
+// Alarm times are local, so the timer is armed again whenever the clock's UTC
+// offset changes at a daylight saving switch.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use crate::app::info;
+use crate::drivers::{calendar, clock};
+use crate::mcu::{rtc, soft_timer};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy, PartialEq)]
+pub struct Alarm {
+	pub time: clock::Time,
+	pub repeat: u8,
+	pub label: [u8; ALARM_LABEL_LEN],
+	pub enabled: bool
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+pub enum AlarmError {
+	InvalidIndex,
+	NoFreeAlarm
+}
+
+struct AlarmState {
+	alarms: [Option<Alarm>; ALARM_COUNT],
+	next: Option<(usize, u64)>,
+	ringing: Option<usize>,
+	snoozed: Option<(usize, u64)>,
+	timer: Option<soft_timer::TimerId>,
+	utc_offset: i64
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+pub const ALARM_COUNT: usize = 4;
+pub const ALARM_LABEL_LEN: usize = 12;
+
+// Repeat masks, bit 0 is Monday
+pub const REPEAT_ONCE: u8 = 0x00;
+pub const REPEAT_WEEKDAYS: u8 = 0x1F;
+pub const REPEAT_WEEKENDS: u8 = 0x60;
+pub const REPEAT_DAILY: u8 = 0x7F;
+
+const SNOOZE_SECONDS: u64 = 9 * 60;
+
+static STATE: Mutex<RefCell<AlarmState>> = Mutex::new(RefCell::new(AlarmState {
+	alarms: [None; ALARM_COUNT],
+	next: None,
+	ringing: None,
+	snoozed: None,
+	timer: None,
+	utc_offset: 0
+}));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl Alarm {
+	// Labels longer than ALARM_LABEL_LEN are cut short
+	pub fn new(time: clock::Time, repeat: u8, label: &[u8]) -> Alarm {
+		let mut alarm = Alarm {
+			time,
+			repeat: repeat & REPEAT_DAILY,
+			label: [0; ALARM_LABEL_LEN],
+			enabled: true
+		};
+
+		let len = label.len().min(ALARM_LABEL_LEN);
+		alarm.label[..len].copy_from_slice(&label[..len]);
+		alarm
+	}
+
+	pub fn get_label(&self) -> &[u8] {
+		let len = self.label.iter().position(|c| *c == 0).unwrap_or(ALARM_LABEL_LEN);
+		&self.label[..len]
+	}
+
+	pub fn get_repeats_on(&self, weekday: calendar::Weekday) -> bool {
+		self.repeat & (1 << weekday as u8) != 0
+	}
+
+	// First time it rings strictly after `now`, both in local Unix seconds
+	pub fn get_next_occurrence(&self, now: u64) -> Option<u64> {
+		if !self.enabled {
+			return None;
+		}
+
+		let today = (now / calendar::SECONDS_PER_DAY) as u32;
+		let seconds = (self.time.hours as u64 * 3600) + (self.time.minutes as u64 * 60);
+
+		// A week and a day covers a weekly repeat whose time today has passed
+		(0..8).map(|offset| today + offset)
+			.filter(|day| self.repeat == REPEAT_ONCE || self.get_repeats_on(calendar::Date::from_days(*day).weekday))
+			.map(|day| (day as u64 * calendar::SECONDS_PER_DAY) + seconds)
+			.find(|at| *at > now)
+	}
+}
+
+#[allow(dead_code)]
+pub fn add(alarm: Alarm) -> Result<usize, AlarmError> {
+	let index = free(|cs| {
+		let mut state = STATE.borrow(cs).borrow_mut();
+		let index = state.alarms.iter().position(|a| a.is_none()).ok_or(AlarmError::NoFreeAlarm)?;
+		state.alarms[index] = Some(alarm);
+		Ok(index)
+	})?;
+
+	schedule();
+	Ok(index)
+}
+
+pub fn get(index: usize) -> Option<Alarm> {
+	free(|cs| STATE.borrow(cs).borrow().alarms.get(index).copied().flatten())
+}
+
+pub fn get_ringing() -> Option<Alarm> {
+	free(|cs| {
+		let state = STATE.borrow(cs).borrow();
+		state.ringing.and_then(|index| state.alarms[index])
+	})
+}
+
+#[allow(dead_code)]
+pub fn remove(index: usize) -> Result<(), AlarmError> {
+	free(|cs| {
+		let mut state = STATE.borrow(cs).borrow_mut();
+		if index >= ALARM_COUNT {
+			return Err(AlarmError::InvalidIndex);
+		}
+
+		state.alarms[index] = None;
+		if state.ringing == Some(index) {
+			state.ringing = None;
+		}
+		if matches!(state.snoozed, Some((snoozed, _)) if snoozed == index) {
+			state.snoozed = None;
+		}
+		Ok(())
+	})?;
+
+	schedule();
+	Ok(())
+}
+
+// Puts the alarm in the given slot, replacing any already there
+pub fn set(index: usize, alarm: Alarm) -> Result<(), AlarmError> {
+	free(|cs| {
+		let mut state = STATE.borrow(cs).borrow_mut();
+		if index >= ALARM_COUNT {
+			return Err(AlarmError::InvalidIndex);
+		}
+
+		state.alarms[index] = Some(alarm);
+		if matches!(state.snoozed, Some((snoozed, _)) if snoozed == index) {
+			state.snoozed = None;
+		}
+		Ok(())
+	})?;
+
+	schedule();
+	Ok(())
+}
+
+#[allow(dead_code)]
+pub fn set_enabled(index: usize, enabled: bool) -> Result<(), AlarmError> {
+	free(|cs| {
+		let mut state = STATE.borrow(cs).borrow_mut();
+		match state.alarms.get_mut(index) {
+			Some(Some(alarm)) => {
+				alarm.enabled = enabled;
+				Ok(())
+			},
+			_ => Err(AlarmError::InvalidIndex)
+		}
+	})?;
+
+	schedule();
+	Ok(())
+}
+
+pub fn dismiss() {
+	free(|cs| {
+		let mut state = STATE.borrow(cs).borrow_mut();
+		state.ringing = None;
+		state.snoozed = None;
+	});
+
+	schedule();
+}
+
+pub fn snooze() {
+	let now = clock::get_local_time();
+
+	free(|cs| {
+		let mut state = STATE.borrow(cs).borrow_mut();
+		if let Some(index) = state.ringing.take() {
+			state.snoozed = Some((index, now + SNOOZE_SECONDS));
+		}
+	});
+
+	schedule();
+}
+
+// Works out the next alarm due and arms the timer for it. Needs calling again
+// whenever the clock is set.
+pub fn schedule() {
+	let now = clock::get_local_time();
+	let utc_offset = clock::get_utc_offset();
+
+	let timer = free(|cs| {
+		let mut state = STATE.borrow(cs).borrow_mut();
+
+		let next = state.alarms.iter().enumerate()
+			.filter_map(|(index, alarm)| alarm.and_then(|a| a.get_next_occurrence(now)).map(|at| (index, at)))
+			.chain(state.snoozed)
+			.min_by_key(|(_, at)| *at);
+
+		state.next = next;
+		state.utc_offset = utc_offset;
+		state.timer.take()
+	});
+
+	if let Some(id) = timer {
+		soft_timer::stop(id);
+	}
+
+	let next = free(|cs| STATE.borrow(cs).borrow().next);
+	if let Some((_, at)) = next {
+		let config = soft_timer::TimerConfig {
+			mode: soft_timer::TimerMode::OneShot,
+			interval: rtc::Duration::from_secs(at.saturating_sub(now).max(1)),
+			callback: None,
+			real_time_callback: false
+		};
+
+		let id = soft_timer::start(config).ok();
+		free(|cs| STATE.borrow(cs).borrow_mut().timer = id);
+	}
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn ring(index: usize) {
+	free(|cs| {
+		let mut state = STATE.borrow(cs).borrow_mut();
+		state.ringing = Some(index);
+
+		if matches!(state.snoozed, Some((snoozed, _)) if snoozed == index) {
+			state.snoozed = None;
+		}
+
+		// A one time alarm is done once it has gone off
+		if let Some(alarm) = state.alarms[index].as_mut() {
+			if alarm.repeat == REPEAT_ONCE {
+				alarm.enabled = false;
+			}
+		}
+	});
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(d: &mut info::DeviceInfo) {
+	if d.change_flags.alarm {
+		d.change_flags.alarm = false;
+	}
+
+	let (timer, next, utc_offset) = free(|cs| {
+		let state = STATE.borrow(cs).borrow();
+		(state.timer, state.next, state.utc_offset)
+	});
+
+	// Local time jumped at a daylight saving switch
+	if d.change_flags.time_change && next.is_some() && clock::get_utc_offset() != utc_offset {
+		schedule();
+		return;
+	}
+
+	let expired = match timer {
+		Some(id) => soft_timer::take_expired(id),
+		None => 0
+	};
+
+	if expired > 0 {
+		free(|cs| STATE.borrow(cs).borrow_mut().timer = None);
+
+		if let Some((index, at)) = next {
+			if clock::get_local_time() >= at {
+				ring(index);
+				d.change_flags.alarm = true;
+			}
+		}
+
+		schedule();
+	}
+}
@@ -0,0 +1,291 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::assets.rs
+// Images and fonts kept in the SPI flash instead of the firmware. The build
+// puts them into a pack (assets/pack.rs): a header, an index of named assets,
+// then each asset as a small header (width, height, pixel format and
+// compression) followed by its data. Assets are streamed out in chunks, so
+// none of them ever has to fit in RAM.
+//
+// A firmware built with the install-assets feature carries the pack and
+// writes it to the flash at start up if what is there differs. The header is
+// programmed last, so a pack cut short by a reset is never taken as good.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::ptr;
+use crate::config;
+use crate::drivers::{flash, log};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AssetError {
+	Corrupt,
+	Flash,
+	NotFound,
+	NotInstalled,
+	OutOfRange,
+	WrongFormat
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PixelFormat {
+	Rgb565 = 0,	// Big-endian, ready for the LCD
+	Mono1 = 1,	// 1 bit per pixel, MSB first, bits run on across rows
+	Font1 = 2,	// Glyph table and 1 bit per pixel bitmaps, read by lcd::font
+	Font2 = 3,	// The same with 2 bit anti-aliased bitmaps
+	Font4 = 4	// The same with 4 bit anti-aliased bitmaps
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Compression {
+	None = 0,
+	Rle = 1		// Packets described in lcd::lcd_api
+}
+
+#[derive(Clone, Copy)]
+pub struct Asset {
+	address: u32,
+	size: u32,
+	width: u16,
+	height: u16,
+	format: PixelFormat,
+	compression: Compression
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+// Images are named by their lcd::images constants
+#[allow(dead_code)]
+pub const TIME_DIGITS: &[u8] = b"time_digits";
+
+// Bytes read from the flash at a time while streaming
+pub const CHUNK_SIZE: usize = 240;
+
+const MAGIC: u32 = 0x3154_5341;
+const HEADER_SIZE: u32 = 16;
+const ENTRY_SIZE: u32 = 24;
+const NAME_SIZE: usize = 16;
+const ASSET_HEADER_SIZE: u32 = 8;
+
+// Number of assets in the installed pack
+static mut ASSET_COUNT: Option<u32> = None;
+
+#[cfg(feature = "install-assets")]
+static PACK: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/assets.bin"));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl Asset {
+	pub fn get_compression(&self) -> Compression {
+		self.compression
+	}
+
+	pub fn get_format(&self) -> PixelFormat {
+		self.format
+	}
+
+	pub fn get_height(&self) -> u16 {
+		self.height
+	}
+
+	// Bytes of pixel data
+	pub fn get_size(&self) -> u32 {
+		self.size
+	}
+
+	pub fn get_width(&self) -> u16 {
+		self.width
+	}
+}
+
+// Must come after flash::init
+pub fn init() {
+	#[cfg(feature = "install-assets")]
+	install();
+
+	match read_header() {
+		Ok(count) => unsafe { *ptr::addr_of_mut!(ASSET_COUNT) = Some(count); },
+		Err(e) => log::push_log(get_error_string(e))
+	}
+
+	let _ = flash::power_down();
+}
+
+pub fn get_error_string(error: AssetError) -> &'static str {
+	match error {
+		AssetError::Corrupt => 			"assets: corrupt",
+		AssetError::Flash => 			"assets: flash failed",
+		AssetError::NotFound => 		"assets: not found",
+		AssetError::NotInstalled => 	"assets: not installed",
+		AssetError::OutOfRange => 		"assets: out of range",
+		AssetError::WrongFormat => 		"assets: wrong format",
+	}
+}
+
+// Looks the asset up in the index by name
+pub fn find(name: &[u8]) -> Result<Asset, AssetError> {
+	let result = find_entry(name);
+	let _ = flash::power_down();
+	result
+}
+
+// Fills buffer with bytes from offset on of the asset's data
+pub fn read(asset: &Asset, offset: u32, buffer: &mut [u8]) -> Result<(), AssetError> {
+	if offset.checked_add(buffer.len() as u32).is_none_or(|end| end > asset.size) {
+		return Err(AssetError::OutOfRange);
+	}
+
+	let result = flash::read(asset.address + offset, buffer).map_err(|_| AssetError::Flash);
+	let _ = flash::power_down();
+	result
+}
+
+// Hands bytes offset to offset + len of the asset's data to f, a chunk at a
+// time, in order
+pub fn stream<F>(asset: &Asset, offset: u32, len: u32, mut f: F) -> Result<(), AssetError>
+where
+	F: FnMut(&[u8])
+{
+	if offset.checked_add(len).is_none_or(|end| end > asset.size) {
+		return Err(AssetError::OutOfRange);
+	}
+
+	let mut buffer = [0u8; CHUNK_SIZE];
+	let mut done: u32 = 0;
+	let result = loop {
+		if done == len {
+			break Ok(());
+		}
+
+		let count = core::cmp::min(CHUNK_SIZE as u32, len - done) as usize;
+		if flash::read(asset.address + offset + done, &mut buffer[..count]).is_err() {
+			break Err(AssetError::Flash);
+		}
+		f(&buffer[..count]);
+		done += count as u32;
+	};
+
+	let _ = flash::power_down();
+	result
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn find_entry(name: &[u8]) -> Result<Asset, AssetError> {
+	let count = unsafe { *ptr::addr_of!(ASSET_COUNT) }.ok_or(AssetError::NotInstalled)?;
+
+	for i in 0..count {
+		let mut entry = [0u8; ENTRY_SIZE as usize];
+		read_pack(HEADER_SIZE + (i * ENTRY_SIZE), &mut entry)?;
+
+		let name_len = entry[..NAME_SIZE].iter().position(|b| *b == 0).unwrap_or(NAME_SIZE);
+		if &entry[..name_len] == name {
+			return read_asset(get_u32(&entry, 16), get_u32(&entry, 20));
+		}
+	}
+
+	Err(AssetError::NotFound)
+}
+
+fn get_u32(bytes: &[u8], index: usize) -> u32 {
+	u32::from_le_bytes([bytes[index], bytes[index + 1], bytes[index + 2], bytes[index + 3]])
+}
+
+fn read_asset(offset: u32, size: u32) -> Result<Asset, AssetError> {
+	let mut header = [0u8; ASSET_HEADER_SIZE as usize];
+	read_pack(offset, &mut header)?;
+
+	let format = match header[4] {
+		0 => PixelFormat::Rgb565,
+		1 => PixelFormat::Mono1,
+		2 => PixelFormat::Font1,
+		3 => PixelFormat::Font2,
+		4 => PixelFormat::Font4,
+		_ => return Err(AssetError::Corrupt)
+	};
+	let compression = match header[5] {
+		0 => Compression::None,
+		1 => Compression::Rle,
+		_ => return Err(AssetError::Corrupt)
+	};
+
+	let address = config::ASSETS_FLASH_ADDRESS + offset + ASSET_HEADER_SIZE;
+	if (address - config::ASSETS_FLASH_ADDRESS).saturating_add(size) > config::ASSETS_FLASH_SIZE {
+		return Err(AssetError::Corrupt);
+	}
+
+	Ok(Asset {
+		address,
+		size,
+		width: u16::from_le_bytes([header[0], header[1]]),
+		height: u16::from_le_bytes([header[2], header[3]]),
+		format,
+		compression
+	})
+}
+
+// Checks the pack header and returns the number of assets
+fn read_header() -> Result<u32, AssetError> {
+	let mut header = [0u8; HEADER_SIZE as usize];
+	read_pack(0, &mut header)?;
+
+	if get_u32(&header, 0) != MAGIC {
+		return Err(AssetError::NotInstalled);
+	}
+
+	let count = get_u32(&header, 4);
+	let size = get_u32(&header, 8);
+	let index_size = count.saturating_mul(ENTRY_SIZE).saturating_add(HEADER_SIZE);
+	if size > config::ASSETS_FLASH_SIZE || index_size > size {
+		return Err(AssetError::Corrupt);
+	}
+
+	Ok(count)
+}
+
+fn read_pack(offset: u32, buffer: &mut [u8]) -> Result<(), AssetError> {
+	flash::read(config::ASSETS_FLASH_ADDRESS + offset, buffer).map_err(|_| AssetError::Flash)
+}
+
+#[cfg(feature = "install-assets")]
+fn install() {
+	// The header carries the CRC of everything after it, so matching headers
+	// mean the pack is already there
+	let mut header = [0u8; HEADER_SIZE as usize];
+	if read_pack(0, &mut header).is_ok() && header[..] == PACK[..HEADER_SIZE as usize] {
+		return;
+	}
+
+	let address = config::ASSETS_FLASH_ADDRESS;
+	let mut result = Ok(());
+	for sector in (0..PACK.len() as u32).step_by(flash::SECTOR_SIZE as usize) {
+		result = result.and_then(|_| flash::erase_sector(address + sector));
+	}
+	let result = result
+		.and_then(|_| flash::program(address + HEADER_SIZE, &PACK[HEADER_SIZE as usize..]))
+		.and_then(|_| flash::program(address, &PACK[..HEADER_SIZE as usize]));
+
+	if let Err(e) = result {
+		log::push_log(flash::get_error_string(e));
+	}
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
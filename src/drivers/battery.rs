@@ -10,7 +10,7 @@
 use crate::app::info;
 use crate::config;
 use super::log;
-use crate::mcu::{adc, gpio, input, rtc};
+use crate::mcu::{adc, gpio, input, rtc, soft_timer};
 
 //==============================================================================
 // Enums, Structs, and Types
@@ -28,7 +28,14 @@ pub enum BatteryLevel{
 //==============================================================================
 // Variables
 //==============================================================================
-const BATTERY_CHECK_INTERVAL: u32 = 30; //60 * 5;	// 5 Minutes
+const BATTERY_CHECK_INTERVAL: rtc::Duration = rtc::Duration::from_secs(30); //60 * 5;	// 5 Minutes
+
+const BATTERY_CHECK_TIMER: soft_timer::TimerConfig = soft_timer::TimerConfig {
+	mode: soft_timer::TimerMode::Periodic,
+	interval: BATTERY_CHECK_INTERVAL,
+	callback: None,
+	real_time_callback: false
+};
 
 const CHARGER_CONNECT_PIN: input::PinConfig = input::PinConfig {
 	pin: config::CHARGER_CONNECTED_PIN,
@@ -39,6 +46,7 @@ const CHARGER_CONNECT_PIN: input::PinConfig = input::PinConfig {
 };
 
 static mut CHARGER_CONNECTED: bool = false;
+static mut BATTERY_TIMER: Option<soft_timer::TimerId> = None;
 
 //==============================================================================
 // Public Functions
@@ -46,6 +54,11 @@ static mut CHARGER_CONNECTED: bool = false;
 pub fn init() {
 	input::init_pin(CHARGER_CONNECT_PIN);
 	connect_handler();
+
+	match soft_timer::start(BATTERY_CHECK_TIMER) {
+		Ok(id) => unsafe { BATTERY_TIMER = Some(id) },
+		Err(_e) => log::push_log("Battery timer failed"),
+	}
 }
 
 //==============================================================================
@@ -101,7 +114,6 @@ fn get_battery_voltage() -> u16 {
 // Task Handler
 //==============================================================================
 pub fn task_handler(d: &mut info::DeviceInfo) {
-	static mut LAST_BATTERY_TIMESTAMP: u32 = 0;
 	static mut LAST_BATTERY_STATE: u8 = 0;
 	static mut LAST_BATTERY_VOLTAGE: u16 = 0;
 	static mut LAST_CHARGER_CONNECTED: bool = false;
@@ -114,8 +126,12 @@ pub fn task_handler(d: &mut info::DeviceInfo) {
 	}
 
 	unsafe {
-		if rtc::get_timediff(LAST_BATTERY_TIMESTAMP) > BATTERY_CHECK_INTERVAL {
-			LAST_BATTERY_TIMESTAMP = rtc::get_timestamp();
+		let check_due = match BATTERY_TIMER {
+			Some(id) => soft_timer::take_expired(id) > 0,
+			None => false
+		};
+
+		if check_due {
 			let tmp_voltage = get_battery_voltage();
 			
 			log::push_log_number("Battery: ", &(tmp_voltage as u32));
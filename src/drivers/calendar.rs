@@ -0,0 +1,358 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::calendar.rs
+// Gregorian calendar arithmetic for the clock. Dates are counted in days from
+// the Unix epoch (1970-01-01, a Thursday) and only use core, so everything in
+// here can be built and checked on the host.
+//
+// Days to and from a date follow the "days_from_civil" algorithms by Howard
+// Hinnant, shifted so the year starts in March and the leap day is always last.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Weekday {
+	Monday,
+	Tuesday,
+	Wednesday,
+	Thursday,
+	Friday,
+	Saturday,
+	Sunday
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Date {
+	pub year: u16,
+	pub month: u8,
+	pub day: u8,
+	pub weekday: Weekday
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Time {
+	pub hours: u8,
+	pub minutes: u8,
+	pub seconds: u8
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+pub const YEAR_MIN: u16 = 1970;
+pub const YEAR_MAX: u16 = 9999;
+
+// 9999-12-31, later timestamps are clamped to it
+const DAYS_MAX: u32 = 2_932_896;
+
+// Days from 0000-03-01 to 1970-01-01
+const DAYS_TO_EPOCH: u32 = 719_468;
+const DAYS_PER_ERA: u32 = 146_097;
+
+const WEEKDAYS: [Weekday; 7] = [
+	Weekday::Monday,
+	Weekday::Tuesday,
+	Weekday::Wednesday,
+	Weekday::Thursday,
+	Weekday::Friday,
+	Weekday::Saturday,
+	Weekday::Sunday
+];
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl Date {
+	pub const EPOCH: Date = Date { year: 1970, month: 1, day: 1, weekday: Weekday::Thursday };
+
+	// None if the date does not exist or is outside YEAR_MIN..=YEAR_MAX
+	pub fn new(year: u16, month: u8, day: u8) -> Option<Date> {
+		if !(YEAR_MIN..=YEAR_MAX).contains(&year) || !(1..=12).contains(&month) ||
+			day < 1 || day > get_days_in_month(year, month) {
+			return None;
+		}
+
+		Some(Date::from_days(days_from_civil(year, month, day)))
+	}
+
+	// Days since the Unix epoch
+	pub fn from_days(days: u32) -> Date {
+		let days = days.min(DAYS_MAX);
+		let (year, month, day) = civil_from_days(days);
+
+		Date {
+			year,
+			month,
+			day,
+			weekday: WEEKDAYS[((days + 3) % 7) as usize]
+		}
+	}
+
+	pub fn as_days(&self) -> u32 {
+		days_from_civil(self.year, self.month, self.day)
+	}
+
+	pub fn get_day_of_year(&self) -> u16 {
+		(self.as_days() - days_from_civil(self.year, 1, 1) + 1) as u16
+	}
+}
+
+#[allow(dead_code)]
+impl Time {
+	pub const MIDNIGHT: Time = Time { hours: 0, minutes: 0, seconds: 0 };
+
+	pub fn new(hours: u8, minutes: u8, seconds: u8) -> Option<Time> {
+		if hours > 23 || minutes > 59 || seconds > 59 {
+			return None;
+		}
+
+		Some(Time { hours, minutes, seconds })
+	}
+
+	// Wraps at midnight
+	pub fn from_seconds(seconds: u64) -> Time {
+		let seconds = seconds % SECONDS_PER_DAY;
+
+		Time {
+			hours: (seconds / 3600) as u8,
+			minutes: ((seconds / 60) % 60) as u8,
+			seconds: (seconds % 60) as u8
+		}
+	}
+
+	pub fn as_seconds(&self) -> u64 {
+		(self.hours as u64 * 3600) + (self.minutes as u64 * 60) + self.seconds as u64
+	}
+}
+
+#[allow(dead_code)]
+pub fn from_unix_time(seconds: u64) -> (Date, Time) {
+	let days = (seconds / SECONDS_PER_DAY).min(DAYS_MAX as u64) as u32;
+	(Date::from_days(days), Time::from_seconds(seconds))
+}
+
+#[allow(dead_code)]
+pub fn to_unix_time(date: &Date, time: &Time) -> u64 {
+	(date.as_days() as u64 * SECONDS_PER_DAY) + time.as_seconds()
+}
+
+// Days since the Unix epoch of the nth (from 1) given weekday in the month
+#[allow(dead_code)]
+pub fn get_nth_weekday(year: u16, month: u8, weekday: Weekday, n: u8) -> u32 {
+	let first = days_from_civil(year, month, 1);
+	let offset = (7 + weekday as u32 - ((first + 3) % 7)) % 7;
+	first + offset + ((n.max(1) as u32 - 1) * 7)
+}
+
+// Days since the Unix epoch of the last given weekday in the month
+#[allow(dead_code)]
+pub fn get_last_weekday(year: u16, month: u8, weekday: Weekday) -> u32 {
+	let last = days_from_civil(year, month, get_days_in_month(year, month));
+	last - ((7 + ((last + 3) % 7) - weekday as u32) % 7)
+}
+
+pub fn is_leap_year(year: u16) -> bool {
+	(year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+pub fn get_days_in_month(year: u16, month: u8) -> u8 {
+	match month {
+		2 if is_leap_year(year) => 29,
+		2 => 28,
+		4 | 6 | 9 | 11 => 30,
+		_ => 31
+	}
+}
+
+#[allow(dead_code)]
+pub fn get_month_string(month: u8) -> &'static str {
+	match month {
+		1 => "Jan",
+		2 => "Feb",
+		3 => "Mar",
+		4 => "Apr",
+		5 => "May",
+		6 => "Jun",
+		7 => "Jul",
+		8 => "Aug",
+		9 => "Sep",
+		10 => "Oct",
+		11 => "Nov",
+		12 => "Dec",
+		_ => "???"
+	}
+}
+
+#[allow(dead_code)]
+pub fn get_weekday_string(weekday: Weekday) -> &'static str {
+	match weekday {
+		Weekday::Monday => "Mon",
+		Weekday::Tuesday => "Tue",
+		Weekday::Wednesday => "Wed",
+		Weekday::Thursday => "Thu",
+		Weekday::Friday => "Fri",
+		Weekday::Saturday => "Sat",
+		Weekday::Sunday => "Sun"
+	}
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn days_from_civil(year: u16, month: u8, day: u8) -> u32 {
+	// March based year, January and February count towards the previous one
+	let year = year as u32 - if month <= 2 { 1 } else { 0 };
+	let month = month as u32;
+	let era = year / 400;
+	let year_of_era = year - (era * 400);
+	let day_of_year = (((153 * if month > 2 { month - 3 } else { month + 9 }) + 2) / 5) + day as u32 - 1;
+	let day_of_era = (year_of_era * 365) + (year_of_era / 4) - (year_of_era / 100) + day_of_year;
+
+	(era * DAYS_PER_ERA) + day_of_era - DAYS_TO_EPOCH
+}
+
+fn civil_from_days(days: u32) -> (u16, u8, u8) {
+	let days = days + DAYS_TO_EPOCH;
+	let era = days / DAYS_PER_ERA;
+	let day_of_era = days - (era * DAYS_PER_ERA);
+	let year_of_era = (day_of_era - (day_of_era / 1460) + (day_of_era / 36524) - (day_of_era / 146_096)) / 365;
+	let day_of_year = day_of_era - ((365 * year_of_era) + (year_of_era / 4) - (year_of_era / 100));
+	let month_from_march = ((5 * day_of_year) + 2) / 153;
+	let day = day_of_year - (((153 * month_from_march) + 2) / 5) + 1;
+	let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+	let year = (year_of_era + (era * 400)) + if month <= 2 { 1 } else { 0 };
+
+	(year as u16, month as u8, day as u8)
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+
+//==============================================================================
+// Tests
+//==============================================================================
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn date(year: u16, month: u8, day: u8) -> Date {
+		Date::new(year, month, day).unwrap()
+	}
+
+	#[test]
+	fn leap_years() {
+		assert!(!is_leap_year(1900));
+		assert!(is_leap_year(2000));
+		assert!(!is_leap_year(2023));
+		assert!(is_leap_year(2024));
+		assert!(!is_leap_year(2100));
+		assert!(is_leap_year(2400));
+	}
+
+	#[test]
+	fn month_lengths() {
+		let lengths = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+		for (month, length) in (1..=12).zip(lengths) {
+			assert_eq!(get_days_in_month(2023, month), length, "month {}", month);
+		}
+		assert_eq!(get_days_in_month(2000, 2), 29);
+		assert_eq!(get_days_in_month(2024, 2), 29);
+		assert_eq!(get_days_in_month(2100, 2), 28);
+
+		assert!(Date::new(2000, 2, 29).is_some());
+		assert!(Date::new(2100, 2, 29).is_none());
+		assert!(Date::new(2023, 4, 31).is_none());
+		assert!(Date::new(2023, 13, 1).is_none());
+		assert!(Date::new(2023, 1, 0).is_none());
+		assert!(Date::new(1969, 12, 31).is_none());
+	}
+
+	#[test]
+	fn weekdays() {
+		assert_eq!(Date::EPOCH, date(1970, 1, 1));
+		assert_eq!(date(1970, 1, 1).weekday, Weekday::Thursday);
+		assert_eq!(date(2000, 1, 1).weekday, Weekday::Saturday);
+		assert_eq!(date(2000, 2, 29).weekday, Weekday::Tuesday);
+		assert_eq!(date(2038, 1, 19).weekday, Weekday::Tuesday);
+		assert_eq!(date(2100, 1, 1).weekday, Weekday::Friday);
+		assert_eq!(date(2100, 3, 1).weekday, Weekday::Monday);
+		assert_eq!(date(9999, 12, 31).weekday, Weekday::Friday);
+
+		// Second Sunday of March and last Sunday of October 2024
+		assert_eq!(Date::from_days(get_nth_weekday(2024, 3, Weekday::Sunday, 2)), date(2024, 3, 10));
+		assert_eq!(Date::from_days(get_last_weekday(2024, 10, Weekday::Sunday)), date(2024, 10, 27));
+	}
+
+	#[test]
+	fn unix_time_round_trips() {
+		let cases = [
+			(0, (1970, 1, 1), (0, 0, 0)),
+			(86_399, (1970, 1, 1), (23, 59, 59)),
+			(86_400, (1970, 1, 2), (0, 0, 0)),
+			(946_684_799, (1999, 12, 31), (23, 59, 59)),
+			(946_684_800, (2000, 1, 1), (0, 0, 0)),
+			(951_782_400, (2000, 2, 29), (0, 0, 0)),
+			(951_868_800, (2000, 3, 1), (0, 0, 0)),
+			(2_147_483_647, (2038, 1, 19), (3, 14, 7)),
+			(2_147_483_648, (2038, 1, 19), (3, 14, 8)),
+			(4_102_444_800, (2100, 1, 1), (0, 0, 0)),
+			(4_107_456_000, (2100, 2, 28), (0, 0, 0)),
+			(4_107_542_400, (2100, 3, 1), (0, 0, 0)),
+			(253_402_300_799, (9999, 12, 31), (23, 59, 59))
+		];
+
+		for (seconds, (year, month, day), (hours, minutes, secs)) in cases {
+			let expected = (date(year, month, day), Time::new(hours, minutes, secs).unwrap());
+			assert_eq!(from_unix_time(seconds), expected, "{}", seconds);
+			assert_eq!(to_unix_time(&expected.0, &expected.1), seconds, "{}", seconds);
+		}
+	}
+
+	#[test]
+	fn every_day_round_trips() {
+		let mut previous = Date::EPOCH;
+		for days in 1..=DAYS_MAX {
+			let date = Date::from_days(days);
+			assert_eq!(date.as_days(), days);
+			assert_eq!(date.weekday, WEEKDAYS[(previous.weekday as usize + 1) % 7]);
+
+			// The day after the last one
+			let next = if previous.day < get_days_in_month(previous.year, previous.month) {
+				(previous.year, previous.month, previous.day + 1)
+			}
+			else if previous.month < 12 {
+				(previous.year, previous.month + 1, 1)
+			}
+			else {
+				(previous.year + 1, 1, 1)
+			};
+			assert_eq!((date.year, date.month, date.day), next, "{}", days);
+			previous = date;
+		}
+		assert_eq!(previous, date(YEAR_MAX, 12, 31));
+	}
+
+	#[test]
+	fn day_of_year() {
+		assert_eq!(date(2000, 1, 1).get_day_of_year(), 1);
+		assert_eq!(date(2000, 12, 31).get_day_of_year(), 366);
+		assert_eq!(date(2100, 12, 31).get_day_of_year(), 365);
+	}
+}
@@ -2,6 +2,12 @@
 // Notes
 //==============================================================================
 // drivers::clock.rs
+// Wall time is kept as the Unix time at a known RTC instant, so the time and
+// date are always worked out from the RTC and cannot drift when seconds are
+// missed. The calendar arithmetic lives in drivers::calendar.
+//
+// Everything kept here is UTC. The time and date shown are local time in the
+// configured time zone, which follows its daylight saving rule on its own.
 
 //==============================================================================
 // Crates and Mods
@@ -9,99 +15,169 @@
 use core::cell::Cell;
 use cortex_m::interrupt::{free, Mutex};
 use crate::app::info;
+use crate::config;
+use crate::drivers::calendar;
+use crate::drivers::timezone::TimeZone;
 use crate::drivers::lcd;
-use crate::mcu::rtc;
+use crate::mcu::{rtc, soft_timer};
 
 //==============================================================================
 // Enums, Structs, and Types
 //==============================================================================
+pub use crate::drivers::calendar::{Date, Time};
+
+// Unix time in seconds at the given RTC instant
 #[derive(Clone, Copy)]
-pub struct Time {
-	pub hours: u8,
-	pub minutes: u8,
-	pub seconds: u8
+struct TimeBase {
+	unix_time: u64,
+	instant: rtc::Instant
 }
 
 //==============================================================================
 // Variables
 //==============================================================================
 static mut DIGITS_ON_DISPLAY: [u8; 6] = [10; 6];	// Init at 10 to force write the first time
-static TIME: Mutex<Cell<Time>> = Mutex::new(Cell::new( Time {
-	hours: 0,
-	minutes: 0,
-	seconds: 0
+static mut DATE_ON_DISPLAY: Option<Date> = None;
+static mut WORLD_TIME_ON_DISPLAY: Option<[u8; WORLD_TIME_LEN]> = None;
+static TIME_ZONE: Mutex<Cell<TimeZone>> = Mutex::new(Cell::new(config::CLOCK_TIME_ZONE));
+static WORLD_TIME_ZONE: Mutex<Cell<Option<TimeZone>>> = Mutex::new(Cell::new(config::CLOCK_WORLD_TIME_ZONE));
+static TIME_BASE: Mutex<Cell<TimeBase>> = Mutex::new(Cell::new(TimeBase {
+	unix_time: config::CLOCK_DEFAULT_UNIX_TIME,
+	instant: rtc::Instant::from_ticks(0)
 }));
 
+const SECOND_TIMER: soft_timer::TimerConfig = soft_timer::TimerConfig {
+	mode: soft_timer::TimerMode::Periodic,
+	interval: rtc::Duration::from_secs(1),
+	callback: None,
+	real_time_callback: false
+};
+static SECOND_TIMER_ID: Mutex<Cell<Option<soft_timer::TimerId>>> = Mutex::new(Cell::new(None));
+
 const BLOCK_LARGE_COLOR: lcd::lcd_api::Color = lcd::lcd_api::Color::Gray;
 const BLOCK_SMALL_COLOR: lcd::lcd_api::Color = lcd::lcd_api::Color::GrayDark;
 const DIGITS_X: [u16; 4] = [ 35, 75, 125, 165];
 const DIGITS_Y: [u16; 4] = [ 120; 4 ];
+const DATE_Y: u16 = 198;
+const DATE_SCALE: u16 = 2;
+const DATE_LEN: usize = 10;
+const WORLD_TIME_Y: u16 = 80;
+const WORLD_TIME_SCALE: u16 = 2;
+const WORLD_TIME_NAME_LEN: usize = 4;
+const WORLD_TIME_LEN: usize = WORLD_TIME_NAME_LEN + 9;
 
+// Center, radius and color of the dots around the edge
 const BLOCKS: [(u16, u16, u16, lcd::lcd_api::Color); 12] = [
-	(45, 0, 4, BLOCK_LARGE_COLOR),
-	(115, 0, 8, BLOCK_SMALL_COLOR),
-	(189, 0, 4, BLOCK_SMALL_COLOR),
+	(47, 2, 2, BLOCK_LARGE_COLOR),
+	(119, 4, 4, BLOCK_SMALL_COLOR),
+	(191, 2, 2, BLOCK_SMALL_COLOR),
 	
-	(0, 45, 4, BLOCK_SMALL_COLOR),
-	(235, 45, 4, BLOCK_LARGE_COLOR),
+	(2, 47, 2, BLOCK_SMALL_COLOR),
+	(237, 47, 2, BLOCK_LARGE_COLOR),
 	
-	(0, 115, 8, BLOCK_SMALL_COLOR),
-	(231, 115, 8, BLOCK_SMALL_COLOR),
+	(4, 119, 4, BLOCK_SMALL_COLOR),
+	(235, 119, 4, BLOCK_SMALL_COLOR),
 	
-	(0, 189, 4, BLOCK_LARGE_COLOR),
-	(235, 189, 4, BLOCK_SMALL_COLOR),
+	(2, 191, 2, BLOCK_LARGE_COLOR),
+	(237, 191, 2, BLOCK_SMALL_COLOR),
 	
-	(45, 235, 4, BLOCK_SMALL_COLOR),
-	(115, 231, 8, BLOCK_SMALL_COLOR),
-	(189, 235, 4, BLOCK_LARGE_COLOR),
+	(47, 237, 2, BLOCK_SMALL_COLOR),
+	(119, 235, 4, BLOCK_SMALL_COLOR),
+	(191, 237, 2, BLOCK_LARGE_COLOR),
 ];
 
 //==============================================================================
 // Public Functions
 //==============================================================================
 pub fn init() {
-	free(|cs| {
-		let mut time = TIME.borrow(cs).get();
-		time.seconds += 1;
-
-		if time.seconds >= 60 {
-			time.seconds = 0;
-			time.minutes += 1;
-		
-			if time.minutes >= 60 {
-				time.minutes = 0;
-				time.hours += 1;
-				
-				if time.hours >= 24 {
-					time.hours = 0;
-				}
-			}
-		}
+	set_unix_time(config::CLOCK_DEFAULT_UNIX_TIME);
+
+	if let Ok(id) = soft_timer::start(SECOND_TIMER) {
+		free(|cs| SECOND_TIMER_ID.borrow(cs).set(Some(id)));
+	}
+}
+
+// Local date
+pub fn get_date() -> Date {
+	calendar::from_unix_time(get_local_time()).0
+}
+
+// Local time of day
+pub fn get_time() -> Time {
+	calendar::from_unix_time(get_local_time()).1
+}
+
+// Unix time shifted to the local time zone
+pub fn get_local_time() -> u64 {
+	let zone = free(|cs| TIME_ZONE.borrow(cs).get());
+	zone.to_local(get_unix_time())
+}
+
+#[allow(dead_code)]
+pub fn get_time_zone() -> TimeZone {
+	free(|cs| TIME_ZONE.borrow(cs).get())
+}
+
+// Seconds local time is ahead of UTC right now
+pub fn get_utc_offset() -> i64 {
+	let zone = free(|cs| TIME_ZONE.borrow(cs).get());
+	zone.get_offset(get_unix_time())
+}
 
-		TIME.borrow(cs).set(time);
-	});
+pub fn get_unix_time() -> u64 {
+	let base = free(|cs| TIME_BASE.borrow(cs).get());
+	base.unix_time.saturating_add(base.instant.elapsed().as_secs())
+}
+
+#[allow(dead_code)]
+pub fn get_world_time_zone() -> Option<TimeZone> {
+	free(|cs| WORLD_TIME_ZONE.borrow(cs).get())
+}
+
+pub fn set_local_time(seconds: u64) {
+	let zone = free(|cs| TIME_ZONE.borrow(cs).get());
+	set_unix_time(zone.to_utc(seconds));
+}
+
+#[allow(dead_code)]
+pub fn set_time_zone(zone: TimeZone) {
+	free(|cs| TIME_ZONE.borrow(cs).set(zone));
+}
+
+pub fn set_unix_time(seconds: u64) {
+	free(|cs| TIME_BASE.borrow(cs).set(TimeBase {
+		unix_time: seconds,
+		instant: rtc::Instant::now()
+	}));
+}
+
+#[allow(dead_code)]
+pub fn set_world_time_zone(zone: Option<TimeZone>) {
+	free(|cs| WORLD_TIME_ZONE.borrow(cs).set(zone));
 }
 
 pub fn update_time(is_military_time: bool) {
-	write(get_digits(is_military_time), false);
+	write(get_digits(is_military_time), get_date(), false);
+	write_world_time(is_military_time, false);
 }
 
 pub fn write_analog() {
 	for block in BLOCKS.iter() {
-		let (x, y, size, color) = block;
-		lcd::lcd_api::fill_rectangle(*x, *size, *y, *size, *color);
+		let (x, y, radius, color) = block;
+		lcd::shapes::fill_circle(*x, *y, *radius, *color);
 	}
 }
 
 pub fn write_time(is_military_time: bool) {
-	write(get_digits(is_military_time), true);
+	write(get_digits(is_military_time), get_date(), true);
+	write_world_time(is_military_time, true);
 }
 
 //==============================================================================
 // Private Functions
 //==============================================================================
 fn get_digits(is_military_time: bool) -> [u8; 4] {
-	let time = free(|cs| TIME.borrow(cs).get());
+	let time = get_time();
 	let mut hours = time.hours;
 
 	// Bring in military time flag as needed
@@ -121,33 +197,33 @@ fn get_digits(is_military_time: bool) -> [u8; 4] {
 	]
 }
 
-fn update_add_second() -> bool {
-	let mut update_needed: bool = false;
-	free(|cs| {
-		let mut time = TIME.borrow(cs).get();
-		time.seconds += 1;
-
-		if time.seconds >= 60 {
-			update_needed = true;
-			time.seconds = 0;
-			time.minutes += 1;
-		
-			if time.minutes >= 60 {
-				time.minutes = 0;
-				time.hours += 1;
-				
-				if time.hours >= 24 {
-					time.hours = 0;
-				}
-			}
-		}
+fn get_date_line(date: &Date) -> ([u8; DATE_LEN], usize) {
+	// "Mon 1 Jan" or "Mon 31 Jan"
+	let mut line = [b' '; DATE_LEN];
+	let mut len = 0;
+
+	for c in calendar::get_weekday_string(date.weekday).bytes() {
+		line[len] = c;
+		len += 1;
+	}
+	len += 1;
+
+	if date.day >= 10 {
+		line[len] = b'0' + (date.day / 10);
+		len += 1;
+	}
+	line[len] = b'0' + (date.day % 10);
+	len += 2;
 
-		TIME.borrow(cs).set(time);
-	});
-	update_needed
+	for c in calendar::get_month_string(date.month).bytes() {
+		line[len] = c;
+		len += 1;
+	}
+
+	(line, len)
 }
 
-fn write(digits: [u8; 4], force_update: bool) {
+fn write(digits: [u8; 4], date: Date, force_update: bool) {
 	if force_update {
 		write_analog();
 	}
@@ -166,6 +242,71 @@ fn write(digits: [u8; 4], force_update: bool) {
 			}
 		}
 	}
+
+	unsafe {
+		if force_update || DATE_ON_DISPLAY != Some(date) {
+			let (line, len) = get_date_line(&date);
+			let char_width = (lcd::font::MINIMAL_CHARACTER_WIDTH * DATE_SCALE) + DATE_SCALE;
+			let x = (240 - (len as u16 * char_width)) / 2;
+
+			// Lines differ in length, clear whatever the last one left behind
+			lcd::lcd_api::fill_rectangle(5, 230, DATE_Y, lcd::font::MINIMAL_CHARACTER_HEIGHT * DATE_SCALE, lcd::lcd_api::Color::Black);
+			lcd::font::write_minimal_line(&line[..len], x, DATE_Y, BLOCK_SMALL_COLOR, lcd::lcd_api::Color::Black, DATE_SCALE);
+
+			DATE_ON_DISPLAY = Some(date);
+		}
+	}
+}
+
+fn get_world_time_line(zone: &TimeZone, is_military_time: bool) -> [u8; WORLD_TIME_LEN] {
+	// "NYC  19:00" or "NYC   7:00 PM"
+	let time = calendar::from_unix_time(zone.to_local(get_unix_time())).1;
+	let mut line = [b' '; WORLD_TIME_LEN];
+
+	let name = zone.name.as_bytes();
+	let len = name.len().min(WORLD_TIME_NAME_LEN);
+	line[..len].copy_from_slice(&name[..len]);
+
+	let mut hours = time.hours;
+	if !is_military_time {
+		hours %= 12;
+		if hours == 0 {
+			hours = 12;
+		}
+	}
+
+	let digits = &mut line[WORLD_TIME_NAME_LEN + 1..];
+	if hours >= 10 || is_military_time {
+		digits[0] = b'0' + (hours / 10);
+	}
+	digits[1] = b'0' + (hours % 10);
+	digits[2] = b':';
+	digits[3] = b'0' + (time.minutes / 10);
+	digits[4] = b'0' + (time.minutes % 10);
+	if !is_military_time {
+		digits[6..8].copy_from_slice(if time.hours < 12 { b"AM" } else { b"PM" });
+	}
+
+	line
+}
+
+fn write_world_time(is_military_time: bool, force_update: bool) {
+	let zone = match free(|cs| WORLD_TIME_ZONE.borrow(cs).get()) {
+		Some(zone) => zone,
+		None => return
+	};
+	let line = get_world_time_line(&zone, is_military_time);
+
+	unsafe {
+		if force_update || WORLD_TIME_ON_DISPLAY != Some(line) {
+			let char_width = (lcd::font::MINIMAL_CHARACTER_WIDTH * WORLD_TIME_SCALE) + WORLD_TIME_SCALE;
+			let x = (240 - (WORLD_TIME_LEN as u16 * char_width)) / 2;
+
+			lcd::font::write_minimal_line(&line, x, WORLD_TIME_Y, BLOCK_SMALL_COLOR, lcd::lcd_api::Color::Black, WORLD_TIME_SCALE);
+
+			WORLD_TIME_ON_DISPLAY = Some(line);
+		}
+	}
 }
 
 //==============================================================================
@@ -177,19 +318,22 @@ fn write(digits: [u8; 4], force_update: bool) {
 // Task Handler
 //==============================================================================
 pub fn task_handler(d: &mut info::DeviceInfo) {
-	static mut LAST_TIMESTAMP: u32 = 0;
-
 	if d.change_flags.time_change {
 		d.change_flags.time_change = false;
 	}
 
-	unsafe {
-		if rtc::get_timediff(LAST_TIMESTAMP) >= 1 {
-			LAST_TIMESTAMP = rtc::get_timestamp();
-			if update_add_second() {
-				d.change_flags.time_change = true;
-				d.time = free(|cs| TIME.borrow(cs).get());
-			}
+	let expired = match free(|cs| SECOND_TIMER_ID.borrow(cs).get()) {
+		Some(id) => soft_timer::take_expired(id),
+		None => 0
+	};
+
+	// However many seconds went by, only a new minute needs redrawing
+	if expired > 0 {
+		let (date, time) = calendar::from_unix_time(get_local_time());
+		if time.minutes != d.time.minutes || time.hours != d.time.hours || date != d.date {
+			d.change_flags.time_change = true;
 		}
+		d.date = date;
+		d.time = time;
 	}
 }
\ No newline at end of file
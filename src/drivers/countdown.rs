@@ -0,0 +1,109 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::countdown.rs
+// Countdown timer. The end is a one shot software timer, so it keeps counting
+// whatever page is shown and wakes the CPU from `wfi` when it runs out. The
+// countdown flag is then raised for the app to show the alert page.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use crate::app::info;
+use crate::mcu::{rtc, soft_timer};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+struct Countdown {
+	deadline: Option<rtc::Instant>,
+	duration: rtc::Duration,
+	timer: Option<soft_timer::TimerId>
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+static COUNTDOWN: Mutex<RefCell<Countdown>> = Mutex::new(RefCell::new(Countdown {
+	deadline: None,
+	duration: rtc::Duration::ZERO,
+	timer: None
+}));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn cancel() {
+	let timer = free(|cs| {
+		let mut countdown = COUNTDOWN.borrow(cs).borrow_mut();
+		countdown.deadline = None;
+		countdown.timer.take()
+	});
+
+	if let Some(id) = timer {
+		soft_timer::stop(id);
+	}
+}
+
+// What the last countdown was started with
+pub fn get_duration() -> rtc::Duration {
+	free(|cs| COUNTDOWN.borrow(cs).borrow().duration)
+}
+
+// None when no countdown is running
+pub fn get_remaining() -> Option<rtc::Duration> {
+	free(|cs| COUNTDOWN.borrow(cs).borrow().deadline).map(|deadline| deadline.remaining())
+}
+
+pub fn start(duration: rtc::Duration) -> Result<(), soft_timer::SoftTimerError> {
+	cancel();
+
+	let id = soft_timer::start(soft_timer::TimerConfig {
+		mode: soft_timer::TimerMode::OneShot,
+		interval: duration,
+		callback: None,
+		real_time_callback: false
+	})?;
+
+	free(|cs| {
+		let mut countdown = COUNTDOWN.borrow(cs).borrow_mut();
+		countdown.deadline = Some(rtc::Instant::after(duration));
+		countdown.duration = duration;
+		countdown.timer = Some(id);
+	});
+
+	Ok(())
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(d: &mut info::DeviceInfo) {
+	if d.change_flags.countdown {
+		d.change_flags.countdown = false;
+	}
+
+	let timer = free(|cs| COUNTDOWN.borrow(cs).borrow().timer);
+	if let Some(id) = timer {
+		if soft_timer::take_expired(id) > 0 {
+			free(|cs| {
+				let mut countdown = COUNTDOWN.borrow(cs).borrow_mut();
+				countdown.deadline = None;
+				countdown.timer = None;
+			});
+			d.change_flags.countdown = true;
+		}
+	}
+}
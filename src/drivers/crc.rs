@@ -0,0 +1,69 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::crc.rs
+// CRC-32 (IEEE 802.3, as used by zlib), worked out bit by bit to keep it
+// small. Used to tell whole records in flash from ones cut short by a reset.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy)]
+pub struct Crc32 {
+	crc: u32
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl Crc32 {
+	pub fn new() -> Crc32 {
+		Crc32 { crc: 0xFFFF_FFFF }
+	}
+
+	pub fn update(&mut self, bytes: &[u8]) {
+		for byte in bytes {
+			self.crc ^= *byte as u32;
+			for _ in 0..8 {
+				let mask = (self.crc & 1).wrapping_neg();
+				self.crc = (self.crc >> 1) ^ (POLYNOMIAL & mask);
+			}
+		}
+	}
+
+	pub fn get_value(&self) -> u32 {
+		!self.crc
+	}
+}
+
+#[allow(dead_code)]
+pub fn get_crc32(bytes: &[u8]) -> u32 {
+	let mut crc = Crc32::new();
+	crc.update(bytes);
+	crc.get_value()
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
@@ -0,0 +1,304 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::flash.rs
+// The 4MB SPI NOR flash (XT25F32B) on the same SPI bus as the LCD. Every
+// command waits for queued display DMA to finish before taking chip select, and
+// releases it before returning, so the LCD never sees flash traffic and the
+// flash never sees pixels.
+//
+// The chip is left in deep power-down after `init`. Any command wakes it
+// first; call `power_down` once done with it to get back to ~1uA.
+//
+// Erased bytes read 0xFF. Programming only clears bits, so an area has to be
+// erased, by 4KB sector or 64KB block, before it can be written again.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::Cell;
+use cortex_m::asm;
+use cortex_m::interrupt::{free, Mutex};
+use crate::config;
+use crate::drivers::log;
+use crate::mcu::{gpio, rtc, spim};
+use nrf52832_pac::p0::pin_cnf::DIR_A as DIR;
+use nrf52832_pac::p0::pin_cnf::PULL_A as PULL;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+pub enum FlashError {
+	Alignment,
+	NotFound,
+	OutOfRange,
+	Spi,
+	Timeout
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JedecId {
+	pub manufacturer: u8,
+	pub memory_type: u8,
+	pub capacity: u8
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+enum Command {
+	WriteEnable = 0x06,
+	ReadStatus = 0x05,
+	ReadData = 0x03,
+	PageProgram = 0x02,
+	SectorErase = 0x20,
+	BlockErase = 0xD8,
+	ReadJedecId = 0x9F,
+	PowerDown = 0xB9,
+	ReleasePowerDown = 0xAB
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+pub const PAGE_SIZE: u32 = 256;
+pub const SECTOR_SIZE: u32 = 4096;
+pub const BLOCK_SIZE: u32 = 65536;
+
+const STATUS_BUSY: u8 = 0x01;
+
+// Worst case times from the datasheet, with some margin
+const PROGRAM_TIMEOUT: rtc::Duration = rtc::Duration::from_millis(5);
+const SECTOR_ERASE_TIMEOUT: rtc::Duration = rtc::Duration::from_millis(500);
+const BLOCK_ERASE_TIMEOUT: rtc::Duration = rtc::Duration::from_millis(3000);
+
+// tRES1 is 20us at most, 64MHz
+const RELEASE_POWER_DOWN_CYCLES: u32 = 64 * 20;
+
+// Largest piece read or written in one go, below the SPIM DMA limit
+const CHUNK_SIZE: usize = 252;
+
+static POWERED_DOWN: Mutex<Cell<bool>> = Mutex::new(Cell::new(true));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn init() {
+	gpio::pin_setup(config::FLASH_CS_PIN, DIR::OUTPUT, gpio::PinState::PinHigh, PULL::DISABLED);
+
+	// Whatever state a reset left it in, the release command wakes it
+	free(|cs| POWERED_DOWN.borrow(cs).set(true));
+
+	match read_jedec_id() {
+		Ok(_) => (),
+		Err(e) => log::push_log(get_error_string(e))
+	}
+
+	let _ = power_down();
+}
+
+#[allow(dead_code)]
+pub fn get_error_string(error: FlashError) -> &'static str {
+	match error {
+		FlashError::Alignment => 	"flash: address not aligned",
+		FlashError::NotFound => 	"flash: no chip found",
+		FlashError::OutOfRange => 	"flash: address out of range",
+		FlashError::Spi => 			"flash: spi transfer failed",
+		FlashError::Timeout => 		"flash: timeout",
+	}
+}
+
+// Erases the 64KB block holding `address`, which must be block aligned
+#[allow(dead_code)]
+pub fn erase_block(address: u32) -> Result<(), FlashError> {
+	erase(Command::BlockErase, address, BLOCK_SIZE, BLOCK_ERASE_TIMEOUT)
+}
+
+// Erases the 4KB sector holding `address`, which must be sector aligned
+#[allow(dead_code)]
+pub fn erase_sector(address: u32) -> Result<(), FlashError> {
+	erase(Command::SectorErase, address, SECTOR_SIZE, SECTOR_ERASE_TIMEOUT)
+}
+
+#[allow(dead_code)]
+pub fn get_powered_down() -> bool {
+	free(|cs| POWERED_DOWN.borrow(cs).get())
+}
+
+#[allow(dead_code)]
+pub fn power_down() -> Result<(), FlashError> {
+	if get_powered_down() {
+		return Ok(());
+	}
+
+	command(&[Command::PowerDown as u8], &mut [])?;
+	free(|cs| POWERED_DOWN.borrow(cs).set(true));
+	Ok(())
+}
+
+// Programs `data` from `address`, split wherever it crosses a 256B page
+#[allow(dead_code)]
+pub fn program(address: u32, data: &[u8]) -> Result<(), FlashError> {
+	check_range(address, data.len() as u32)?;
+
+	let mut address = address;
+	let mut data = data;
+	while !data.is_empty() {
+		let page_left = (PAGE_SIZE - (address % PAGE_SIZE)) as usize;
+		let (page, rest) = data.split_at(page_left.min(data.len()));
+		program_page(address, page)?;
+
+		address += page.len() as u32;
+		data = rest;
+	}
+	Ok(())
+}
+
+// Programs up to a page, `data` must not run past the end of the page
+#[allow(dead_code)]
+pub fn program_page(address: u32, data: &[u8]) -> Result<(), FlashError> {
+	check_range(address, data.len() as u32)?;
+	if (address % PAGE_SIZE) + data.len() as u32 > PAGE_SIZE {
+		return Err(FlashError::Alignment);
+	}
+	if data.is_empty() {
+		return Ok(());
+	}
+
+	release_power_down()?;
+	command(&[Command::WriteEnable as u8], &mut [])?;
+
+	let header = get_address_command(Command::PageProgram, address);
+	select();
+	let result = spim::write(&header)
+		.and_then(|_| data.chunks(CHUNK_SIZE).try_for_each(spim::write))
+		.map_err(|_| FlashError::Spi);
+	deselect();
+	result?;
+
+	wait_ready(PROGRAM_TIMEOUT)
+}
+
+#[allow(dead_code)]
+pub fn read(address: u32, buffer: &mut [u8]) -> Result<(), FlashError> {
+	check_range(address, buffer.len() as u32)?;
+	release_power_down()?;
+
+	let mut header = get_address_command(Command::ReadData, address);
+	select();
+	let result = spim::transfer(&mut header)
+		.and_then(|_| buffer.chunks_mut(CHUNK_SIZE).try_for_each(spim::transfer))
+		.map_err(|_| FlashError::Spi);
+	deselect();
+	result
+}
+
+pub fn read_jedec_id() -> Result<JedecId, FlashError> {
+	release_power_down()?;
+
+	let mut id = [0; 3];
+	command(&[Command::ReadJedecId as u8], &mut id)?;
+
+	// A missing chip leaves MISO floating high, or low if something holds it
+	if id[0] == 0x00 || id[0] == 0xFF {
+		return Err(FlashError::NotFound);
+	}
+
+	Ok(JedecId {
+		manufacturer: id[0],
+		memory_type: id[1],
+		capacity: id[2]
+	})
+}
+
+#[allow(dead_code)]
+pub fn read_status() -> Result<u8, FlashError> {
+	release_power_down()?;
+
+	let mut status = [0];
+	command(&[Command::ReadStatus as u8], &mut status)?;
+	Ok(status[0])
+}
+
+#[allow(dead_code)]
+pub fn release_power_down() -> Result<(), FlashError> {
+	if !get_powered_down() {
+		return Ok(());
+	}
+
+	command(&[Command::ReleasePowerDown as u8], &mut [])?;
+	asm::delay(RELEASE_POWER_DOWN_CYCLES);
+	free(|cs| POWERED_DOWN.borrow(cs).set(false));
+	Ok(())
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn check_range(address: u32, len: u32) -> Result<(), FlashError> {
+	match address.checked_add(len) {
+		Some(end) if end <= config::FLASH_SIZE => Ok(()),
+		_ => Err(FlashError::OutOfRange)
+	}
+}
+
+// Sends `tx` then clocks in `rx.len()` bytes, all under one chip select
+fn command(tx: &[u8], rx: &mut [u8]) -> Result<(), FlashError> {
+	select();
+	let result = spim::write(tx)
+		.and_then(|_| if rx.is_empty() { Ok(()) } else { spim::transfer(rx) })
+		.map_err(|_| FlashError::Spi);
+	deselect();
+	result
+}
+
+fn deselect() {
+	gpio::set_pin_state(config::FLASH_CS_PIN, gpio::PinState::PinHigh);
+}
+
+fn erase(erase: Command, address: u32, size: u32, timeout: rtc::Duration) -> Result<(), FlashError> {
+	if !address.is_multiple_of(size) {
+		return Err(FlashError::Alignment);
+	}
+	check_range(address, size)?;
+
+	release_power_down()?;
+	command(&[Command::WriteEnable as u8], &mut [])?;
+	command(&get_address_command(erase, address), &mut [])?;
+	wait_ready(timeout)
+}
+
+fn get_address_command(command: Command, address: u32) -> [u8; 4] {
+	let address = address.to_be_bytes();
+	[command as u8, address[1], address[2], address[3]]
+}
+
+fn select() {
+	// Queued pixels hold the LCD selected until they are out
+	spim::wait_idle();
+	gpio::set_pin_state(config::FLASH_CS_PIN, gpio::PinState::PinLow);
+}
+
+fn wait_ready(timeout: rtc::Duration) -> Result<(), FlashError> {
+	let deadline = rtc::Instant::after(timeout);
+	loop {
+		let mut status = [0];
+		command(&[Command::ReadStatus as u8], &mut status)?;
+		if status[0] & STATUS_BUSY == 0 {
+			return Ok(());
+		}
+		if deadline.has_passed() {
+			return Err(FlashError::Timeout);
+		}
+	}
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
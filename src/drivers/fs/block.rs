@@ -0,0 +1,99 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::fs::block.rs
+// What the filesystem needs from the storage under it: 4KB blocks that are
+// erased to 0xFF as a whole and then programmed, where programming can only
+// clear bits. The SPI NOR is one, a RamDevice is another for running the
+// filesystem on the host.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use super::FsError;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+pub trait BlockDevice {
+	fn get_block_count(&self) -> u32;
+
+	fn read(&mut self, block: u32, offset: u32, buffer: &mut [u8]) -> Result<(), FsError>;
+
+	// Only ever clears bits, like the flash it stands for
+	fn program(&mut self, block: u32, offset: u32, data: &[u8]) -> Result<(), FsError>;
+
+	fn erase(&mut self, block: u32) -> Result<(), FsError>;
+}
+
+// Blocks held in borrowed memory, which is left as it is so a filesystem can
+// be mounted again from what an earlier one wrote
+#[allow(dead_code)]
+pub struct RamDevice<'a> {
+	memory: &'a mut [u8]
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+pub const BLOCK_SIZE: u32 = 4096;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl<'a> RamDevice<'a> {
+	pub fn new(memory: &'a mut [u8]) -> Self {
+		assert!(memory.len().is_multiple_of(BLOCK_SIZE as usize));
+
+		RamDevice { memory }
+	}
+
+	pub fn get_memory(&self) -> &[u8] {
+		self.memory
+	}
+}
+
+impl<'a> BlockDevice for RamDevice<'a> {
+	fn get_block_count(&self) -> u32 {
+		(self.memory.len() / BLOCK_SIZE as usize) as u32
+	}
+
+	fn read(&mut self, block: u32, offset: u32, buffer: &mut [u8]) -> Result<(), FsError> {
+		check_access(self, block, offset, buffer.len())?;
+		let start = get_address(block, offset);
+		buffer.copy_from_slice(&self.memory[start..start + buffer.len()]);
+		Ok(())
+	}
+
+	fn program(&mut self, block: u32, offset: u32, data: &[u8]) -> Result<(), FsError> {
+		check_access(self, block, offset, data.len())?;
+		let start = get_address(block, offset);
+		for (byte, value) in self.memory[start..start + data.len()].iter_mut().zip(data) {
+			*byte &= *value;
+		}
+		Ok(())
+	}
+
+	fn erase(&mut self, block: u32) -> Result<(), FsError> {
+		check_access(self, block, 0, BLOCK_SIZE as usize)?;
+		let start = get_address(block, 0);
+		self.memory[start..start + BLOCK_SIZE as usize].fill(0xFF);
+		Ok(())
+	}
+}
+
+// For devices to turn away anything outside of a block
+pub fn check_access<D: BlockDevice>(device: &D, block: u32, offset: u32, len: usize) -> Result<(), FsError> {
+	if block >= device.get_block_count() || offset as usize + len > BLOCK_SIZE as usize {
+		return Err(FsError::Device);
+	}
+	Ok(())
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn get_address(block: u32, offset: u32) -> usize {
+	(block * BLOCK_SIZE + offset) as usize
+}
@@ -0,0 +1,1039 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::fs::filesystem.rs
+// The filesystem itself, over any BlockDevice of 4KB blocks. Only core is
+// used, so it runs the same on the watch and on the host with a RamDevice.
+//
+// One block holds the metadata, a log of fixed size entries after a header:
+//	header:	META_MAGIC, revision, CRC-32 of the two, erased
+//	entry:	tag, id, tail block, size, name, CRC-32 of the rest
+// Each change to a file appends one entry. When the block is full the
+// current state is written to a free block, header last, with the revision
+// one higher. Only then is the old block let go, so the block with a good
+// header and the highest revision always holds a whole state. The metadata
+// moves round the device like file data does, rather than wearing out two
+// fixed blocks, and mount finds it by reading the header of every block.
+// One free block is always kept back so there is somewhere to move it to,
+// even on a full device.
+//
+// File data lives in the other blocks, chained backwards like littlefs:
+//	header:	DATA_MAGIC, previous block, CRC-32 of the two, erased
+//	data:	DATA_SIZE bytes
+// A file's entry names its last block and its size, which together say how
+// many blocks there are and how full the last one is. New data goes into the
+// erased end of the last block or into new blocks, and only counts once the
+// entry recording the new size is in. A write cut short by a reset leaves
+// the old size in place; the half written bytes are left behind by moving
+// the last block on the next append. Writing a whole file builds a new chain
+// and switches to it with a single entry.
+//
+// Blocks are handed out round the device from a cursor, so erases are spread
+// over all of them rather than the lowest free ones. Which blocks are free is
+// worked out at mount by walking every file's chain.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use crate::drivers::crc;
+use super::block::{BlockDevice, BLOCK_SIZE};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FsError {
+	Corrupt,
+	Device,
+	Full,
+	InvalidName,
+	NoFileSystem,
+	NotFound,
+	NotMounted,
+	TooManyFiles,
+	TooSmall
+}
+
+#[derive(Clone, Copy)]
+pub struct FileInfo {
+	id: u16,
+	name: [u8; NAME_LEN],
+	name_len: u8,
+	tail: u32,
+	size: u32
+}
+
+// Read position in an open file. Stays valid while the file is written to,
+// reads after a delete fail with NotFound.
+#[derive(Clone, Copy)]
+pub struct File {
+	id: u16,
+	position: u32
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum EntryKind {
+	Set = 1,
+	Delete = 2
+}
+
+pub struct FileSystem<D: BlockDevice> {
+	device: D,
+	files: [Option<FileInfo>; MAX_FILES],
+	used: [u32; MAX_BLOCKS / 32],
+	metadata_block: u32,
+	metadata_offset: u32,
+	revision: u32,
+	cursor: u32,
+	next_id: u16,
+	mounted: bool
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+pub const MAX_FILES: usize = 32;
+pub const MAX_BLOCKS: usize = 1024;
+pub const NAME_LEN: usize = 28;
+
+const META_MAGIC: u32 = 0x4653_4D31;	// "FSM1"
+const DATA_MAGIC: u32 = 0x4653_4431;	// "FSD1"
+// The metadata block and the free one kept to move it to
+const RESERVED_BLOCKS: u32 = 2;
+const HEADER_SIZE: u32 = 16;
+const DATA_SIZE: u32 = BLOCK_SIZE - HEADER_SIZE;
+
+const ENTRY_SIZE: u32 = 48;
+const ENTRY_TAG: u32 = 0xF5E0_0000;
+const NO_BLOCK: u32 = 0xFFFF_FFFF;
+
+// Bytes moved at a time when copying inside the device
+const COPY_LEN: usize = 64;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl FileInfo {
+	pub fn get_name(&self) -> &[u8] {
+		&self.name[..self.name_len as usize]
+	}
+
+	pub fn get_size(&self) -> u32 {
+		self.size
+	}
+}
+
+#[allow(dead_code)]
+impl File {
+	pub fn get_position(&self) -> u32 {
+		self.position
+	}
+
+	// Reads carry on from here, past the end they return nothing
+	pub fn seek(&mut self, position: u32) {
+		self.position = position;
+	}
+}
+
+#[allow(dead_code)]
+impl<D: BlockDevice> FileSystem<D> {
+	pub fn new(device: D) -> Self {
+		assert!(device.get_block_count() as usize <= MAX_BLOCKS);
+
+		FileSystem {
+			device,
+			files: [None; MAX_FILES],
+			used: [0; MAX_BLOCKS / 32],
+			metadata_block: 0,
+			metadata_offset: HEADER_SIZE,
+			revision: 0,
+			cursor: 0,
+			next_id: 0,
+			mounted: false
+		}
+	}
+
+	pub fn append(&mut self, name: &[u8], data: &[u8]) -> Result<(), FsError> {
+		self.check_mounted()?;
+
+		let file = match self.find(name) {
+			Some(_) if data.is_empty() => return Ok(()),
+			Some(file) => file,
+			None => self.new_file(name)?
+		};
+
+		let used = self.used;
+		let result = self.append_blocks(file.tail, file.size, data).and_then(|(tail, moved)| {
+			self.commit(EntryKind::Set, FileInfo { tail, size: file.size + data.len() as u32, ..file })?;
+			Ok(moved)
+		});
+
+		match result {
+			Ok(moved) => {
+				// The old last block was copied rather than added to
+				if let Some(block) = moved {
+					self.set_used(block, false);
+				}
+				Ok(())
+			},
+			Err(e) => {
+				self.used = used;
+				Err(e)
+			}
+		}
+	}
+
+	pub fn delete(&mut self, name: &[u8]) -> Result<(), FsError> {
+		self.check_mounted()?;
+
+		let file = self.find(name).ok_or(FsError::NotFound)?;
+		self.commit(EntryKind::Delete, file)?;
+		self.free_chain(file.tail, file.size);
+		Ok(())
+	}
+
+	// Starts an empty filesystem. Its metadata goes in with a higher revision
+	// than any already there, so until it is in the old files stay.
+	pub fn format(&mut self) -> Result<(), FsError> {
+		self.check_size()?;
+
+		let newest = self.find_metadata()?;
+		let revision = newest.map_or(1, |(_, revision)| revision.wrapping_add(1));
+		let block = if self.mounted {
+			self.allocate_erased()?
+		}
+		else {
+			// Nothing to keep but the newest metadata, for a mount to fall back on
+			let block = if matches!(newest, Some((0, _))) { 1 } else { 0 };
+			self.device.erase(block)?;
+			block
+		};
+
+		self.mounted = false;
+		self.write_metadata_header(block, revision)?;
+		self.mount()
+	}
+
+	pub fn get_info(&self, file: &File) -> Result<FileInfo, FsError> {
+		self.check_mounted()?;
+		self.files.iter().flatten().find(|f| f.id == file.id).copied().ok_or(FsError::NotFound)
+	}
+
+	// Blocks left for file data, not counting the one kept back
+	pub fn get_free_blocks(&self) -> u32 {
+		let free = (0..self.device.get_block_count()).filter(|b| !self.get_used(*b)).count() as u32;
+		free.saturating_sub(RESERVED_BLOCKS - 1)
+	}
+
+	pub fn get_mounted(&self) -> bool {
+		self.mounted
+	}
+
+	pub fn list(&self) -> impl Iterator<Item = FileInfo> {
+		IntoIterator::into_iter(self.files).flatten()
+	}
+
+	pub fn mount(&mut self) -> Result<(), FsError> {
+		self.mounted = false;
+		self.files = [None; MAX_FILES];
+		self.used = [0; MAX_BLOCKS / 32];
+
+		self.check_size()?;
+		let (block, revision) = self.find_metadata()?.ok_or(FsError::NoFileSystem)?;
+		self.metadata_block = block;
+		self.revision = revision;
+
+		// Replay the log, it ends at the first erased or broken entry
+		let mut offset = HEADER_SIZE;
+		while offset + ENTRY_SIZE <= BLOCK_SIZE {
+			let mut entry = [0; ENTRY_SIZE as usize];
+			self.device.read(block, offset, &mut entry)?;
+			if entry.iter().all(|b| *b == 0xFF) {
+				break;
+			}
+
+			match decode_entry(&entry) {
+				Some((kind, file)) => self.apply(kind, file),
+				None => {
+					// Nothing more can go after a broken entry, the next
+					// change moves the state to the other block
+					offset = BLOCK_SIZE;
+					break;
+				}
+			}
+			offset += ENTRY_SIZE;
+		}
+		self.metadata_offset = offset;
+
+		// Everything not on a chain is free, older metadata blocks included
+		self.set_used(block, true);
+		let files = self.files;
+		for file in files.iter().flatten() {
+			let mut block = file.tail;
+			for _ in 0..get_block_count(file.size) {
+				if block >= self.device.get_block_count() || self.get_used(block) {
+					return Err(FsError::Corrupt);
+				}
+				self.set_used(block, true);
+				block = self.read_data_header(block)?;
+			}
+		}
+
+		self.next_id = self.files.iter().flatten().map(|f| f.id.wrapping_add(1)).max().unwrap_or(0);
+
+		// Start handing out blocks somewhere new each time, so a watch that is
+		// often reset does not keep wearing the same few
+		self.cursor = revision.wrapping_mul(2_654_435_761) % self.device.get_block_count();
+		self.mounted = true;
+		Ok(())
+	}
+
+	pub fn open(&self, name: &[u8]) -> Result<File, FsError> {
+		self.check_mounted()?;
+		self.find(name).map(|f| File { id: f.id, position: 0 }).ok_or(FsError::NotFound)
+	}
+
+	// Returns how many bytes were read, 0 at the end of the file
+	pub fn read(&mut self, file: &mut File, buffer: &mut [u8]) -> Result<usize, FsError> {
+		let info = self.get_info(file)?;
+		if file.position >= info.size {
+			return Ok(0);
+		}
+
+		let len = buffer.len().min((info.size - file.position) as usize);
+		let mut done = 0;
+		while done < len {
+			let index = file.position / DATA_SIZE;
+			let offset = file.position % DATA_SIZE;
+			let block = self.get_block(&info, index)?;
+
+			let count = ((DATA_SIZE - offset) as usize).min(len - done);
+			self.device.read(block, HEADER_SIZE + offset, &mut buffer[done..done + count])?;
+
+			done += count;
+			file.position += count as u32;
+		}
+		Ok(done)
+	}
+
+	// Replaces the whole file, or creates it. Until the new data is all in the
+	// old contents stay as they were.
+	pub fn write(&mut self, name: &[u8], data: &[u8]) -> Result<(), FsError> {
+		self.check_mounted()?;
+
+		let old = self.find(name);
+		let file = match old {
+			Some(file) => file,
+			None => self.new_file(name)?
+		};
+
+		let used = self.used;
+		let result = self.append_blocks(NO_BLOCK, 0, data).and_then(|(tail, _)| {
+			self.commit(EntryKind::Set, FileInfo { tail, size: data.len() as u32, ..file })
+		});
+
+		match result {
+			Ok(()) => {
+				if let Some(old) = old {
+					self.free_chain(old.tail, old.size);
+				}
+				Ok(())
+			},
+			Err(e) => {
+				self.used = used;
+				Err(e)
+			}
+		}
+	}
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+impl<D: BlockDevice> FileSystem<D> {
+	// A new data block after `previous`, never the one kept for the metadata
+	fn allocate(&mut self, previous: u32) -> Result<u32, FsError> {
+		if self.get_free_blocks() == 0 {
+			return Err(FsError::Full);
+		}
+
+		let block = self.allocate_erased()?;
+		self.device.program(block, 0, &get_header(DATA_MAGIC, previous))?;
+		Ok(block)
+	}
+
+	fn allocate_erased(&mut self) -> Result<u32, FsError> {
+		let count = self.device.get_block_count();
+		for _ in 0..count {
+			let block = self.cursor;
+			self.cursor = if block + 1 >= count { 0 } else { block + 1 };
+
+			if !self.get_used(block) {
+				self.set_used(block, true);
+				self.device.erase(block)?;
+				return Ok(block);
+			}
+		}
+		Err(FsError::Full)
+	}
+
+	// Writes `data` after `size` bytes already in the chain ending at `tail`.
+	// Returns the new last block, and the old one if it had to be moved.
+	fn append_blocks(&mut self, tail: u32, size: u32, data: &[u8]) -> Result<(u32, Option<u32>), FsError> {
+		let mut tail = tail;
+		let mut moved = None;
+		let mut data = data;
+
+		let used = size % DATA_SIZE;
+		if tail != NO_BLOCK && used != 0 && !data.is_empty() {
+			if !self.get_erased(tail, HEADER_SIZE + used, DATA_SIZE - used)? {
+				// Left over from a write cut short, copy what counts to a new block
+				let previous = self.read_data_header(tail)?;
+				let block = self.allocate(previous)?;
+				self.copy(tail, block, used)?;
+				moved = Some(tail);
+				tail = block;
+			}
+
+			let (now, rest) = data.split_at(((DATA_SIZE - used) as usize).min(data.len()));
+			self.device.program(tail, HEADER_SIZE + used, now)?;
+			data = rest;
+		}
+
+		while !data.is_empty() {
+			tail = self.allocate(tail)?;
+			let (now, rest) = data.split_at((DATA_SIZE as usize).min(data.len()));
+			self.device.program(tail, HEADER_SIZE, now)?;
+			data = rest;
+		}
+
+		Ok((tail, moved))
+	}
+
+	fn apply(&mut self, kind: EntryKind, file: FileInfo) {
+		let index = self.files.iter().position(|f| matches!(f, Some(f) if f.id == file.id));
+		match (kind, index) {
+			(EntryKind::Set, Some(index)) => self.files[index] = Some(file),
+			(EntryKind::Set, None) => {
+				if let Some(slot) = self.files.iter_mut().find(|f| f.is_none()) {
+					*slot = Some(file);
+				}
+			},
+			(EntryKind::Delete, Some(index)) => self.files[index] = None,
+			(EntryKind::Delete, None) => ()
+		}
+	}
+
+	fn check_mounted(&self) -> Result<(), FsError> {
+		if self.mounted { Ok(()) } else { Err(FsError::NotMounted) }
+	}
+
+	fn check_size(&self) -> Result<(), FsError> {
+		if self.device.get_block_count() > RESERVED_BLOCKS { Ok(()) } else { Err(FsError::TooSmall) }
+	}
+
+	fn commit(&mut self, kind: EntryKind, file: FileInfo) -> Result<(), FsError> {
+		let entry = encode_entry(kind, &file);
+		let block = self.metadata_block;
+		let offset = self.metadata_offset;
+
+		if offset + ENTRY_SIZE <= BLOCK_SIZE && self.get_erased(block, offset, ENTRY_SIZE)? {
+			self.device.program(block, offset, &entry)?;
+			self.metadata_offset += ENTRY_SIZE;
+		}
+		else {
+			self.compact(kind, file)?;
+		}
+
+		self.apply(kind, file);
+		Ok(())
+	}
+
+	// Writes the state, with one more change, to a free block and lets the
+	// old metadata block go
+	fn compact(&mut self, kind: EntryKind, change: FileInfo) -> Result<(), FsError> {
+		let block = self.allocate_erased()?;
+		let revision = self.revision.wrapping_add(1);
+
+		let result = self.write_metadata(block, revision, kind, change);
+		match result {
+			Ok(offset) => {
+				self.set_used(self.metadata_block, false);
+				self.metadata_block = block;
+				self.metadata_offset = offset;
+				self.revision = revision;
+				Ok(())
+			},
+			Err(e) => {
+				self.set_used(block, false);
+				Err(e)
+			}
+		}
+	}
+
+	fn copy(&mut self, from: u32, to: u32, len: u32) -> Result<(), FsError> {
+		let mut buffer = [0; COPY_LEN];
+		let mut offset = 0;
+		while offset < len {
+			let count = (COPY_LEN as u32).min(len - offset);
+			self.device.read(from, HEADER_SIZE + offset, &mut buffer[..count as usize])?;
+			self.device.program(to, HEADER_SIZE + offset, &buffer[..count as usize])?;
+			offset += count;
+		}
+		Ok(())
+	}
+
+	// The block and revision of the newest metadata on the device
+	fn find_metadata(&mut self) -> Result<Option<(u32, u32)>, FsError> {
+		let mut newest: Option<(u32, u32)> = None;
+		for block in 0..self.device.get_block_count() {
+			if let Some(revision) = self.read_metadata_header(block)? {
+				if newest.is_none_or(|(_, r)| (revision.wrapping_sub(r) as i32) > 0) {
+					newest = Some((block, revision));
+				}
+			}
+		}
+		Ok(newest)
+	}
+
+	fn find(&self, name: &[u8]) -> Option<FileInfo> {
+		self.files.iter().flatten().find(|f| f.get_name() == name).copied()
+	}
+
+	fn free_chain(&mut self, tail: u32, size: u32) {
+		let mut block = tail;
+		for _ in 0..get_block_count(size) {
+			self.set_used(block, false);
+			block = match self.read_data_header(block) {
+				Ok(previous) => previous,
+				Err(_) => return
+			};
+		}
+	}
+
+	// Walks back from the last block, the chain only links that way
+	fn get_block(&mut self, file: &FileInfo, index: u32) -> Result<u32, FsError> {
+		let mut block = file.tail;
+		for _ in (index + 1)..get_block_count(file.size) {
+			block = self.read_data_header(block)?;
+		}
+		Ok(block)
+	}
+
+	fn get_erased(&mut self, block: u32, offset: u32, len: u32) -> Result<bool, FsError> {
+		let mut buffer = [0; COPY_LEN];
+		let mut checked = 0;
+		while checked < len {
+			let count = (COPY_LEN as u32).min(len - checked);
+			self.device.read(block, offset + checked, &mut buffer[..count as usize])?;
+			if buffer[..count as usize].iter().any(|b| *b != 0xFF) {
+				return Ok(false);
+			}
+			checked += count;
+		}
+		Ok(true)
+	}
+
+	fn get_used(&self, block: u32) -> bool {
+		self.used[block as usize / 32] & (1 << (block % 32)) != 0
+	}
+
+	fn new_file(&mut self, name: &[u8]) -> Result<FileInfo, FsError> {
+		if name.is_empty() || name.len() > NAME_LEN {
+			return Err(FsError::InvalidName);
+		}
+		if self.files.iter().all(|f| f.is_some()) {
+			return Err(FsError::TooManyFiles);
+		}
+
+		// Ids are not reused while the file they belonged to is still there
+		while self.files.iter().flatten().any(|f| f.id == self.next_id) {
+			self.next_id = self.next_id.wrapping_add(1);
+		}
+		let id = self.next_id;
+		self.next_id = self.next_id.wrapping_add(1);
+
+		let mut file = FileInfo { id, name: [0; NAME_LEN], name_len: name.len() as u8, tail: NO_BLOCK, size: 0 };
+		file.name[..name.len()].copy_from_slice(name);
+		Ok(file)
+	}
+
+	fn read_data_header(&mut self, block: u32) -> Result<u32, FsError> {
+		let mut header = [0; 12];
+		self.device.read(block, 0, &mut header)?;
+		match decode_header(&header) {
+			Some((DATA_MAGIC, previous)) => Ok(previous),
+			_ => Err(FsError::Corrupt)
+		}
+	}
+
+	fn read_metadata_header(&mut self, block: u32) -> Result<Option<u32>, FsError> {
+		let mut header = [0; 12];
+		self.device.read(block, 0, &mut header)?;
+		match decode_header(&header) {
+			Some((META_MAGIC, revision)) => Ok(Some(revision)),
+			_ => Ok(None)
+		}
+	}
+
+	fn set_used(&mut self, block: u32, used: bool) {
+		if used {
+			self.used[block as usize / 32] |= 1 << (block % 32);
+		}
+		else {
+			self.used[block as usize / 32] &= !(1 << (block % 32));
+		}
+	}
+
+	// Fills an erased block with the state and one more change, header last.
+	// Returns where the next entry goes.
+	fn write_metadata(&mut self, block: u32, revision: u32, kind: EntryKind, change: FileInfo) -> Result<u32, FsError> {
+		let mut offset = HEADER_SIZE;
+		let files = self.files;
+		let others = files.iter().flatten().filter(|f| f.id != change.id);
+		let change = if kind == EntryKind::Set { Some(change) } else { None };
+		for file in others.chain(change.iter()) {
+			self.device.program(block, offset, &encode_entry(EntryKind::Set, file))?;
+			offset += ENTRY_SIZE;
+		}
+
+		self.write_metadata_header(block, revision)?;
+		Ok(offset)
+	}
+
+	fn write_metadata_header(&mut self, block: u32, revision: u32) -> Result<(), FsError> {
+		self.device.program(block, 0, &get_header(META_MAGIC, revision))
+	}
+}
+
+fn decode_entry(entry: &[u8; ENTRY_SIZE as usize]) -> Option<(EntryKind, FileInfo)> {
+	let crc_offset = ENTRY_SIZE as usize - 4;
+	if crc::get_crc32(&entry[..crc_offset]) != get_u32(entry, crc_offset) {
+		return None;
+	}
+
+	let tag = get_u32(entry, 0);
+	if tag & 0xFFFF_0000 != ENTRY_TAG {
+		return None;
+	}
+	let kind = match (tag >> 8) & 0xFF {
+		1 => EntryKind::Set,
+		2 => EntryKind::Delete,
+		_ => return None
+	};
+	let name_len = (tag & 0xFF) as u8;
+	if name_len as usize > NAME_LEN {
+		return None;
+	}
+
+	let mut file = FileInfo {
+		id: get_u32(entry, 4) as u16,
+		name: [0; NAME_LEN],
+		name_len,
+		tail: get_u32(entry, 8),
+		size: get_u32(entry, 12)
+	};
+	file.name.copy_from_slice(&entry[16..16 + NAME_LEN]);
+	Some((kind, file))
+}
+
+fn decode_header(header: &[u8; 12]) -> Option<(u32, u32)> {
+	let crc = crc::get_crc32(&header[..8]);
+	if crc == get_u32(header, 8) { Some((get_u32(header, 0), get_u32(header, 4))) } else { None }
+}
+
+fn encode_entry(kind: EntryKind, file: &FileInfo) -> [u8; ENTRY_SIZE as usize] {
+	let mut entry = [0xFF; ENTRY_SIZE as usize];
+	let tag = ENTRY_TAG | ((kind as u32) << 8) | file.name_len as u32;
+	entry[0..4].copy_from_slice(&tag.to_le_bytes());
+	entry[4..8].copy_from_slice(&(0xFFFF_0000 | file.id as u32).to_le_bytes());
+	entry[8..12].copy_from_slice(&file.tail.to_le_bytes());
+	entry[12..16].copy_from_slice(&file.size.to_le_bytes());
+	entry[16..16 + NAME_LEN].copy_from_slice(&file.name);
+
+	let crc_offset = ENTRY_SIZE as usize - 4;
+	let crc = crc::get_crc32(&entry[..crc_offset]);
+	entry[crc_offset..].copy_from_slice(&crc.to_le_bytes());
+	entry
+}
+
+fn get_block_count(size: u32) -> u32 {
+	size.div_ceil(DATA_SIZE)
+}
+
+fn get_header(magic: u32, value: u32) -> [u8; 12] {
+	let mut header = [0; 12];
+	header[0..4].copy_from_slice(&magic.to_le_bytes());
+	header[4..8].copy_from_slice(&value.to_le_bytes());
+
+	let crc = crc::get_crc32(&header[..8]);
+	header[8..12].copy_from_slice(&crc.to_le_bytes());
+	header
+}
+
+fn get_u32(bytes: &[u8], offset: usize) -> u32 {
+	u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+}
+
+//==============================================================================
+// Tests
+//==============================================================================
+#[cfg(test)]
+mod tests {
+	use super::{FileSystem, FsError, BLOCK_SIZE, DATA_SIZE, ENTRY_SIZE};
+	use crate::drivers::fs::block::{BlockDevice, RamDevice};
+
+	// A RamDevice that counts erases, and can lose power after a number of
+	// programs and erases. The program it dies in gets half of its bytes in.
+	struct TestDevice<'a> {
+		ram: RamDevice<'a>,
+		writes_left: Option<u32>,
+		erases: Vec<u32>
+	}
+
+	impl<'a> TestDevice<'a> {
+		fn new(memory: &'a mut [u8], writes_left: Option<u32>) -> Self {
+			let blocks = memory.len() / BLOCK_SIZE as usize;
+			TestDevice { ram: RamDevice::new(memory), writes_left, erases: vec![0; blocks] }
+		}
+
+		fn take_power(&mut self) -> bool {
+			match self.writes_left.as_mut() {
+				Some(0) => false,
+				Some(left) => {
+					*left -= 1;
+					true
+				},
+				None => true
+			}
+		}
+	}
+
+	impl BlockDevice for TestDevice<'_> {
+		fn get_block_count(&self) -> u32 {
+			self.ram.get_block_count()
+		}
+
+		fn read(&mut self, block: u32, offset: u32, buffer: &mut [u8]) -> Result<(), FsError> {
+			self.ram.read(block, offset, buffer)
+		}
+
+		fn program(&mut self, block: u32, offset: u32, data: &[u8]) -> Result<(), FsError> {
+			if !self.take_power() {
+				let _ = self.ram.program(block, offset, &data[..data.len() / 2]);
+				return Err(FsError::Device);
+			}
+			self.ram.program(block, offset, data)
+		}
+
+		fn erase(&mut self, block: u32) -> Result<(), FsError> {
+			if !self.take_power() {
+				return Err(FsError::Device);
+			}
+			self.erases[block as usize] += 1;
+			self.ram.erase(block)
+		}
+	}
+
+	fn new_memory(blocks: u32) -> Vec<u8> {
+		vec![0xFF; (blocks * BLOCK_SIZE) as usize]
+	}
+
+	fn format(memory: &mut [u8]) -> FileSystem<TestDevice<'_>> {
+		let mut fs = FileSystem::new(TestDevice::new(memory, None));
+		fs.format().unwrap();
+		fs
+	}
+
+	fn mount(memory: &mut [u8]) -> FileSystem<TestDevice<'_>> {
+		let mut fs = FileSystem::new(TestDevice::new(memory, None));
+		fs.mount().unwrap();
+		fs
+	}
+
+	fn pattern(len: usize, seed: u8) -> Vec<u8> {
+		(0..len).map(|i| (i as u8).wrapping_mul(31).wrapping_add(seed)).collect()
+	}
+
+	fn read_all(fs: &mut FileSystem<TestDevice>, name: &[u8]) -> Vec<u8> {
+		let mut file = fs.open(name).unwrap();
+		let mut data = Vec::new();
+		let mut buffer = [0; 300];
+		loop {
+			let count = fs.read(&mut file, &mut buffer).unwrap();
+			if count == 0 {
+				return data;
+			}
+			data.extend_from_slice(&buffer[..count]);
+		}
+	}
+
+	fn get_names(fs: &FileSystem<TestDevice>) -> Vec<Vec<u8>> {
+		let mut names: Vec<Vec<u8>> = fs.list().map(|f| f.get_name().to_vec()).collect();
+		names.sort();
+		names
+	}
+
+	// Runs change on a copy of memory with the power cut after each number
+	// of writes in turn, until one gets through. What is left after each cut
+	// is mounted and handed to check, along with whether the change finished.
+	fn cut_power<C, K>(memory: &[u8], change: C, check: K)
+	where
+		C: Fn(&mut FileSystem<TestDevice>) -> Result<(), FsError>,
+		K: Fn(&mut FileSystem<TestDevice>, bool)
+	{
+		for cut in 0..1000 {
+			let mut copy = memory.to_vec();
+			let finished = {
+				let mut fs = FileSystem::new(TestDevice::new(&mut copy, Some(cut)));
+				fs.mount().unwrap();
+				change(&mut fs).is_ok()
+			};
+
+			check(&mut mount(&mut copy), finished);
+			if finished {
+				return;
+			}
+		}
+		panic!("change never finished");
+	}
+
+	#[test]
+	fn small_devices_are_turned_away() {
+		for blocks in 0..3 {
+			let mut memory = new_memory(blocks);
+			let mut fs = FileSystem::new(TestDevice::new(&mut memory, None));
+			assert_eq!(fs.mount(), Err(FsError::TooSmall));
+			assert_eq!(fs.format(), Err(FsError::TooSmall));
+		}
+
+		let mut memory = new_memory(3);
+		let fs = format(&mut memory);
+		assert_eq!(fs.get_free_blocks(), 1);
+	}
+
+	#[test]
+	fn blank_device_has_no_filesystem() {
+		let mut memory = new_memory(8);
+		let mut fs = FileSystem::new(TestDevice::new(&mut memory, None));
+		assert_eq!(fs.mount(), Err(FsError::NoFileSystem));
+		assert_eq!(fs.open(b"a").err(), Some(FsError::NotMounted));
+		assert_eq!(fs.write(b"a", b"data"), Err(FsError::NotMounted));
+	}
+
+	#[test]
+	fn files_read_back_what_was_written() {
+		let mut memory = new_memory(8);
+		let mut fs = format(&mut memory);
+
+		let long = pattern(DATA_SIZE as usize + 1000, 1);
+		fs.write(b"long", &long).unwrap();
+		fs.write(b"short", b"hello").unwrap();
+		assert_eq!(read_all(&mut fs, b"long"), long);
+		assert_eq!(read_all(&mut fs, b"short"), b"hello");
+
+		// Across the end of the first block
+		let mut file = fs.open(b"long").unwrap();
+		file.seek(DATA_SIZE - 50);
+		let mut buffer = [0; 100];
+		assert_eq!(fs.read(&mut file, &mut buffer), Ok(100));
+		assert_eq!(&buffer[..], &long[DATA_SIZE as usize - 50..DATA_SIZE as usize + 50]);
+		assert_eq!(fs.get_info(&file).unwrap().get_size(), long.len() as u32);
+
+		file.seek(long.len() as u32);
+		assert_eq!(fs.read(&mut file, &mut buffer), Ok(0));
+
+		assert_eq!(fs.open(b"missing").err(), Some(FsError::NotFound));
+		assert_eq!(fs.write(b"", b"data"), Err(FsError::InvalidName));
+		assert_eq!(fs.write(&[b'n'; 29], b"data"), Err(FsError::InvalidName));
+	}
+
+	#[test]
+	fn append_adds_to_the_end() {
+		let mut memory = new_memory(8);
+		let mut fs = format(&mut memory);
+
+		let mut expected = Vec::new();
+		for (i, len) in [10, 3000, 3000, 1, DATA_SIZE as usize].iter().enumerate() {
+			let data = pattern(*len, i as u8);
+			fs.append(b"log", &data).unwrap();
+			expected.extend_from_slice(&data);
+			assert_eq!(read_all(&mut fs, b"log"), expected);
+		}
+
+		fs.append(b"log", b"").unwrap();
+		assert_eq!(read_all(&mut fs, b"log"), expected);
+	}
+
+	#[test]
+	fn write_replaces_and_frees_the_old_blocks() {
+		let mut memory = new_memory(8);
+		let mut fs = format(&mut memory);
+		let free = fs.get_free_blocks();
+
+		fs.write(b"file", &pattern(DATA_SIZE as usize * 3, 1)).unwrap();
+		assert_eq!(fs.get_free_blocks(), free - 3);
+
+		fs.write(b"file", b"smaller").unwrap();
+		assert_eq!(fs.get_free_blocks(), free - 1);
+		assert_eq!(read_all(&mut fs, b"file"), b"smaller");
+	}
+
+	#[test]
+	fn delete_and_list() {
+		let mut memory = new_memory(8);
+		let mut fs = format(&mut memory);
+		let free = fs.get_free_blocks();
+
+		fs.write(b"a", b"first").unwrap();
+		fs.write(b"b", b"second").unwrap();
+		fs.append(b"c", b"third").unwrap();
+		assert_eq!(get_names(&fs), [b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]);
+
+		let mut file = fs.open(b"b").unwrap();
+		fs.delete(b"b").unwrap();
+		assert_eq!(get_names(&fs), [b"a".to_vec(), b"c".to_vec()]);
+		assert_eq!(fs.read(&mut file, &mut [0; 4]), Err(FsError::NotFound));
+		assert_eq!(fs.delete(b"b"), Err(FsError::NotFound));
+
+		fs.delete(b"a").unwrap();
+		fs.delete(b"c").unwrap();
+		assert_eq!(fs.list().count(), 0);
+		assert_eq!(fs.get_free_blocks(), free);
+	}
+
+	#[test]
+	fn remount_finds_the_same_files() {
+		let mut memory = new_memory(8);
+		let mut expected = Vec::new();
+		let mut last = Vec::new();
+		let free = {
+			let mut fs = format(&mut memory);
+
+			// Enough changes to move the metadata a few times
+			for i in 0..300 {
+				last = pattern(i % 40, i as u8);
+				fs.append(b"log", &last).unwrap();
+				expected.extend_from_slice(&last);
+				fs.write(b"last", &last).unwrap();
+			}
+			fs.write(b"gone", b"soon").unwrap();
+			fs.delete(b"gone").unwrap();
+			fs.get_free_blocks()
+		};
+
+		let mut fs = mount(&mut memory);
+		assert_eq!(get_names(&fs), [b"last".to_vec(), b"log".to_vec()]);
+		assert_eq!(read_all(&mut fs, b"log"), expected);
+		assert_eq!(read_all(&mut fs, b"last"), last);
+		assert_eq!(fs.get_free_blocks(), free);
+
+		// And carries on from there
+		fs.append(b"log", b"more").unwrap();
+		expected.extend_from_slice(b"more");
+		assert_eq!(read_all(&mut mount(&mut memory), b"log"), expected);
+	}
+
+	#[test]
+	fn full_device_can_still_delete() {
+		let mut memory = new_memory(4);
+		let mut fs = format(&mut memory);
+
+		fs.write(b"a", &pattern(DATA_SIZE as usize, 1)).unwrap();
+		fs.write(b"b", &pattern(DATA_SIZE as usize, 2)).unwrap();
+		assert_eq!(fs.get_free_blocks(), 0);
+		assert_eq!(fs.append(b"b", b"x"), Err(FsError::Full));
+		assert_eq!(fs.write(b"c", b"x"), Err(FsError::Full));
+
+		// With the log full the delete has to move the metadata, into the
+		// block kept back for it
+		let block = fs.metadata_block;
+		fs.metadata_offset = BLOCK_SIZE;
+		fs.delete(b"a").unwrap();
+		assert_ne!(fs.metadata_block, block);
+		assert_eq!(fs.get_free_blocks(), 1);
+
+		let mut fs = mount(&mut memory);
+		assert_eq!(get_names(&fs), [b"b".to_vec()]);
+		assert_eq!(read_all(&mut fs, b"b"), pattern(DATA_SIZE as usize, 2));
+	}
+
+	#[test]
+	fn metadata_erases_are_spread() {
+		let mut memory = new_memory(8);
+		let mut fs = format(&mut memory);
+
+		// Each append is one entry, the log fills every 84 or so
+		for i in 0..3000 {
+			fs.append(b"log", &[i as u8]).unwrap();
+		}
+		assert_eq!(read_all(&mut fs, b"log").len(), 3000);
+
+		let erases = &fs.device.erases;
+		let total: u32 = erases.iter().sum();
+		let most = *erases.iter().max().unwrap();
+		assert!(total >= 3000 / (BLOCK_SIZE / ENTRY_SIZE), "{:?}", erases);
+		assert!(most <= (total / erases.len() as u32) + 2, "{:?}", erases);
+	}
+
+	#[test]
+	fn power_cut_during_append() {
+		let mut memory = new_memory(8);
+		let old = pattern(100, 1);
+		format(&mut memory).write(b"log", &old).unwrap();
+
+		let new = pattern(DATA_SIZE as usize, 2);
+		let whole = [old.clone(), new.clone()].concat();
+		cut_power(&memory, |fs| fs.append(b"log", &new), |fs, finished| {
+			let data = read_all(fs, b"log");
+			assert!(data == whole || (!finished && data == old));
+
+			// Whatever was left half written is stepped over
+			fs.append(b"log", b"after").unwrap();
+			assert_eq!(read_all(fs, b"log"), [data, b"after".to_vec()].concat());
+		});
+	}
+
+	#[test]
+	fn power_cut_during_write() {
+		let mut memory = new_memory(8);
+		let old = pattern(DATA_SIZE as usize + 10, 1);
+		format(&mut memory).write(b"file", &old).unwrap();
+
+		let new = pattern(3000, 2);
+		cut_power(&memory, |fs| fs.write(b"file", &new), |fs, finished| {
+			let data = read_all(fs, b"file");
+			assert!(data == new || (!finished && data == old));
+			assert_eq!(fs.get_free_blocks(), 6 - ((data.len() as u32).div_ceil(DATA_SIZE)));
+		});
+	}
+
+	#[test]
+	fn power_cut_while_moving_the_metadata() {
+		let mut memory = new_memory(8);
+		let old = {
+			let mut fs = format(&mut memory);
+			fs.write(b"other", b"kept").unwrap();
+			while fs.metadata_offset + ENTRY_SIZE <= BLOCK_SIZE {
+				fs.append(b"log", b"x").unwrap();
+			}
+			read_all(&mut fs, b"log")
+		};
+
+		cut_power(&memory, |fs| fs.delete(b"log"), |fs, finished| {
+			assert_eq!(read_all(fs, b"other"), b"kept");
+			match fs.open(b"log") {
+				Ok(_) => {
+					assert!(!finished);
+					assert_eq!(read_all(fs, b"log"), old);
+				},
+				Err(e) => assert_eq!(e, FsError::NotFound)
+			}
+
+			fs.append(b"other", b" going").unwrap();
+			assert_eq!(read_all(fs, b"other"), b"kept going");
+		});
+	}
+}
@@ -0,0 +1,173 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::fs::mod.rs
+// Named files on the external SPI flash, for assets, logs and history. The
+// layout and how it survives resets are described in filesystem.rs.
+//
+// Only call these from the main loop, never from an interrupt: an erase can
+// keep the caller waiting for a few hundred milliseconds. The flash is put
+// back into deep power-down after each call.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+pub mod block;
+pub mod filesystem;
+
+pub use filesystem::{File, FileInfo, FileSystem, FsError};
+
+use core::ptr;
+use crate::config;
+use crate::drivers::{flash, log};
+use block::{BlockDevice, BLOCK_SIZE};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+// The part of the SPI flash set aside for files
+pub struct FlashDevice {
+	address: u32,
+	blocks: u32
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+static mut FS: Option<FileSystem<FlashDevice>> = None;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+impl BlockDevice for FlashDevice {
+	fn get_block_count(&self) -> u32 {
+		self.blocks
+	}
+
+	fn read(&mut self, block: u32, offset: u32, buffer: &mut [u8]) -> Result<(), FsError> {
+		block::check_access(self, block, offset, buffer.len())?;
+		flash::read(self.get_address(block, offset), buffer).map_err(|_| FsError::Device)
+	}
+
+	fn program(&mut self, block: u32, offset: u32, data: &[u8]) -> Result<(), FsError> {
+		block::check_access(self, block, offset, data.len())?;
+		flash::program(self.get_address(block, offset), data).map_err(|_| FsError::Device)
+	}
+
+	fn erase(&mut self, block: u32) -> Result<(), FsError> {
+		block::check_access(self, block, 0, BLOCK_SIZE as usize)?;
+		flash::erase_sector(self.get_address(block, 0)).map_err(|_| FsError::Device)
+	}
+}
+
+// Mounts the filesystem, or starts an empty one if there is none yet
+pub fn init() {
+	let device = FlashDevice {
+		address: config::FS_FLASH_ADDRESS,
+		blocks: config::FS_FLASH_BLOCKS
+	};
+	let fs = unsafe { &mut *ptr::addr_of_mut!(FS) }.insert(FileSystem::new(device));
+
+	let result = match fs.mount() {
+		Err(FsError::NoFileSystem) => fs.format(),
+		result => result
+	};
+	if let Err(e) = result {
+		log::push_log(get_error_string(e));
+	}
+
+	let _ = flash::power_down();
+}
+
+#[allow(dead_code)]
+pub fn get_error_string(error: FsError) -> &'static str {
+	match error {
+		FsError::Corrupt => 		"fs: corrupt",
+		FsError::Device => 			"fs: flash failed",
+		FsError::Full => 			"fs: full",
+		FsError::InvalidName => 	"fs: invalid name",
+		FsError::NoFileSystem => 	"fs: not formatted",
+		FsError::NotFound => 		"fs: file not found",
+		FsError::NotMounted => 		"fs: not mounted",
+		FsError::TooManyFiles => 	"fs: too many files",
+		FsError::TooSmall => 		"fs: flash too small",
+	}
+}
+
+#[allow(dead_code)]
+pub fn append(name: &[u8], data: &[u8]) -> Result<(), FsError> {
+	with_fs(|fs| fs.append(name, data))
+}
+
+#[allow(dead_code)]
+pub fn delete(name: &[u8]) -> Result<(), FsError> {
+	with_fs(|fs| fs.delete(name))
+}
+
+#[allow(dead_code)]
+pub fn format() -> Result<(), FsError> {
+	with_fs(|fs| fs.format())
+}
+
+#[allow(dead_code)]
+pub fn get_free_blocks() -> u32 {
+	with_fs(|fs| Ok(fs.get_free_blocks())).unwrap_or(0)
+}
+
+#[allow(dead_code)]
+pub fn get_info(file: &File) -> Result<FileInfo, FsError> {
+	with_fs(|fs| fs.get_info(file))
+}
+
+#[allow(dead_code)]
+pub fn list() -> impl Iterator<Item = FileInfo> {
+	let files = with_fs(|fs| Ok(fs.list()));
+	files.into_iter().flatten()
+}
+
+#[allow(dead_code)]
+pub fn open(name: &[u8]) -> Result<File, FsError> {
+	with_fs(|fs| fs.open(name))
+}
+
+#[allow(dead_code)]
+pub fn read(file: &mut File, buffer: &mut [u8]) -> Result<usize, FsError> {
+	with_fs(|fs| fs.read(file, buffer))
+}
+
+#[allow(dead_code)]
+pub fn write(name: &[u8], data: &[u8]) -> Result<(), FsError> {
+	with_fs(|fs| fs.write(name, data))
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+impl FlashDevice {
+	fn get_address(&self, block: u32, offset: u32) -> u32 {
+		self.address + (block * BLOCK_SIZE) + offset
+	}
+}
+
+fn with_fs<R, F>(f: F) -> Result<R, FsError>
+where
+	F: FnOnce(&mut FileSystem<FlashDevice>) -> Result<R, FsError>
+{
+	// Only used from the main loop, so nothing else holds it
+	let result = match unsafe { &mut *ptr::addr_of_mut!(FS) } {
+		Some(fs) => f(fs),
+		None => Err(FsError::NotMounted)
+	};
+
+	let _ = flash::power_down();
+	result
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
@@ -2,18 +2,38 @@
 // Notes
 //==============================================================================
 // drivers::lcd::font.rs
-// Minimal and Large Numeric Font
+// Minimal, Proportional and Large Numeric Fonts
 //
 // Minimal Characters:
 //  Minimal characters are to be used most places. They can be easily scaled. 
 //  Each character is based on a 5x8 block. when writing strings, at least 1
-//  column of pixels should be used to separate adjacent characters.
+//  column of pixels should be used to separate adjacent characters. They stay
+//  in the firmware so the log can still be read without the asset pack.
+//
+// Proportional Characters:
+//  Latin-1 text in the fonts built from the BDF files in assets/, each with a
+//  Font constant named after its file (sans_16.bdf is SANS_16). Glyphs have
+//  their own width and advance and sit on a common baseline. Text is UTF-8;
+//  characters a font lacks are drawn with its default glyph, a few common
+//  typographic ones with their plain ASCII look-alike.
+//
+//  Fonts from greymap BDF files are anti-aliased, with 2 or 4 bits per pixel.
+//  The edges of their glyphs are blended against the Background the text is
+//  drawn on, so it should match what is really behind the text.
+//
+// Time Characters:
+//  40x64 digits for the clock, read from the time_digits asset in the SPI
+//  flash. The asset is looked up once and kept. Without it, the digits are
+//  drawn as minimal characters scaled up to the same size.
 
 
 //==============================================================================
 // Crates and Mods
 //==============================================================================
-use super::{lcd, lcd_api, st7789};
+use core::ptr;
+use super::lcd_api;
+use crate::drivers::{assets, log};
+use crate::drivers::assets::{Asset, AssetError, PixelFormat};
 
 //==============================================================================
 // Enums, Structs, and Types
@@ -22,8 +42,31 @@ pub struct MinimalCharacter {
 	bytes: [u8; 5]
 }
 
-pub struct TimeCharacter {
-	bytes: [u8; 320]
+// A proportional font in the asset pack, with the line metrics needed to lay
+// out text without reading it
+pub struct Font {
+	name: &'static [u8],
+	ascent: u8,
+	descent: u8
+}
+
+// A font's asset found in the pack, for reading many glyphs without looking
+// it up each time
+pub struct OpenFont {
+	asset: Asset,
+	bits_per_pixel: u8,
+	first: u32,
+	count: u32,
+	default: u32
+}
+
+struct Glyph {
+	offset: u32,
+	width: u16,
+	height: u16,
+	x_offset: i16,
+	top: u16,
+	advance: u16
 }
 
 //==============================================================================
@@ -110,346 +153,64 @@ const MINIMAL_CHARACTER_LIST: [MinimalCharacter; 71] = [
 pub const MINIMAL_CHARACTER_WIDTH: u16 = 5;
 pub const MINIMAL_CHARACTER_HEIGHT: u16 = 8;
 
-#[allow(dead_code)]
-const TIME_CHARACTER_LIST: [TimeCharacter; 10] = [
-	TimeCharacter { bytes: [ // 0
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x07, 0xFF, 0xFF, 0xFF, 0xE0, 0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x3F, 0xC0, 0x00, 0xFF, 0xFC, 0x3F, 0xC0, 0x01, 0xFF, 0xFC, 
-		0x3F, 0xC0, 0x01, 0xFF, 0xFC, 0x3F, 0xC0, 0x01, 0xFF, 0xFC, 
-		0x3F, 0xC0, 0x03, 0xFF, 0xFC, 0x3F, 0xC0, 0x03, 0xFF, 0xFC, 
-		0x3F, 0xC0, 0x03, 0xFF, 0xFC, 0x3F, 0xC0, 0x07, 0xFB, 0xFC, 
-		0x3F, 0xC0, 0x07, 0xFB, 0xFC, 0x3F, 0xC0, 0x07, 0xFB, 0xFC, 
-		0x3F, 0xC0, 0x0F, 0xF3, 0xFC, 0x3F, 0xC0, 0x0F, 0xF3, 0xFC, 
-		0x3F, 0xC0, 0x0F, 0xF3, 0xFC, 0x3F, 0xC0, 0x1F, 0xE3, 0xFC, 
-		0x3F, 0xC0, 0x1F, 0xE3, 0xFC, 0x3F, 0xC0, 0x1F, 0xE3, 0xFC, 
-		0x3F, 0xC0, 0x3F, 0xC3, 0xFC, 0x3F, 0xC0, 0x3F, 0xC3, 0xFC, 
-		0x3F, 0xC0, 0x3F, 0xC3, 0xFC, 0x3F, 0xC0, 0x7F, 0x83, 0xFC, 
-		0x3F, 0xC0, 0x7F, 0x83, 0xFC, 0x3F, 0xC0, 0x7F, 0x83, 0xFC, 
-		0x3F, 0xC0, 0xFF, 0x03, 0xFC, 0x3F, 0xC0, 0xFF, 0x03, 0xFC, 
-		0x3F, 0xC0, 0xFF, 0x03, 0xFC, 0x3F, 0xC1, 0xFE, 0x03, 0xFC, 
-		0x3F, 0xC1, 0xFE, 0x03, 0xFC, 0x3F, 0xC1, 0xFE, 0x03, 0xFC, 
-		0x3F, 0xC3, 0xFC, 0x03, 0xFC, 0x3F, 0xC3, 0xFC, 0x03, 0xFC, 
-		0x3F, 0xC3, 0xFC, 0x03, 0xFC, 0x3F, 0xC7, 0xF8, 0x03, 0xFC, 
-		0x3F, 0xC7, 0xF8, 0x03, 0xFC, 0x3F, 0xC7, 0xF8, 0x03, 0xFC, 
-		0x3F, 0xCF, 0xF0, 0x03, 0xFC, 0x3F, 0xCF, 0xF0, 0x03, 0xFC, 
-		0x3F, 0xCF, 0xF0, 0x03, 0xFC, 0x3F, 0xDF, 0xE0, 0x03, 0xFC, 
-		0x3F, 0xDF, 0xE0, 0x03, 0xFC, 0x3F, 0xDF, 0xE0, 0x03, 0xFC, 
-		0x3F, 0xFF, 0xC0, 0x03, 0xFC, 0x3F, 0xFF, 0xC0, 0x03, 0xFC, 
-		0x3F, 0xFF, 0xC0, 0x03, 0xFC, 0x3F, 0xFF, 0x80, 0x03, 0xFC, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 0x07, 0xFF, 0xFF, 0xFF, 0xE0, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xC0, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] },
-	TimeCharacter { bytes: [ // 1
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x01, 0xFF, 0x00, 
-		0x00, 0x00, 0x03, 0xFF, 0x00, 0x00, 0x00, 0x07, 0xFF, 0x00, 
-		0x00, 0x00, 0x0F, 0xFF, 0x00, 0x00, 0x00, 0x1F, 0xFF, 0x00, 
-		0x00, 0x00, 0x3F, 0xFF, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0x00, 
-		0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x01, 0xFE, 0xFF, 0x00, 
-		0x00, 0x03, 0xFC, 0xFF, 0x00, 0x00, 0x07, 0xF8, 0xFF, 0x00, 
-		0x00, 0x0F, 0xF0, 0xFF, 0x00, 0x00, 0x1F, 0xE0, 0xFF, 0x00, 
-		0x00, 0x3F, 0xC0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x7E, 0x00, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] },
-	TimeCharacter { bytes: [ // 2
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x07, 0xFF, 0xFF, 0xFF, 0xE0, 0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x0F, 0xFC, 0x3F, 0xC0, 0x00, 0x07, 0xFC, 
-		0x1F, 0x80, 0x00, 0x03, 0xFC, 0x0F, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0xFF, 0xFF, 0xFF, 0xFC, 0x03, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x07, 0xFF, 0xFF, 0xFF, 0xF8, 0x0F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF0, 0x1F, 0xFF, 0xFF, 0xFF, 0xE0, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xC0, 0x3F, 0xFF, 0xFF, 0xFF, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0xF0, 0x3F, 0xC0, 0x00, 0x01, 0xF8, 
-		0x3F, 0xE0, 0x00, 0x03, 0xFC, 0x3F, 0xF0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 0x07, 0xFF, 0xFF, 0xFF, 0xE0, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xC0, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] },
-	TimeCharacter { bytes: [ // 3
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x07, 0xFF, 0xFF, 0xFF, 0xE0, 0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x0F, 0xFC, 0x3F, 0xC0, 0x00, 0x07, 0xFC, 
-		0x1F, 0x80, 0x00, 0x03, 0xFC, 0x0F, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x07, 0xFC, 0x00, 0x00, 0x00, 0x0F, 0xFC, 
-		0x00, 0x00, 0x7F, 0xFF, 0xF8, 0x00, 0x00, 0xFF, 0xFF, 0xF8, 
-		0x00, 0x00, 0xFF, 0xFF, 0xF0, 0x00, 0x00, 0xFF, 0xFF, 0xE0, 
-		0x00, 0x00, 0xFF, 0xFF, 0xE0, 0x00, 0x00, 0xFF, 0xFF, 0xF0, 
-		0x00, 0x00, 0xFF, 0xFF, 0xF8, 0x00, 0x00, 0x7F, 0xFF, 0xF8, 
-		0x00, 0x00, 0x00, 0x0F, 0xFC, 0x00, 0x00, 0x00, 0x07, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x0F, 0x80, 0x00, 0x03, 0xFC, 0x1F, 0x80, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x07, 0xFC, 0x3F, 0xC0, 0x00, 0x0F, 0xFC, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 0x07, 0xFF, 0xFF, 0xFF, 0xE0, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xC0, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] },
-	TimeCharacter { bytes: [ // 4
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x1F, 0x80, 0x00, 0x01, 0xF8, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xE0, 0x00, 0x07, 0xFC, 0x3F, 0xF0, 0x00, 0x0F, 0xFC, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xFC, 0x1F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xFC, 0x07, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xFC, 0x00, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x00, 0x00, 0x00, 0x0F, 0xFC, 0x00, 0x00, 0x00, 0x07, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x01, 0xF8, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] },
-	TimeCharacter { bytes: [ // 5
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x00, 0xFF, 0xFF, 0xFF, 0x80, 0x03, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x07, 0xFF, 0xFF, 0xFF, 0xC0, 0x0F, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xC0, 0x1F, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xC0, 0x3F, 0xFF, 0xFF, 0xFF, 0x80, 
-		0x3F, 0xF0, 0x00, 0x00, 0x00, 0x3F, 0xE0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xE0, 0x00, 0x00, 0x00, 0x3F, 0xF0, 0x00, 0x00, 0x00, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0x00, 0x3F, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xE0, 0x1F, 0xFF, 0xFF, 0xFF, 0xF0, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xF8, 0x07, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xFC, 0x00, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x00, 0x00, 0x00, 0x0F, 0xFC, 0x00, 0x00, 0x00, 0x07, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x0F, 0x80, 0x00, 0x03, 0xFC, 0x1F, 0x80, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x07, 0xFC, 0x3F, 0xC0, 0x00, 0x0F, 0xFC, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 0x07, 0xFF, 0xFF, 0xFF, 0xE0, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xC0, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] },
-	TimeCharacter { bytes: [ // 6
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x00, 0xFF, 0xFF, 0xFF, 0x80, 0x03, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x07, 0xFF, 0xFF, 0xFF, 0xC0, 0x0F, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xC0, 0x1F, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xC0, 0x3F, 0xFF, 0xFF, 0xFF, 0x80, 
-		0x3F, 0xF0, 0x00, 0x00, 0x00, 0x3F, 0xE0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xC0, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x00, 0x00, 0x00, 
-		0x3F, 0xE0, 0x00, 0x00, 0x00, 0x3F, 0xF0, 0x00, 0x00, 0x00, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0x00, 0x3F, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xE0, 0x3F, 0xFF, 0xFF, 0xFF, 0xF0, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xF8, 0x3F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x3F, 0xF0, 0x00, 0x0F, 0xFC, 0x3F, 0xE0, 0x00, 0x07, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xE0, 0x00, 0x07, 0xFC, 0x3F, 0xF0, 0x00, 0x0F, 0xFC, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 0x07, 0xFF, 0xFF, 0xFF, 0xE0, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xC0, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] }, 
-	TimeCharacter { bytes: [ // 7
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x01, 0xFF, 0xFF, 0xFF, 0xF8, 0x03, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xFC, 0x03, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xFC, 0x03, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xFC, 0x01, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x00, 0x00, 0x00, 0x0F, 0xFC, 0x00, 0x00, 0x00, 0x07, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x01, 0xF8, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] },
-	TimeCharacter { bytes: [ // 8
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x07, 0xFF, 0xFF, 0xFF, 0xE0, 0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x3F, 0xF0, 0x00, 0x0F, 0xFC, 0x3F, 0xE0, 0x00, 0x07, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xE0, 0x00, 0x07, 0xFC, 0x3F, 0xF0, 0x00, 0x0F, 0xFC, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 0x07, 0xFF, 0xFF, 0xFF, 0xE0, 
-		0x07, 0xFF, 0xFF, 0xFF, 0xE0, 0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x3F, 0xF0, 0x00, 0x0F, 0xFC, 0x3F, 0xE0, 0x00, 0x07, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xE0, 0x00, 0x07, 0xFC, 0x3F, 0xF0, 0x00, 0x0F, 0xFC, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 0x07, 0xFF, 0xFF, 0xFF, 0xE0, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xC0, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] },
-	TimeCharacter { bytes: [ // 9
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-		0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xC0, 
-		0x07, 0xFF, 0xFF, 0xFF, 0xE0, 0x0F, 0xFF, 0xFF, 0xFF, 0xF0, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 0x1F, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x3F, 0xF0, 0x00, 0x0F, 0xFC, 0x3F, 0xE0, 0x00, 0x07, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xC0, 0x00, 0x03, 0xFC, 0x3F, 0xC0, 0x00, 0x03, 0xFC, 
-		0x3F, 0xE0, 0x00, 0x07, 0xFC, 0x3F, 0xF0, 0x00, 0x0F, 0xFC, 
-		0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 0x3F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x1F, 0xFF, 0xFF, 0xFF, 0xFC, 0x1F, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x0F, 0xFF, 0xFF, 0xFF, 0xFC, 0x07, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xFC, 0x00, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x00, 0x00, 0x00, 0x0F, 0xFC, 0x00, 0x00, 0x00, 0x07, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x03, 0xFC, 
-		0x00, 0x00, 0x00, 0x07, 0xFC, 0x00, 0x00, 0x00, 0x0F, 0xFC, 
-		0x01, 0xFF, 0xFF, 0xFF, 0xFC, 0x03, 0xFF, 0xFF, 0xFF, 0xFC, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xF8, 0x03, 0xFF, 0xFF, 0xFF, 0xF8, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xF0, 0x03, 0xFF, 0xFF, 0xFF, 0xE0, 
-		0x03, 0xFF, 0xFF, 0xFF, 0xC0, 0x01, 0xFF, 0xFF, 0xFF, 0x00, 
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ] }
-];
-
 pub const TIME_CHARACTER_WIDTH: u16 = 40;
 pub const TIME_CHARACTER_HEIGHT: u16 = 64;
 
+// Minimal characters at this scale fill a time character
+const TIME_FALLBACK_SCALE: u16 = TIME_CHARACTER_WIDTH / MINIMAL_CHARACTER_WIDTH;
+
+// The time_digits asset, None until it has been looked up
+static mut TIME_DIGITS: Option<Result<Asset, AssetError>> = None;
+
+// Layout of the font assets, see assets/pack.rs
+const FONT_HEADER_SIZE: u32 = 8;
+const GLYPH_SIZE: u32 = 10;
+
+include!(concat!(env!("OUT_DIR"), "/fonts.rs"));
+
 //==============================================================================
 // Public Functions
 //==============================================================================
+#[allow(dead_code)]
+impl Font {
+	pub const fn new(name: &'static [u8], ascent: u8, descent: u8) -> Self {
+		Font { name, ascent, descent }
+	}
+
+	// Rows from the top of a line down to the baseline
+	pub fn get_ascent(&self) -> u16 {
+		self.ascent as u16
+	}
+
+	// Rows below the baseline
+	pub fn get_descent(&self) -> u16 {
+		self.descent as u16
+	}
+
+	pub fn get_height(&self) -> u16 {
+		self.ascent as u16 + self.descent as u16
+	}
+
+	pub fn get_name(&self) -> &'static [u8] {
+		self.name
+	}
+}
+
+#[allow(dead_code)]
+impl OpenFont {
+	pub fn get_advance(&self, c: char) -> Result<u16, AssetError> {
+		Ok(get_glyph(self, c)?.advance)
+	}
+
+	pub fn get_text_width(&self, text: &[u8]) -> Result<u16, AssetError> {
+		let mut width: u16 = 0;
+		for c in get_chars(text) {
+			width = width.saturating_add(self.get_advance(c)?);
+		}
+		Ok(width)
+	}
+}
+
 #[allow(dead_code)]
 fn get_minimal_character(c: char) -> &'static MinimalCharacter {
 	match c {
@@ -545,7 +306,6 @@ pub fn write_minimal_character(c: u8, x: u16, y: u16, fg: lcd_api::Color, bg: lc
 	let char_height = MINIMAL_CHARACTER_HEIGHT * scale;
 
 	lcd_api::set_window(x, char_width, y, char_height);
-	lcd::write_command(st7789::COMMAND::MEMORY_WRITE);
 	let bytes = get_minimal_character(c as char).bytes;
 	let bg = (bg as u16).to_le_bytes();
 	let fg = (fg as u16).to_le_bytes();
@@ -566,10 +326,10 @@ pub fn write_minimal_character(c: u8, x: u16, y: u16, fg: lcd_api::Color, bg: lc
 
 				for _col_scaler in 0..scale {
 					if pixel_is_on {
-						lcd::write_data(&fg);
+						lcd_api::write_pixels(&fg);
 					}
 					else {
-						lcd::write_data(&bg);
+						lcd_api::write_pixels(&bg);
 					}
 				}
 				
@@ -588,32 +348,243 @@ pub fn write_minimal_character(c: u8, x: u16, y: u16, fg: lcd_api::Color, bg: lc
 
 #[allow(dead_code)]
 pub fn write_time_character(n: u8, x: u16, y: u16, fg: lcd_api::Color, bg: lcd_api::Color) {
-	let char_width = TIME_CHARACTER_WIDTH;
-	let char_height = TIME_CHARACTER_HEIGHT;
+	// The digits are stacked one above the other in a single asset
+	let row = (n % 10) as u16 * TIME_CHARACTER_HEIGHT;
+	let result = get_time_digits()
+		.and_then(|digits| lcd_api::write_mono(&digits, row, TIME_CHARACTER_HEIGHT, x, y, fg, bg));
 
-	lcd_api::set_window(x, char_width, y, char_height);
-	lcd::write_command(st7789::COMMAND::MEMORY_WRITE);
-	let bytes = TIME_CHARACTER_LIST[(n % 10) as usize].bytes;
-	let bg = (bg as u16).to_le_bytes();
-	let fg = (fg as u16).to_le_bytes();
+	if result.is_err() {
+		write_minimal_character(b'0' + (n % 10), x, y, fg, bg, TIME_FALLBACK_SCALE);
+	}
+}
 
-	let num_bytes: usize = (char_width*char_height/8) as usize;
+// Each malformed UTF-8 sequence comes out as a single U+FFFD
+#[allow(dead_code)]
+pub fn get_chars(text: &[u8]) -> impl Iterator<Item = char> + '_ {
+	get_char_indices(text).map(|(_, c)| c)
+}
 
-	for byte in 0..num_bytes {
-		for bit in 0..8 {
-			if (bytes[byte] & (0x80 >> bit)) > 0 {
-				lcd::write_data(&fg);
-			}
-			else {
-				lcd::write_data(&bg);
+// The characters of the text and the byte each starts at
+pub fn get_char_indices(text: &[u8]) -> impl Iterator<Item = (usize, char)> + '_ {
+	text.utf8_chunks().flat_map(move |chunk| {
+		let start = chunk.valid().as_ptr() as usize - text.as_ptr() as usize;
+		let invalid = (!chunk.invalid().is_empty())
+			.then_some((start + chunk.valid().len(), char::REPLACEMENT_CHARACTER));
+		chunk.valid().char_indices().map(move |(i, c)| (start + i, c)).chain(invalid)
+	})
+}
+
+#[allow(dead_code)]
+pub fn get_text_width(text: &[u8], font: &Font) -> Result<u16, AssetError> {
+	open_font(font)?.get_text_width(text)
+}
+
+pub fn open_font(font: &Font) -> Result<OpenFont, AssetError> {
+	let asset = assets::find(font.name)?;
+	let bits_per_pixel = match asset.get_format() {
+		PixelFormat::Font1 => 1,
+		PixelFormat::Font2 => 2,
+		PixelFormat::Font4 => 4,
+		_ => return Err(AssetError::WrongFormat)
+	};
+
+	let mut header = [0u8; FONT_HEADER_SIZE as usize];
+	assets::read(&asset, 0, &mut header)?;
+	// A pack from another build
+	if header[6] != font.ascent || header[7] != font.descent {
+		return Err(AssetError::Corrupt);
+	}
+
+	Ok(OpenFont {
+		asset,
+		bits_per_pixel,
+		first: u16::from_le_bytes([header[0], header[1]]) as u32,
+		count: u16::from_le_bytes([header[2], header[3]]) as u32,
+		default: u16::from_le_bytes([header[4], header[5]]) as u32
+	})
+}
+
+// Draws a line of UTF-8 text with the top left corner of the line at x, y, in
+// fg on a box the width of the text and the height of the line filled with bg.
+// The box is cut off at the right edge of the display. Returns the width of
+// the text.
+#[allow(dead_code)]
+pub fn write_text(text: &[u8], x: u16, y: u16, font: &Font, fg: lcd_api::Color, bg: lcd_api::Background) -> Result<u16, AssetError> {
+	let open = open_font(font)?;
+	let width = open.get_text_width(text)?;
+	let right = core::cmp::min(x as u32 + width as u32, 240) as u16;
+	if x < right {
+		lcd_api::fill_area(x, right - x, y, font.get_height(), bg);
+		write_glyphs(text, x, y, (x, right), &open, fg, bg)?;
+	}
+
+	Ok(width)
+}
+
+// Draws just the glyphs of a line of text, for text on a background that is
+// already there, cut off at the columns in clip
+pub fn write_glyphs(text: &[u8], x: u16, y: u16, clip: (u16, u16), font: &OpenFont, fg: lcd_api::Color, bg: lcd_api::Background) -> Result<(), AssetError> {
+	let mut pen = x as i32;
+	for c in get_chars(text) {
+		if pen >= clip.1 as i32 {
+			break;
+		}
+		let glyph = get_glyph(font, c)?;
+		write_glyph(font, &glyph, pen, y, clip, fg, bg)?;
+		pen += glyph.advance as i32;
+	}
+	Ok(())
+}
+
+// Draws just the set pixels of a line of minimal characters, leaving out any
+// that would cross the columns in clip
+pub fn write_minimal_glyphs(text: &[u8], x: u16, y: u16, clip: (u16, u16), fg: lcd_api::Color, scale: u16) {
+	let char_width = MINIMAL_CHARACTER_WIDTH * scale;
+	let on = fg.to_rgb565();
+	let key = !on;
+
+	let mut pen = x;
+	for c in get_chars(text) {
+		if pen + char_width > clip.1 {
+			break;
+		}
+		if pen >= clip.0 {
+			let bytes = get_minimal_character(c).bytes;
+			let mut blitter = lcd_api::Blitter::new(pen, y, char_width, MINIMAL_CHARACTER_HEIGHT * scale, Some(key));
+			for row in 0..(MINIMAL_CHARACTER_HEIGHT * scale) {
+				for column in 0..char_width {
+					let bit = ((row / scale) * MINIMAL_CHARACTER_WIDTH + (column / scale)) as usize;
+					let pixel_is_on = (bytes[bit / 8] & (0x80 >> (bit % 8))) > 0;
+					blitter.push(if pixel_is_on { on } else { key }.to_be_bytes());
+				}
 			}
+			blitter.flush();
 		}
+		pen += char_width + scale;
 	}
 }
 
 //==============================================================================
 // Private Functions
 //==============================================================================
+// The clock redraws digits every second, too often to search the pack each
+// time. A missing asset is only logged once.
+fn get_time_digits() -> Result<Asset, AssetError> {
+	let cached = unsafe { *ptr::addr_of!(TIME_DIGITS) };
+	if let Some(result) = cached {
+		return result;
+	}
+
+	let result = assets::find(assets::TIME_DIGITS);
+	if let Err(e) = result {
+		log::push_log(assets::get_error_string(e));
+	}
+	unsafe { *ptr::addr_of_mut!(TIME_DIGITS) = Some(result); }
+	result
+}
+
+fn get_glyph(font: &OpenFont, c: char) -> Result<Glyph, AssetError> {
+	if let Some(glyph) = read_glyph(font, c as u32)? {
+		return Ok(glyph);
+	}
+	if let Some(glyph) = read_glyph(font, get_substitute(c) as u32)? {
+		return Ok(glyph);
+	}
+	read_glyph(font, font.default)?.ok_or(AssetError::Corrupt)
+}
+
+// None when the font has no glyph for the code point
+fn read_glyph(font: &OpenFont, code: u32) -> Result<Option<Glyph>, AssetError> {
+	if code < font.first || code - font.first >= font.count {
+		return Ok(None);
+	}
+
+	let mut entry = [0u8; GLYPH_SIZE as usize];
+	assets::read(&font.asset, FONT_HEADER_SIZE + ((code - font.first) * GLYPH_SIZE), &mut entry)?;
+	if entry[8] == 0 {
+		return Ok(None);
+	}
+
+	Ok(Some(Glyph {
+		offset: u32::from_le_bytes([entry[0], entry[1], entry[2], entry[3]]),
+		width: entry[4] as u16,
+		height: entry[5] as u16,
+		x_offset: entry[6] as i8 as i16,
+		top: entry[7] as u16,
+		advance: entry[8] as u16
+	}))
+}
+
+// Plain stand-ins for the punctuation phones like to put in notifications
+fn get_substitute(c: char) -> char {
+	match c {
+		'\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{2032}' => '\'',
+		'\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{2033}' => '"',
+		'\u{2010}'..='\u{2015}' | '\u{2212}' => '-',
+		'\u{2022}' => '\u{B7}',
+		'\u{2039}' => '<',
+		'\u{203A}' => '>',
+		_ => c
+	}
+}
+
+// Draws the glyph with its pen position at pen, cut off at the columns in clip.
+// Pixels with none of the glyph are keyed, so the background shows through,
+// and those with some of it are blended against the background.
+fn write_glyph(font: &OpenFont, glyph: &Glyph, pen: i32, y: u16, clip: (u16, u16), fg: lcd_api::Color, bg: lcd_api::Background) -> Result<(), AssetError> {
+	let start = pen + glyph.x_offset as i32;
+	let first = core::cmp::max(start, clip.0 as i32);
+	let end = core::cmp::min(start + glyph.width as i32, clip.1 as i32);
+	if first >= end || glyph.height == 0 {
+		return Ok(());
+	}
+
+	let bits = font.bits_per_pixel;
+	let full = (1u8 << bits) - 1;
+	let fg = fg.to_rgb565();
+	let key = !fg;
+	let skip = (first - start) as u16;
+	let visible = (end - first) as u16;
+	let mut column: u16 = 0;
+	let mut row = y + glyph.top;
+	let mut bg_color = bg.get_rgb565(row);
+	let mut remaining = glyph.width as u32 * glyph.height as u32;
+
+	let mut blitter = lcd_api::Blitter::new(first as u16, row, visible, glyph.height, Some(key));
+	let result = assets::stream(&font.asset, glyph.offset, (remaining * bits as u32).div_ceil(8), |bytes| {
+		for byte in bytes {
+			for i in 0..(8 / bits) {
+				if remaining == 0 {
+					break;
+				}
+				if column >= skip && column < skip + visible {
+					let level = (byte >> (8 - (bits * (i + 1)))) & full;
+					let color = match level {
+						0 => key,
+						_ if level == full => fg,
+						_ => match lcd_api::blend(fg, bg_color, (level as u32 * 255 / full as u32) as u8) {
+							// Only pixels with none of the glyph may be skipped
+							color if color == key => color ^ 1,
+							color => color
+						}
+					};
+					blitter.push(color.to_be_bytes());
+				}
+
+				column += 1;
+				if column == glyph.width {
+					column = 0;
+					row += 1;
+					bg_color = bg.get_rgb565(row);
+				}
+				remaining -= 1;
+			}
+		}
+	});
+
+	blitter.flush();
+	result
+}
 
 
 //==============================================================================
@@ -0,0 +1,268 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::lcd::framebuffer.rs
+// In-memory RGB565 Display. The pixel storage is borrowed so it can be sized
+// for the job: a full 240x240 screen on the host, or a small off-screen area
+// on the watch where RAM is tight. Anything drawn outside of it is dropped.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use super::lcd_api;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+pub struct FrameBuffer<'a> {
+	width: u16,
+	height: u16,
+	pixels: &'a mut [u16],
+
+	x_start: u16,
+	x_end: u16,
+	y_start: u16,
+	y_end: u16,
+	x: u16,
+	y: u16,
+	pixel_msb: Option<u8>,
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl<'a> FrameBuffer<'a> {
+	pub fn new(width: u16, height: u16, pixels: &'a mut [u16]) -> Self {
+		assert!(pixels.len() >= (width as usize) * (height as usize));
+
+		FrameBuffer {
+			width,
+			height,
+			pixels,
+			x_start: 0,
+			x_end: width.saturating_sub(1),
+			y_start: 0,
+			y_end: height.saturating_sub(1),
+			x: 0,
+			y: 0,
+			pixel_msb: None,
+		}
+	}
+
+	pub fn get_height(&self) -> u16 {
+		self.height
+	}
+
+	pub fn get_pixel(&self, x: u16, y: u16) -> Option<u16> {
+		if x < self.width && y < self.height {
+			Some(self.pixels[(y as usize * self.width as usize) + x as usize])
+		}
+		else {
+			None
+		}
+	}
+
+	pub fn get_pixels(&self) -> &[u16] {
+		&self.pixels[..(self.width as usize * self.height as usize)]
+	}
+
+	pub fn get_width(&self) -> u16 {
+		self.width
+	}
+}
+
+impl<'a> lcd_api::Display for FrameBuffer<'a> {
+	fn set_window(&mut self, x: u16, width: u16, y: u16, height: u16) {
+		self.x_start = x;
+		self.x_end = x + width.saturating_sub(1);
+		self.y_start = y;
+		self.y_end = y + height.saturating_sub(1);
+		self.x = x;
+		self.y = y;
+		self.pixel_msb = None;
+	}
+
+	fn write_pixels(&mut self, pixels: &[u8]) {
+		// A pixel may be split across two writes, so hold on to a lone MSB
+		for byte in pixels {
+			if let Some(msb) = self.pixel_msb.take() {
+				self.push_pixel(u16::from_be_bytes([msb, *byte]));
+			}
+			else {
+				self.pixel_msb = Some(*byte);
+			}
+		}
+	}
+
+	fn fill(&mut self, color: u16, len: u32) {
+		// Colors are passed in the same byte order they are sent to the LCD
+		let color = color.swap_bytes();
+		for _ in 0..len {
+			self.push_pixel(color);
+		}
+	}
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+impl<'a> FrameBuffer<'a> {
+	fn push_pixel(&mut self, pixel: u16) {
+		if self.x < self.width && self.y < self.height {
+			self.pixels[(self.y as usize * self.width as usize) + self.x as usize] = pixel;
+		}
+
+		// Wrap within the window the same way the ST7789 does
+		if self.x >= self.x_end {
+			self.x = self.x_start;
+			self.y = if self.y >= self.y_end { self.y_start } else { self.y + 1 };
+		}
+		else {
+			self.x += 1;
+		}
+	}
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+
+//==============================================================================
+// Tests
+//==============================================================================
+#[cfg(test)]
+mod tests {
+	use std::sync::Mutex;
+	use super::FrameBuffer;
+	use crate::drivers::lcd::{font, lcd_api};
+	use crate::drivers::lcd::lcd_api::Color;
+
+	// Drawing goes to the one display set in lcd_api, so tests take turns
+	static DISPLAY_LOCK: Mutex<()> = Mutex::new(());
+
+	// Runs draw with a width x height FrameBuffer as the display, then hands
+	// back its pixels
+	fn render<F: FnOnce()>(width: u16, height: u16, draw: F) -> Vec<u16> {
+		let _lock = DISPLAY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+		let pixels = vec![0u16; width as usize * height as usize].leak();
+		let framebuffer: &'static mut FrameBuffer<'static> = Box::leak(Box::new(FrameBuffer::new(width, height, pixels)));
+		let framebuffer: *mut FrameBuffer<'static> = framebuffer;
+
+		lcd_api::set_display(Some(unsafe { &mut *framebuffer }));
+		draw();
+		lcd_api::set_display(None);
+
+		unsafe { (*framebuffer).get_pixels().to_vec() }
+	}
+
+	fn get_pixel(pixels: &[u16], width: u16, x: u16, y: u16) -> u16 {
+		pixels[(y as usize * width as usize) + x as usize]
+	}
+
+	#[test]
+	fn fill_rectangle_sets_only_its_pixels() {
+		let pixels = render(32, 32, || lcd_api::fill_rectangle(10, 4, 20, 3, Color::Red));
+
+		for y in 0..32 {
+			for x in 0..32 {
+				let inside = (10..14).contains(&x) && (20..23).contains(&y);
+				let expected = if inside { Color::Red.to_rgb565() } else { 0 };
+				assert_eq!(get_pixel(&pixels, 32, x, y), expected, "pixel {}, {}", x, y);
+			}
+		}
+	}
+
+	#[test]
+	fn window_past_the_edge_is_cut_off() {
+		// Runs off the right and bottom, the rest must not wrap back in
+		let pixels = render(16, 16, || lcd_api::fill_rectangle(12, 8, 14, 4, Color::White));
+
+		for y in 0..16 {
+			for x in 0..16 {
+				let inside = x >= 12 && y >= 14;
+				let expected = if inside { Color::White.to_rgb565() } else { 0 };
+				assert_eq!(get_pixel(&pixels, 16, x, y), expected, "pixel {}, {}", x, y);
+			}
+		}
+	}
+
+	#[test]
+	fn pixels_split_across_writes() {
+		let pixels = render(4, 1, || {
+			lcd_api::set_window(0, 4, 0, 1);
+			lcd_api::write_pixels(&[0x12]);
+			lcd_api::write_pixels(&[0x34, 0x56]);
+			lcd_api::write_pixels(&[0x78, 0x9A, 0xBC]);
+		});
+
+		assert_eq!(pixels, [0x1234, 0x5678, 0x9ABC, 0x0000]);
+	}
+
+	#[test]
+	fn minimal_character() {
+		const L: [&str; 8] = [
+			"#....",
+			"#....",
+			"#....",
+			"#....",
+			"#....",
+			"#....",
+			"#####",
+			"....."
+		];
+		let fg = Color::White.to_rgb565();
+		let bg = Color::Blue.to_rgb565();
+		let pixels = render(24, 20, || font::write_minimal_character(b'L', 2, 1, Color::White, Color::Blue, 2));
+
+		for y in 0..20 {
+			for x in 0..24 {
+				let expected = if (2..12).contains(&x) && (1..17).contains(&y) {
+					let on = L[((y - 1) / 2) as usize].as_bytes()[((x - 2) / 2) as usize] == b'#';
+					if on { fg } else { bg }
+				}
+				else {
+					0
+				};
+				assert_eq!(get_pixel(&pixels, 24, x, y), expected, "pixel {}, {}", x, y);
+			}
+		}
+	}
+
+	#[test]
+	fn minimal_character_past_the_edge() {
+		let whole = render(60, 60, || font::write_minimal_character(b'A', 50, 50, Color::White, Color::Black, 2));
+		let cut = render(56, 56, || font::write_minimal_character(b'A', 50, 50, Color::White, Color::Black, 2));
+
+		for y in 0..56 {
+			for x in 0..56 {
+				assert_eq!(get_pixel(&cut, 56, x, y), get_pixel(&whole, 60, x, y), "pixel {}, {}", x, y);
+			}
+		}
+	}
+
+	#[test]
+	fn clock_digits() {
+		// Without an asset pack the digits are scaled up minimal characters
+		let width = font::TIME_CHARACTER_WIDTH;
+		let height = font::TIME_CHARACTER_HEIGHT;
+		for n in 0..10 {
+			let digit = render(width + 8, height + 8, || font::write_time_character(n, 4, 4, Color::Green, Color::Black));
+			let expected = render(width + 8, height + 8, || font::write_minimal_character(b'0' + n, 4, 4, Color::Green, Color::Black, 8));
+
+			assert_eq!(digit, expected, "digit {}", n);
+			assert!(digit.contains(&Color::Green.to_rgb565()), "digit {}", n);
+		}
+	}
+}
@@ -2,7 +2,9 @@
 // Notes
 //==============================================================================
 // drivers::lcd::images.rs
-// LCD Drivers Mod List
+// An Image for every PNG in assets/, generated by the build (assets/pack.rs).
+// The pixels stay in the asset pack in the SPI flash; an Image names its asset
+// and gives its size, so pages can be laid out around it.
 
 //==============================================================================
 // Crates and Mods
@@ -12,2139 +14,44 @@
 //==============================================================================
 // Enums, Structs, and Types
 //==============================================================================
-
+pub struct Image {
+	name: &'static [u8],
+	width: u16,
+	height: u16,
+	key: Option<u16>
+}
 
 //==============================================================================
 // Variables
 //==============================================================================
-#[allow(dead_code)]
-pub const RUSTACEAN: [u8; 33920] = [
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xDA, 0x00, 0xB9, 0xC0, 0xA9, 0x80, 0xA1, 0x80, 0xA9, 0x80, 0xC1, 0xE0, 0xE2, 0x40, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xC1, 0xE0, 
-	0x99, 0x60, 0x89, 0x40, 0x99, 0x60, 0xB9, 0xC0, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x61, 0xE5, 0x75, 
-	0xEF, 0x5C, 0xE7, 0x5C, 0xB5, 0x96, 0x10, 0x82, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 
-	0x89, 0x40, 0xE2, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0xC5, 0xFE, 0x58, 0xF7, 0x7D, 0xE7, 0x3C, 
-	0x94, 0xB2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x60, 0xB1, 0xC0, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFD, 0xD6, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xBD, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x38, 0x60, 0xD2, 0x00, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xE2, 0x20, 0xE6, 0x99, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0x94, 0x92, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x89, 0x40, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF3, 0x6A, 0xFF, 0xDE, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x63, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x38, 0x60, 0xE2, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0x93, 0xEF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xEF, 0x5D, 0x10, 0x82, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x99, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xED, 0x74, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xB5, 0x96, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x79, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xA1, 0x80, 0xBD, 0xF7, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0x6B, 0x4D, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 
-	0xE2, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xCE, 0x17, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xCE, 0x59, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xD2, 0x00, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xE2, 0x40, 0x20, 0x20, 0xD6, 0xBA, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0x8C, 0x71, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0xA9, 0xA0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xC1, 0xE0, 0xC6, 0x18, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xCE, 0x79, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x91, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xB1, 0xA0, 0x00, 0x00, 0xD6, 0x9A, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0x8C, 0x71, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x58, 0xA0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0x89, 0x40, 0xAD, 0x75, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xB5, 0xD6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0x68, 0xE0, 0x00, 0x00, 0xB5, 0xD6, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0x5A, 0xEB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0xDA, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0x30, 0x40, 0x63, 0x2C, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x73, 0xAE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE2, 0x40, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xEA, 0x40, 0x10, 0x00, 0x00, 0x00, 0x6B, 0x4D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xDF, 0x1B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0xB9, 0xC0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xDA, 0x20, 0x00, 0x00, 0x00, 0x00, 0xCE, 0x59, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xD6, 0x9A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xDA, 0x00, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xDA, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBD, 0xF7, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF7, 0xDE, 0x7B, 0xCF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x99, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xC9, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x29, 0x45, 0xCE, 0x59, 
-	0xFF, 0xDF, 0xFF, 0xFF, 0xCE, 0x79, 0x39, 0xC7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xD2, 0x00, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xCA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x62, 0xA5, 0x34, 0xDE, 0xDB, 0xCE, 0x79, 
-	0x73, 0xAE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x81, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xC9, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x39, 0xC7, 0x39, 0xC7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xD2, 0x00, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xD2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x79, 0x00, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xD2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE2, 0x20, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xE2, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x89, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0x48, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0xA1, 0x80, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0x79, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x81, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xA1, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0xD2, 0x00, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xD2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC9, 0xE0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xE2, 0x20, 0x28, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x68, 0xE0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0x99, 0x60, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70, 0xE0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xB9, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x40, 
-	0xDA, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0x91, 0x40, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x40, 0x80, 0xE2, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0x99, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x68, 0xE0, 0xDA, 0x20, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xB1, 0xC0, 
-	0x30, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x60, 0xC0, 0xDA, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0x91, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0x80, 0xB9, 0xC0, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xE2, 0x20, 0xD2, 0x00, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xE2, 0x40, 0xA1, 0x80, 0x38, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x68, 0xE0, 
-	0xB9, 0xC0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xCA, 0x00, 0x89, 0x40, 0x48, 0x80, 
-	0x20, 0x20, 0x58, 0xC0, 0x91, 0x40, 0xC9, 0xE0, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xEA, 0x40, 0xE2, 0x20, 0xDA, 0x00, 0xDA, 0x00, 0xDA, 0x20, 0xE2, 0x40, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xC1, 0xC0, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xC1, 0xC0, 0xE2, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xC9, 0xE0, 0xB9, 0xC0, 0xC9, 0xE0, 0xE2, 0x20, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x40, 0xA9, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA9, 0x60, 
-	0xC1, 0xC0, 0xDA, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xB9, 0xA0, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xB1, 0x80, 0xD2, 0x00, 0xEA, 0x40, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xDA, 0x00, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xB1, 0xA0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xB9, 0xA0, 0xDA, 0x00, 0xF2, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xB1, 0x80, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA9, 0x60, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xEA, 0x40, 0xEA, 0x40, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xE2, 0x20, 
-	0xC1, 0xE0, 0xB1, 0xA0, 0xA9, 0xA0, 0xA9, 0xA0, 0xC1, 0xC0, 0xDA, 0x20, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xDA, 0x20, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xC1, 0xC0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xE2, 0x40, 0xB9, 0xC0, 0x89, 0x40, 0x48, 0x80, 0x18, 0x20, 0x08, 0x00, 0x10, 0x00, 0x38, 0x60, 
-	0x81, 0x20, 0xB1, 0xA0, 0xDA, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xB1, 0xA0, 0x60, 0xC0, 0x00, 0x00, 
-	0x58, 0xC0, 0x89, 0x40, 0xA1, 0x60, 0x99, 0x60, 0x81, 0x20, 0x48, 0x80, 0x48, 0x80, 0xA9, 0x80, 
-	0xE2, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xB1, 0x80, 
-	0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xDA, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xDA, 0x20, 0x91, 0x40, 
-	0x18, 0x20, 0x00, 0x00, 0x00, 0x00, 0x48, 0x80, 0x58, 0xC0, 0x58, 0xC0, 0x48, 0x80, 0x00, 0x00, 
-	0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x79, 0x00, 0xCA, 0x00, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xB1, 0xA0, 0x38, 0x40, 0x58, 0xA0, 0xB1, 0xA0, 0xE2, 0x20, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xDA, 0x20, 0xA1, 0x80, 
-	0x40, 0x80, 0xA9, 0x80, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xDA, 0x00, 0xC1, 0xC0, 0xD1, 0xE0, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x40, 0xA1, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA9, 0x80, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xDA, 0x20, 0xEA, 0x40, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xA1, 0x80, 0x18, 0x20, 0x48, 0x80, 
-	0xA1, 0x80, 0xCA, 0x00, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 
-	0xCA, 0x00, 0xA1, 0x80, 0x40, 0x80, 0x00, 0x00, 0x00, 0x00, 0x81, 0x20, 0xE2, 0x20, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xDA, 0x20, 0x60, 0xE0, 0x40, 0x80, 0xB9, 0xC0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xEA, 0x40, 0x99, 0x60, 0x60, 0xC0, 0xDA, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 
-	0xDA, 0x00, 0xC1, 0xC0, 0xA9, 0x60, 0xA1, 0x60, 0xC9, 0xE0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x40, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xDA, 0x00, 0xA1, 0x60, 
-	0xB1, 0x80, 0xC9, 0xE0, 0xDA, 0x00, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xDA, 0x20, 0x60, 0xC0, 0x58, 0xA0, 0xC1, 0xC0, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xC1, 0xC0, 0x58, 0xA0, 0x00, 0x00, 0x30, 0x40, 0xB9, 0xC0, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xC1, 0xC0, 0x20, 0x20, 0x89, 0x40, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xCA, 0x00, 0x50, 0xA0, 0xC9, 0xE0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xE2, 0x40, 0xD1, 0xE0, 0xB9, 0xA0, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xD1, 0xE0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xC1, 0xC0, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA9, 0x80, 0xC1, 0xC0, 0xD2, 0x00, 0xE2, 0x20, 
-	0xF2, 0x60, 0xF2, 0x60, 0xCA, 0x00, 0x40, 0x60, 0xA9, 0xA0, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xA9, 0xA0, 0x20, 0x20, 0x00, 0x00, 
-	0x99, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xA9, 0x80, 
-	0x10, 0x00, 0xB1, 0xA0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xE2, 0x20, 0x60, 0xC0, 0xC1, 0xC0, 0xF2, 0x60, 0xF2, 0x60, 
-	0xE2, 0x40, 0xD2, 0x00, 0xC1, 0xC0, 0xA9, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xDA, 0x00, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x40, 
-	0xB1, 0x80, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0x99, 0x40, 0x58, 0xC0, 0xDA, 0x00, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xDA, 0x20, 0x60, 0xC0, 
-	0x00, 0x00, 0x81, 0x20, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xA1, 0x80, 0x28, 0x20, 
-	0xC9, 0xE0, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xE2, 0x40, 0x60, 0xC0, 0xA1, 0x60, 0xA9, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA9, 0x60, 0xE2, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xEA, 0x40, 0xA9, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0x89, 0x20, 0x71, 0x00, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 
-	0x81, 0x20, 0x00, 0x00, 0x79, 0x00, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xA1, 0x80, 0x20, 0x20, 0xCA, 0x00, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xE2, 0x20, 0x48, 0x80, 0x91, 0x20, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xB1, 0xA0, 
-	0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xEA, 0x40, 0xB1, 0x80, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0x91, 0x20, 
-	0x81, 0x20, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0x91, 0x60, 0x00, 0x00, 0x81, 0x20, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xB1, 0xC0, 0x10, 0x00, 0xC9, 0xE0, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xD2, 0x00, 0x38, 0x60, 
-	0x99, 0x40, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xEA, 0x40, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0x89, 0x20, 
-	0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0x91, 0x60, 0x00, 0x00, 0x81, 0x20, 0xC1, 0xC0, 0xC1, 0xC0, 0xC1, 0xC0, 
-	0xC1, 0xC0, 0xC1, 0xC0, 0xC1, 0xC0, 0xA9, 0x80, 0x10, 0x00, 0xB9, 0xC0, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xA9, 0xA0, 
-	0x48, 0x80, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xF2, 0x60, 0xEA, 0x40, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0x89, 0x40, 0x00, 0x00, 0x70, 0xE0, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0x91, 0x40, 0x20, 0x20, 0xA1, 0x80, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0x99, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0x79, 0x00, 0x08, 0x00, 0x89, 0x20, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x40, 0x40, 0x60, 0x79, 0x00, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xEA, 0x40, 0xB9, 0xC0, 0x50, 0xA0, 0xA1, 0x60, 
-	0xA1, 0x60, 0x00, 0x00, 0xB1, 0xC0, 0xEA, 0x40, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA1, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 
-	0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xF2, 0x60, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-	0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 
-];
+include!(concat!(env!("OUT_DIR"), "/images.rs"));
 
 //==============================================================================
 // Public Functions
 //==============================================================================
+#[allow(dead_code)]
+impl Image {
+	pub const fn new(name: &'static [u8], width: u16, height: u16, key: Option<u16>) -> Self {
+		Image { name, width, height, key }
+	}
+
+	pub fn get_height(&self) -> u16 {
+		self.height
+	}
 
+	// Pixels of this RGB565 color are transparent
+	pub fn get_key(&self) -> Option<u16> {
+		self.key
+	}
+
+	pub fn get_name(&self) -> &'static [u8] {
+		self.name
+	}
+
+	pub fn get_width(&self) -> u16 {
+		self.width
+	}
+}
 
 //==============================================================================
 // Private Functions
@@ -2158,4 +65,4 @@ pub const RUSTACEAN: [u8; 33920] = [
 
 //==============================================================================
 // Task Handler
-//==============================================================================
\ No newline at end of file
+//==============================================================================
@@ -0,0 +1,316 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::lcd::layout.rs
+// Text set in a box on the display. Lines break at newlines and, when the box
+// wraps, between words to fit its width; a word wider than the box is broken
+// where it reaches the edge. Each line is placed left, center or right, and as
+// many lines as fit in the height of the box are drawn. With ellipsis on, a
+// line that is cut short ends in "..." instead.
+//
+// measure gives the size of the text as it would be set, so a page can place
+// things around it before anything is drawn.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use super::font::{self, Font, OpenFont};
+use super::lcd_api::{self, Background, Color};
+use crate::drivers::assets::AssetError;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Align {
+	Left,
+	Center,
+	Right
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+pub enum Typeface {
+	Minimal(u16),					// The built in characters at this scale
+	Proportional(&'static Font)
+}
+
+// Where text goes and how it is set
+#[derive(Clone, Copy)]
+pub struct TextBox {
+	pub x: u16,
+	pub y: u16,
+	pub width: u16,
+	pub height: u16,
+	pub typeface: Typeface,
+	pub align: Align,
+	pub line_spacing: u16,	// Rows left between one line and the next
+	pub wrap: bool,			// Break lines between words to fit the width
+	pub ellipsis: bool		// End text that does not fit with "..."
+}
+
+// A Typeface ready to measure and draw with
+enum Face {
+	Minimal(u16),
+	Proportional(OpenFont, u16)
+}
+
+// Bytes start to end of the text, set in one line. Advance is how far they
+// move the pen, width how far the line reaches with any ellipsis.
+struct Line {
+	start: usize,
+	end: usize,
+	next: usize,
+	advance: u16,
+	width: u16,
+	ellipsis: bool
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const ELLIPSIS: &[u8] = b"...";
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl TextBox {
+	// Left aligned, wrapped and single spaced text that is cut off where it
+	// runs out of room
+	pub const fn new(x: u16, y: u16, width: u16, height: u16, typeface: Typeface) -> Self {
+		TextBox {
+			x,
+			y,
+			width,
+			height,
+			typeface,
+			align: Align::Left,
+			line_spacing: 0,
+			wrap: true,
+			ellipsis: false
+		}
+	}
+}
+
+// The width of the widest line and the height of the lines together, as the
+// text would be set in the box
+#[allow(dead_code)]
+pub fn measure(text: &[u8], text_box: &TextBox) -> Result<(u16, u16), AssetError> {
+	let face = Face::open(text_box.typeface)?;
+	let mut width = 0;
+	let mut lines = 0;
+	set_lines(text, text_box, &face, |line, _| {
+		width = core::cmp::max(width, line.width);
+		lines += 1;
+		Ok(())
+	})?;
+
+	let height = match lines {
+		0 => 0,
+		_ => (lines * face.get_line_height()) + ((lines - 1) * text_box.line_spacing)
+	};
+	Ok((width, height))
+}
+
+// Fills the box with bg and sets the text in it in fg. Returns the size of the
+// text, as measure does.
+#[allow(dead_code)]
+pub fn write(text: &[u8], text_box: &TextBox, fg: Color, bg: Background) -> Result<(u16, u16), AssetError> {
+	let face = Face::open(text_box.typeface)?;
+	let clip = (text_box.x, core::cmp::min(text_box.x as u32 + text_box.width as u32, 240) as u16);
+	lcd_api::fill_area(text_box.x, clip.1.saturating_sub(text_box.x), text_box.y, text_box.height, bg);
+
+	let mut width = 0;
+	let mut bottom = text_box.y;
+	set_lines(text, text_box, &face, |line, y| {
+		let room = text_box.width.saturating_sub(line.width);
+		let x = text_box.x + match text_box.align {
+			Align::Left => 0,
+			Align::Center => room / 2,
+			Align::Right => room
+		};
+
+		face.write(&text[line.start..line.end], x, y, clip, fg, bg)?;
+		if line.ellipsis {
+			face.write(ELLIPSIS, x + line.advance, y, clip, fg, bg)?;
+		}
+
+		width = core::cmp::max(width, line.width);
+		bottom = y + face.get_line_height();
+		Ok(())
+	})?;
+
+	Ok((width, bottom - text_box.y))
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+impl Face {
+	fn open(typeface: Typeface) -> Result<Self, AssetError> {
+		match typeface {
+			Typeface::Minimal(scale) => Ok(Face::Minimal(scale)),
+			Typeface::Proportional(font) => Ok(Face::Proportional(font::open_font(font)?, font.get_height()))
+		}
+	}
+
+	fn get_advance(&self, c: char) -> Result<u16, AssetError> {
+		match self {
+			Face::Minimal(scale) => Ok((font::MINIMAL_CHARACTER_WIDTH + 1) * scale),
+			Face::Proportional(font, _) => font.get_advance(c)
+		}
+	}
+
+	fn get_text_advance(&self, text: &[u8]) -> Result<u16, AssetError> {
+		let mut advance: u16 = 0;
+		for c in font::get_chars(text) {
+			advance = advance.saturating_add(self.get_advance(c)?);
+		}
+		Ok(advance)
+	}
+
+	fn get_line_height(&self) -> u16 {
+		match self {
+			Face::Minimal(scale) => font::MINIMAL_CHARACTER_HEIGHT * scale,
+			Face::Proportional(_, height) => *height
+		}
+	}
+
+	// Minimal characters carry the columns that separate them from the next,
+	// which the last one on a line does not need
+	fn get_gap(&self) -> u16 {
+		match self {
+			Face::Minimal(scale) => *scale,
+			Face::Proportional(_, _) => 0
+		}
+	}
+
+	// How far text with this advance reaches
+	fn get_width(&self, advance: u16) -> u16 {
+		advance.saturating_sub(self.get_gap())
+	}
+
+	fn write(&self, text: &[u8], x: u16, y: u16, clip: (u16, u16), fg: Color, bg: Background) -> Result<(), AssetError> {
+		match self {
+			Face::Minimal(scale) => {
+				font::write_minimal_glyphs(text, x, y, clip, fg, *scale);
+				Ok(())
+			},
+			Face::Proportional(font, _) => font::write_glyphs(text, x, y, clip, font, fg, bg)
+		}
+	}
+}
+
+impl Line {
+	fn new(start: usize, end: usize, next: usize, advance: u16, face: &Face) -> Self {
+		Line { start, end, next, advance, width: face.get_width(advance), ellipsis: false }
+	}
+}
+
+// Hands each line that fits in the box to f, along with the row it goes on
+fn set_lines<F>(text: &[u8], text_box: &TextBox, face: &Face, mut f: F) -> Result<(), AssetError>
+where
+	F: FnMut(&Line, u16) -> Result<(), AssetError>
+{
+	let pitch = face.get_line_height() + text_box.line_spacing;
+	let max_lines = (text_box.height + text_box.line_spacing) / core::cmp::max(pitch, 1);
+
+	let mut start = 0;
+	let mut y = text_box.y;
+	for n in 0..max_lines {
+		if start >= text.len() {
+			break;
+		}
+
+		let mut line = break_line(text, start, text_box, face)?;
+		let cut_short = (n + 1 == max_lines && line.next < text.len()) || line.width > text_box.width;
+		if text_box.ellipsis && cut_short {
+			line = add_ellipsis(text, &line, text_box, face)?;
+		}
+
+		f(&line, y)?;
+		start = line.next;
+		y += pitch;
+	}
+	Ok(())
+}
+
+// Sets as much of the text from start as fits on a line
+fn break_line(text: &[u8], start: usize, text_box: &TextBox, face: &Face) -> Result<Line, AssetError> {
+	let mut advance: u16 = 0;
+	// Where the last space before the line got too long was
+	let mut space: Option<(usize, u16, usize)> = None;
+	let mut after_space = false;
+
+	for (i, c) in font::get_char_indices(&text[start..]) {
+		let i = start + i;
+		if c == '\n' {
+			return Ok(Line::new(start, i, i + 1, advance, face));
+		}
+
+		let next_advance = advance.saturating_add(face.get_advance(c)?);
+		if c == ' ' {
+			// A run of spaces breaks before the first, the next line starts
+			// after the last
+			space = match space {
+				Some((end, end_advance, _)) if after_space => Some((end, end_advance, i + 1)),
+				_ => Some((i, advance, i + 1))
+			};
+			after_space = true;
+		}
+		else {
+			after_space = false;
+			if text_box.wrap && face.get_width(next_advance) > text_box.width && i > start {
+				return Ok(match space {
+					Some((end, end_advance, next)) => Line::new(start, end, next, end_advance, face),
+					None => Line::new(start, i, i, advance, face)
+				});
+			}
+		}
+		advance = next_advance;
+	}
+
+	Ok(Line::new(start, text.len(), text.len(), advance, face))
+}
+
+// Cuts the line short enough to end it with an ellipsis inside the box
+fn add_ellipsis(text: &[u8], line: &Line, text_box: &TextBox, face: &Face) -> Result<Line, AssetError> {
+	let ellipsis = face.get_text_advance(ELLIPSIS)?;
+	let limit = (text_box.width + face.get_gap()).saturating_sub(ellipsis);
+	let mut advance: u16 = 0;
+	let mut end = line.end;
+	for (i, c) in font::get_char_indices(&text[line.start..line.end]) {
+		let next_advance = advance.saturating_add(face.get_advance(c)?);
+		if next_advance > limit {
+			end = line.start + i;
+			break;
+		}
+		advance = next_advance;
+	}
+
+	// "Hello..." rather than "Hello ..."
+	while end > line.start && text[end - 1] == b' ' {
+		end -= 1;
+		advance -= face.get_advance(' ')?;
+	}
+
+	Ok(Line {
+		start: line.start,
+		end,
+		next: line.next,
+		advance,
+		width: face.get_width(advance + ellipsis),
+		ellipsis: true
+	})
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
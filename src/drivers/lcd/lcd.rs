@@ -7,28 +7,62 @@
 //==============================================================================
 // Crates and Mods
 //==============================================================================
-use heapless::Vec;
 use crate::config;
 use crate::drivers::log;
 use crate::mcu::{gpio, spi, spim, timer};
 use nrf52832_pac::p0::pin_cnf::DIR_A as DIR;
 use nrf52832_pac::p0::pin_cnf::PULL_A as PULL;
 
-use super::st7789;
+use super::{lcd_api, st7789};
 
 //==============================================================================
 // Enums, Structs, and Types
 //==============================================================================
+pub struct St7789;
 
 
 //==============================================================================
 // Variables
 //==============================================================================
-
+// Bytes sent per SPIM transfer, the most it takes that still holds whole pixels
+pub const BLOCK_SIZE: usize = 254;
+const BLOCK_PIXELS: usize = BLOCK_SIZE / 2;
 
 //==============================================================================
 // Public Functions
 //==============================================================================
+impl lcd_api::Display for St7789 {
+	fn set_window(&mut self, x: u16, width: u16, y: u16, height: u16) {
+		let x_end = x + width - 1;
+		let y_end = y + height - 1;
+
+		// TODO: Check that this endian conversion is correct
+		let x = x.to_le_bytes();
+		let x_end = x_end.to_le_bytes();
+		let y = y.to_le_bytes();
+		let y_end = y_end.to_le_bytes();
+
+		// Define the window column size
+		write_command(st7789::COMMAND::COLUMN_ADDRESS);
+		write_data(&[ x[1], x[0], x_end[1], x_end[0] ]);
+
+		// Define the window row size
+		write_command(st7789::COMMAND::ROW_ADDRESS);
+		write_data(&[ y[1], y[0], y_end[1], y_end[0] ]);
+
+		// Everything that follows is pixel data for this window
+		write_command(st7789::COMMAND::MEMORY_WRITE);
+	}
+
+	fn write_pixels(&mut self, pixels: &[u8]) {
+		write_block(pixels);
+	}
+
+	fn fill(&mut self, color: u16, len: u32) {
+		write_block_solid(color, len);
+	}
+}
+
 pub fn init() {
 	// Initialize lcd control pins
 	gpio::pin_setup(config::LCD_CS_PIN, DIR::OUTPUT, gpio::PinState::PinHigh, PULL::DISABLED);
@@ -43,6 +77,11 @@ pub fn init() {
 	set_backlight(0);
 }
 
+pub fn get_busy() -> bool {
+	// Pixel blocks are still being clocked out by the DMA
+	spim::get_busy()
+}
+
 pub fn set_backlight(backlight: u8) {
 	let mut states: [gpio::PinState; 3] = [gpio::PinState::PinHigh; 3];
 	for s in 0..3 {
@@ -61,6 +100,8 @@ pub fn set_backlight(backlight: u8) {
 }
 
 pub fn write_command(command: st7789::COMMAND) {
+	flush();
+
 	gpio::set_pin_state(config::LCD_CS_PIN, gpio::PinState::PinLow);
 	gpio::set_pin_state(config::LCD_DCX_PIN, gpio::PinState::PinLow);
 
@@ -73,6 +114,8 @@ pub fn write_command(command: st7789::COMMAND) {
 }
 
 pub fn write_data(data: &[u8]) {
+	flush();
+
 	gpio::set_pin_state(config::LCD_CS_PIN, gpio::PinState::PinLow);
 	gpio::set_pin_state(config::LCD_DCX_PIN, gpio::PinState::PinHigh);
 
@@ -87,75 +130,47 @@ pub fn write_block(data: &[u8]) {
 	gpio::set_pin_state(config::LCD_CS_PIN, gpio::PinState::PinLow);
 	gpio::set_pin_state(config::LCD_DCX_PIN, gpio::PinState::PinHigh);
 
-	let mut bytes_remaining = data.len();
-	let mut current_index: usize = 0;
-	let mut v: Vec<u8, 255> = Vec::new();
-
-	while bytes_remaining > 0 {
-		v.clear();
-		let bytes_this_transfer = if bytes_remaining > 0xFF {
-			0xFF
-		}
-		else {
-			bytes_remaining
-		};
-
-		if let Ok(()) = v.extend_from_slice(
-			&data[current_index..(current_index + bytes_this_transfer)]
-		) {
-			if let Err(_e) = spim::write(&v[..bytes_this_transfer]) {
-				log::push_log("Spim write block failed");
-				break;
-			}
-		}
-		else {
+	// Queued chunks alternate between the DMA buffers, CS is released once
+	// the last one is out
+	for chunk in data.chunks(BLOCK_SIZE) {
+		if let Err(_e) = spim::write_async(chunk, config::LCD_CS_PIN) {
+			log::push_log("Spim write block failed");
+			gpio::set_pin_state(config::LCD_CS_PIN, gpio::PinState::PinHigh);
 			break;
 		}
-
-		current_index += bytes_this_transfer;
-		bytes_remaining -= bytes_this_transfer;
 	}
-	
-	gpio::set_pin_state(config::LCD_CS_PIN, gpio::PinState::PinHigh);
 }
 
 pub fn write_block_solid(color: u16, len: u32) {
 	gpio::set_pin_state(config::LCD_CS_PIN, gpio::PinState::PinLow);
 	gpio::set_pin_state(config::LCD_DCX_PIN, gpio::PinState::PinHigh);
 
-	let block: [u16; 127] = [color; 127];
-	let block: [u8; 254] = unsafe {
-		core::mem::transmute::<[u16; 127], [u8; 254]>(block)
+	let block: [u16; BLOCK_PIXELS] = [color; BLOCK_PIXELS];
+	let block: [u8; BLOCK_SIZE] = unsafe {
+		core::mem::transmute::<[u16; BLOCK_PIXELS], [u8; BLOCK_SIZE]>(block)
 	};
-	let mut v: Vec<u8, 254> = Vec::new();
 	let mut remaining = len;
-	if let Ok(()) = v.extend_from_slice(&block[..]) {
-		while remaining > 0 {
-			if remaining > 127 {
-				if let Err(_e) = spim::write(&v[..]) {
-					log::push_log("Spim write solid failed");
-				}
-				remaining -= 127;
-			}
-			else {
-				let end: usize = (remaining * 2) as usize;
-				if let Err(_e) = spim::write(&v[0..end]) {
-					log::push_log("Spim write solid failed");
-				}
-				remaining = 0;
-			}
+	while remaining > 0 {
+		let pixels = if remaining > BLOCK_PIXELS as u32 { BLOCK_PIXELS as u32 } else { remaining };
+		if let Err(_e) = spim::write_async(&block[..(pixels * 2) as usize], config::LCD_CS_PIN) {
+			log::push_log("Spim write solid failed");
+			gpio::set_pin_state(config::LCD_CS_PIN, gpio::PinState::PinHigh);
+			break;
 		}
+		remaining -= pixels;
 	}
-	else {
-		log::push_log("Vec extend failed");
-	}
-
-	gpio::set_pin_state(config::LCD_CS_PIN, gpio::PinState::PinHigh);
 }
 
 //==============================================================================
 // Private Functions
 //==============================================================================
+fn flush() {
+	// DCX and CS can only change once queued pixels are out
+	if let Err(_e) = spim::flush() {
+		log::push_log("Spim block transfer failed");
+	}
+}
+
 fn configure() {
 	// Enter safe reset sequence
 	gpio::set_pin_state(config::LCD_RESET_PIN, gpio::PinState::PinHigh);
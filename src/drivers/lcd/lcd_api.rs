@@ -2,16 +2,29 @@
 // Notes
 //==============================================================================
 // drivers::lcd.rs
+// Everything drawn goes through a Display. By default that is the ST7789 panel,
+// but any other Display, such as a RAM FrameBuffer, can be swapped in.
+//
+// Images and glyphs from the asset pack are streamed out of the SPI flash a
+// chunk at a time. Their pixels, including those of RLE compressed images, are
+// expanded straight into one SPIM block at a time on the way to the Display,
+// and long runs of one color are sent with fill. Anti-aliased pixels are
+// blended with the Background they are drawn on as they go.
 
 //==============================================================================
 // Crates and Mods
 //==============================================================================
-use super::{images, lcd, st7789};
+use core::ptr;
+use super::images::{self, Image};
+use super::lcd;
+use crate::drivers::assets::{self, Asset, AssetError, Compression, PixelFormat};
+use crate::drivers::log;
 
 //==============================================================================
 // Enums, Structs, and Types
 //==============================================================================
 #[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum BacklightBrightness {
 	Brightness0 = 0,
 	Brightness1 = 1,
@@ -43,14 +56,182 @@ pub enum Color { // 5-6-5		R,  G,  B
 	Rust		= 0xE078,
 }
 
+// What a box of text is filled with and the edges of anti-aliased glyphs are
+// blended against: a color, or a vertical gradient from top at row y to bottom
+// at row y + height - 1. Rows above and below take the nearer end's color.
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+pub enum Background {
+	Solid(Color),
+	Gradient { top: Color, bottom: Color, y: u16, height: u16 }
+}
+
+pub trait Display {
+	// Define the area to draw in, pixels fill it left to right, top to bottom
+	fn set_window(&mut self, x: u16, width: u16, y: u16, height: u16);
+
+	// Stream big-endian RGB565 pixel data into the window
+	fn write_pixels(&mut self, pixels: &[u8]);
+
+	// Stream len pixels of the same color into the window
+	fn fill(&mut self, color: u16, len: u32);
+}
+
+// Pixels on their way to the Display, gathered into a block for the SPIM. A
+// keyed image is drawn in spans along each row, skipping transparent pixels.
+// Flush once the last pixel has been pushed.
+pub struct Blitter {
+	x: u16,
+	y: u16,
+	width: u16,
+	key: Option<[u8; 2]>,
+	column: u16,
+	row: u16,
+	start: u16,
+	pixels: [u8; lcd::BLOCK_SIZE],
+	count: usize
+}
+
+// Where an RLE stream was left at the end of a chunk. Packets start with a
+// control byte, 0x80 | (n - 1) for n copies of the pixel that follows or n - 1
+// for n pixels as they are. Runs and literals are 1 to 128 pixels long.
+#[derive(Clone, Copy)]
+enum RleState {
+	Control,
+	Run(u16, Option<u8>),
+	Literal(u16, Option<u8>)
+}
+
 //==============================================================================
 // Variables
 //==============================================================================
+// Runs at least this long are sent with fill rather than expanded
+const FILL_MIN: u16 = 8;
 
+static mut LCD: lcd::St7789 = lcd::St7789;
+static mut DISPLAY: Option<&'static mut dyn Display> = None;
 
 //==============================================================================
 // Public Functions
 //==============================================================================
+impl BacklightBrightness {
+	// Levels above Brightness7 are not valid
+	pub fn from_level(level: u8) -> Option<Self> {
+		match level {
+			0 => Some(BacklightBrightness::Brightness0),
+			1 => Some(BacklightBrightness::Brightness1),
+			2 => Some(BacklightBrightness::Brightness2),
+			3 => Some(BacklightBrightness::Brightness3),
+			4 => Some(BacklightBrightness::Brightness4),
+			5 => Some(BacklightBrightness::Brightness5),
+			6 => Some(BacklightBrightness::Brightness6),
+			7 => Some(BacklightBrightness::Brightness7),
+			_ => None
+		}
+	}
+}
+
+impl Color {
+	pub fn to_rgb565(self) -> u16 {
+		// Colors are stored byte swapped, ready to be sent out little endian
+		(self as u16).swap_bytes()
+	}
+}
+
+impl Background {
+	// The RGB565 color of the background on the given row of the display
+	pub fn get_rgb565(&self, row: u16) -> u16 {
+		match *self {
+			Background::Solid(color) => color.to_rgb565(),
+			Background::Gradient { top, bottom, y, height } => {
+				let position = row.saturating_sub(y).min(height.saturating_sub(1));
+				blend(bottom.to_rgb565(), top.to_rgb565(), (position as u32 * 255 / height.saturating_sub(1).max(1) as u32) as u8)
+			}
+		}
+	}
+}
+
+impl Blitter {
+	pub fn new(x: u16, y: u16, width: u16, height: u16, key: Option<u16>) -> Self {
+		// Spans of a keyed image each get a window of their own
+		if key.is_none() {
+			set_window(x, width, y, height);
+		}
+
+		Blitter {
+			x,
+			y,
+			width,
+			key: key.map(|key| key.to_be_bytes()),
+			column: 0,
+			row: 0,
+			start: 0,
+			pixels: [0; lcd::BLOCK_SIZE],
+			count: 0
+		}
+	}
+
+	pub fn push(&mut self, pixel: [u8; 2]) {
+		if Some(pixel) == self.key {
+			self.flush();
+		}
+		else {
+			if self.count == 0 {
+				self.start = self.column;
+			}
+			self.pixels[self.count..self.count + 2].copy_from_slice(&pixel);
+			self.count += 2;
+
+			if self.count == self.pixels.len() {
+				self.flush();
+			}
+		}
+		self.advance(1);
+	}
+
+	pub fn run(&mut self, pixel: [u8; 2], len: u16) {
+		let mut remaining = len;
+		while remaining > 0 {
+			// A keyed run is cut at the end of each row
+			let len = match self.key {
+				Some(_) => core::cmp::min(remaining, self.width - self.column),
+				None => remaining
+			};
+
+			if Some(pixel) == self.key {
+				self.flush();
+				self.advance(len);
+			}
+			else if len >= FILL_MIN {
+				self.flush();
+				if self.key.is_some() {
+					set_window(self.x + self.column, len, self.y + self.row, 1);
+				}
+				fill(u16::from_le_bytes(pixel), len as u32);
+				self.advance(len);
+			}
+			else {
+				for _ in 0..len {
+					self.push(pixel);
+				}
+			}
+			remaining -= len;
+		}
+	}
+
+	pub fn flush(&mut self) {
+		if self.count == 0 {
+			return;
+		}
+
+		if self.key.is_some() {
+			set_window(self.x + self.start, (self.count / 2) as u16, self.y + self.row, 1);
+		}
+		write_pixels(&self.pixels[..self.count]);
+		self.count = 0;
+	}
+}
+
 pub fn init() {
 	lcd::init();
 	fill_background(Color::Black);
@@ -58,8 +239,17 @@ pub fn init() {
 }
 
 pub fn get_busy() -> bool {
-	// For now, not using DMA, this library will never be busy
-	false
+	lcd::get_busy()
+}
+
+// Mixes alpha / 255 of fg with the rest of bg, both RGB565
+pub fn blend(fg: u16, bg: u16, alpha: u8) -> u16 {
+	let mix = |shift: u16, mask: u16| {
+		let fg = ((fg >> shift) & mask) as u32;
+		let bg = ((bg >> shift) & mask) as u32;
+		((((fg * alpha as u32) + (bg * (255 - alpha as u32)) + 127) / 255) as u16) << shift
+	};
+	mix(11, 0x1F) | mix(5, 0x3F) | mix(0, 0x1F)
 }
 
 #[allow(dead_code)]
@@ -67,10 +257,29 @@ pub fn fill_background(color: Color) {
 	fill_rectangle(0, 240, 0, 240, color);
 }
 
+#[allow(dead_code)]
+pub fn fill(color: u16, len: u32) {
+	get_display().fill(color, len);
+}
+
 pub fn fill_rectangle(x: u16, width: u16, y: u16, height: u16, color: Color) {
-	set_window(x, width, y, height);
-	lcd::write_command(st7789::COMMAND::MEMORY_WRITE);
-	lcd::write_block_solid(color as u16, (width*height) as u32);
+	let display = get_display();
+	display.set_window(x, width, y, height);
+	display.fill(color as u16, (width as u32) * (height as u32));
+}
+
+// Fills the rectangle with the background, a band of rows per gradient step
+#[allow(dead_code)]
+pub fn fill_area(x: u16, width: u16, y: u16, height: u16, background: Background) {
+	let display = get_display();
+	let mut row = y;
+	while row < y + height {
+		let color = background.get_rgb565(row);
+		let rows = (row..y + height).take_while(|r| background.get_rgb565(*r) == color).count() as u16;
+		display.set_window(x, width, row, rows);
+		display.fill(color.swap_bytes(), (width as u32) * (rows as u32));
+		row += rows;
+	}
 }
 
 #[allow(dead_code)]
@@ -79,35 +288,150 @@ pub fn set_backlight(target_brightness: BacklightBrightness) {
 	lcd::set_backlight(target_brightness as u8);
 }
 
+#[allow(dead_code)]
+pub fn set_display(display: Option<&'static mut dyn Display>) {
+	// None hands drawing back to the LCD
+	unsafe { *ptr::addr_of_mut!(DISPLAY) = display; }
+}
+
 pub fn set_window(x: u16, width: u16, y: u16, height: u16) {
-	let x_end = x + width - 1;
-	let y_end = y + height - 1;
+	get_display().set_window(x, width, y, height);
+}
+
+#[allow(dead_code)]
+pub fn write_pixels(pixels: &[u8]) {
+	get_display().write_pixels(pixels);
+}
 
-	// TODO: Check that this endian conversion is correct
-	let x = x.to_le_bytes();
-	let x_end = x_end.to_le_bytes();
-	let y = y.to_le_bytes();
-	let y_end = y_end.to_le_bytes();
+// Draws the image with its top left corner at x, y. Where a keyed image is
+// transparent, what is on the display is left as it is.
+#[allow(dead_code)]
+pub fn write_image(image: &Image, x: u16, y: u16) -> Result<(), AssetError> {
+	let asset = assets::find(image.get_name())?;
+	if asset.get_format() != PixelFormat::Rgb565 {
+		return Err(AssetError::WrongFormat);
+	}
+	// A pack from another build
+	if asset.get_width() != image.get_width() || asset.get_height() != image.get_height() {
+		return Err(AssetError::Corrupt);
+	}
+
+	let mut blitter = Blitter::new(x, y, image.get_width(), image.get_height(), image.get_key());
+	let result = match asset.get_compression() {
+		// CHUNK_SIZE is even, so chunks hold whole pixels
+		Compression::None => assets::stream(&asset, 0, asset.get_size(), |bytes| {
+			for pixel in bytes.chunks_exact(2) {
+				blitter.push([pixel[0], pixel[1]]);
+			}
+		}),
+		Compression::Rle => {
+			let mut state = RleState::Control;
+			assets::stream(&asset, 0, asset.get_size(), |bytes| {
+				for byte in bytes {
+					state = state.decode(*byte, &mut blitter);
+				}
+			})
+		}
+	};
+
+	blitter.flush();
+	result
+}
+
+// Draws rows first_row to first_row + rows of a 1 bit per pixel asset at x, y,
+// set bits in fg and clear bits in bg
+#[allow(dead_code)]
+pub fn write_mono(image: &Asset, first_row: u16, rows: u16, x: u16, y: u16, fg: Color, bg: Color) -> Result<(), AssetError> {
+	if image.get_format() != PixelFormat::Mono1 || image.get_compression() != Compression::None {
+		return Err(AssetError::WrongFormat);
+	}
+	if first_row + rows > image.get_height() {
+		return Err(AssetError::OutOfRange);
+	}
+
+	// Rows need not start on a byte boundary
+	let width = image.get_width() as u32;
+	let first_bit = first_row as u32 * width;
+	let bits = rows as u32 * width;
+	let offset = first_bit / 8;
+	let len = (first_bit % 8 + bits).div_ceil(8);
 
-	// Define the window column size
-	lcd::write_command(st7789::COMMAND::COLUMN_ADDRESS);
-	lcd::write_data( &mut [ x[1], x[0], x_end[1], x_end[0] ]);
+	let fg = (fg as u16).to_le_bytes();
+	let bg = (bg as u16).to_le_bytes();
+	let mut skip = first_bit % 8;
+	let mut remaining = bits;
 
-	// Define the window row size
-	lcd::write_command(st7789::COMMAND::ROW_ADDRESS);
-	lcd::write_data( &mut [ y[1], y[0], y_end[1], y_end[0] ]);
+	let mut blitter = Blitter::new(x, y, image.get_width(), rows, None);
+	let result = assets::stream(image, offset, len, |bytes| {
+		for byte in bytes {
+			for bit in skip..8 {
+				if remaining == 0 {
+					break;
+				}
+				blitter.push(if (byte & (0x80 >> bit)) > 0 { fg } else { bg });
+				remaining -= 1;
+			}
+			skip = 0;
+		}
+	});
+
+	blitter.flush();
+	result
 }
 
 #[allow(dead_code)]
 pub fn write_splash() {
-	set_window(39, 160, 59, 106);
-	lcd::write_command(st7789::COMMAND::MEMORY_WRITE);
-	lcd::write_block(&images::RUSTACEAN);
+	if let Err(e) = write_image(&images::RUSTACEAN, 39, 59) {
+		log::push_log(assets::get_error_string(e));
+	}
 }
 
 //==============================================================================
 // Private Functions
 //==============================================================================
+impl Blitter {
+	// Only keyed images need to know where they are
+	fn advance(&mut self, len: u16) {
+		if self.key.is_none() {
+			return;
+		}
+
+		self.column += len;
+		if self.column >= self.width {
+			self.flush();
+			self.column = 0;
+			self.row += 1;
+		}
+	}
+}
+
+impl RleState {
+	fn decode(self, byte: u8, blitter: &mut Blitter) -> Self {
+		match self {
+			RleState::Control if (byte & 0x80) > 0 => RleState::Run((byte & 0x7F) as u16 + 1, None),
+			RleState::Control => RleState::Literal(byte as u16 + 1, None),
+			RleState::Run(len, None) => RleState::Run(len, Some(byte)),
+			RleState::Run(len, Some(high)) => {
+				blitter.run([high, byte], len);
+				RleState::Control
+			},
+			RleState::Literal(len, None) => RleState::Literal(len, Some(byte)),
+			RleState::Literal(len, Some(high)) => {
+				blitter.push([high, byte]);
+				if len > 1 { RleState::Literal(len - 1, None) } else { RleState::Control }
+			}
+		}
+	}
+}
+
+fn get_display() -> &'static mut dyn Display {
+	unsafe {
+		match &mut *ptr::addr_of_mut!(DISPLAY) {
+			Some(display) => &mut **display,
+			None => &mut *ptr::addr_of_mut!(LCD),
+		}
+	}
+}
 
 
 //==============================================================================
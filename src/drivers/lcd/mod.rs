@@ -13,9 +13,12 @@
 // Enums, Structs, and Types
 //==============================================================================
 pub mod font;
+pub mod framebuffer;
 pub mod images;
+pub mod layout;
 pub mod lcd;
 pub mod lcd_api;
+pub mod shapes;
 mod st7789;
 
 //==============================================================================
@@ -0,0 +1,357 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::lcd::shapes.rs
+// Lines, circles, arcs, rounded rectangles and polygons. Every shape is broken
+// into runs of pixels along a row (or down a column, for steep thin lines),
+// and each run is one window and one fill, so shapes cost a few bytes per row
+// over the SPI rather than a write per pixel. Anything off the display is cut
+// off.
+//
+// Angles are in degrees, clockwise from 12 o'clock, as on a watch face.
+// Outlines are drawn thickness pixels inwards from the edge of the shape.
+// Polygons are filled even-odd, the corners taken as pixel centers.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use super::lcd_api::{self, Color};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+// The part of a circle an arc covers, as the directions of its start and end
+struct Sector {
+	start: (i32, i32),
+	end: (i32, i32),
+	wide: bool		// More than half of the circle
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+#[allow(dead_code)]
+pub const MAX_POLYGON_POINTS: usize = 16;
+
+const DISPLAY_SIZE: i32 = 240;
+
+// Positions inside polygons are kept in 1/256ths of a pixel
+const SUBPIXELS: i32 = 256;
+
+// sin(0..=90 degrees) * 16384
+const SINE: [i32; 91] = [
+	0, 286, 572, 857, 1143, 1428, 1713, 1997, 2280, 2563,
+	2845, 3126, 3406, 3686, 3964, 4240, 4516, 4790, 5063, 5334,
+	5604, 5872, 6138, 6402, 6664, 6924, 7182, 7438, 7692, 7943,
+	8192, 8438, 8682, 8923, 9162, 9397, 9630, 9860, 10087, 10311,
+	10531, 10749, 10963, 11174, 11381, 11585, 11786, 11982, 12176, 12365,
+	12551, 12733, 12911, 13085, 13255, 13421, 13583, 13741, 13894, 14044,
+	14189, 14330, 14466, 14598, 14726, 14849, 14968, 15082, 15191, 15296,
+	15396, 15491, 15582, 15668, 15749, 15826, 15897, 15964, 16026, 16083,
+	16135, 16182, 16225, 16262, 16294, 16322, 16344, 16362, 16374, 16382,
+	16384
+];
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+// A line from one end to the other, both included, thickness pixels across
+#[allow(dead_code)]
+pub fn draw_line(x0: u16, y0: u16, x1: u16, y1: u16, thickness: u16, color: Color) {
+	if thickness <= 1 {
+		write_thin_line(x0 as i32, y0 as i32, x1 as i32, y1 as i32, color);
+	}
+	else {
+		write_thick_line(x0 as i32, y0 as i32, x1 as i32, y1 as i32, thickness as i32, color);
+	}
+}
+
+#[allow(dead_code)]
+pub fn draw_circle(x: u16, y: u16, radius: u16, thickness: u16, color: Color) {
+	let r = radius as i32;
+	write_ring(x as i32 - r, y as i32 - r, (2 * r) + 1, (2 * r) + 1, r, thickness as i32, None, color);
+}
+
+#[allow(dead_code)]
+pub fn fill_circle(x: u16, y: u16, radius: u16, color: Color) {
+	draw_circle(x, y, radius, radius + 1, color);
+}
+
+// Part of a circle's outline, clockwise from start to end. An arc that ends
+// where it starts goes all the way round.
+#[allow(dead_code)]
+pub fn draw_arc(x: u16, y: u16, radius: u16, thickness: u16, start: u16, end: u16, color: Color) {
+	let start = start % 360;
+	let end = end % 360;
+	let sweep = if end > start { end - start } else { end + 360 - start };
+
+	let r = radius as i32;
+	let sector = (sweep < 360).then(|| Sector {
+		start: get_direction(start),
+		end: get_direction(end),
+		wide: sweep > 180
+	});
+	write_ring(x as i32 - r, y as i32 - r, (2 * r) + 1, (2 * r) + 1, r, thickness as i32, sector.as_ref(), color);
+}
+
+#[allow(dead_code)]
+pub fn draw_rounded_rectangle(x: u16, width: u16, y: u16, height: u16, radius: u16, thickness: u16, color: Color) {
+	let radius = get_corner_radius(width, height, radius);
+	write_ring(x as i32, y as i32, width as i32, height as i32, radius, thickness as i32, None, color);
+}
+
+#[allow(dead_code)]
+pub fn fill_rounded_rectangle(x: u16, width: u16, y: u16, height: u16, radius: u16, color: Color) {
+	draw_rounded_rectangle(x, width, y, height, radius, core::cmp::max(width, height), color);
+}
+
+#[allow(dead_code)]
+pub fn fill_triangle(a: (u16, u16), b: (u16, u16), c: (u16, u16), color: Color) {
+	fill_polygon(&[a, b, c], color);
+}
+
+// Only the first MAX_POLYGON_POINTS corners are used
+#[allow(dead_code)]
+pub fn fill_polygon(points: &[(u16, u16)], color: Color) {
+	let mut corners = [(0, 0); MAX_POLYGON_POINTS];
+	let count = core::cmp::min(points.len(), MAX_POLYGON_POINTS);
+	for (corner, point) in corners.iter_mut().zip(points) {
+		*corner = (to_subpixels(point.0 as i32), to_subpixels(point.1 as i32));
+	}
+	write_polygon(&corners[..count], color);
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+impl Sector {
+	// Whether the direction from the center to a pixel falls in the arc
+	fn contains(&self, x: i32, y: i32) -> bool {
+		// Positive when b is less than half a turn clockwise of a
+		let cross = |a: (i32, i32), b: (i32, i32)| (a.0 * b.1) - (a.1 * b.0);
+		if self.wide {
+			!(cross(self.end, (x, y)) > 0 && cross((x, y), self.start) > 0)
+		}
+		else {
+			cross(self.start, (x, y)) >= 0 && cross((x, y), self.end) >= 0
+		}
+	}
+}
+
+fn write_span(x: i32, end: i32, y: i32, color: Color) {
+	let x = core::cmp::max(x, 0);
+	let end = core::cmp::min(end, DISPLAY_SIZE);
+	if x < end && (0..DISPLAY_SIZE).contains(&y) {
+		lcd_api::fill_rectangle(x as u16, (end - x) as u16, y as u16, 1, color);
+	}
+}
+
+fn write_column(x: i32, y: i32, end: i32, color: Color) {
+	let y = core::cmp::max(y, 0);
+	let end = core::cmp::min(end, DISPLAY_SIZE);
+	if y < end && (0..DISPLAY_SIZE).contains(&x) {
+		lcd_api::fill_rectangle(x as u16, 1, y as u16, (end - y) as u16, color);
+	}
+}
+
+// Bresenham, gathering the pixels into runs along the line's longer axis
+fn write_thin_line(x0: i32, y0: i32, x1: i32, y1: i32, color: Color) {
+	let dx = (x1 - x0).abs();
+	let dy = -(y1 - y0).abs();
+	let step_x = if x0 < x1 { 1 } else { -1 };
+	let step_y = if y0 < y1 { 1 } else { -1 };
+	let flat = dx >= -dy;
+	let mut error = dx + dy;
+
+	let write_run = |from: (i32, i32), to: (i32, i32)| {
+		if flat {
+			write_span(core::cmp::min(from.0, to.0), core::cmp::max(from.0, to.0) + 1, to.1, color);
+		}
+		else {
+			write_column(to.0, core::cmp::min(from.1, to.1), core::cmp::max(from.1, to.1) + 1, color);
+		}
+	};
+
+	let (mut x, mut y) = (x0, y0);
+	let mut run = (x0, y0);
+	while x != x1 || y != y1 {
+		let (mut next_x, mut next_y) = (x, y);
+		let doubled = 2 * error;
+		if doubled >= dy {
+			error += dy;
+			next_x += step_x;
+		}
+		if doubled <= dx {
+			error += dx;
+			next_y += step_y;
+		}
+
+		if (flat && next_y != y) || (!flat && next_x != x) {
+			write_run(run, (x, y));
+			run = (next_x, next_y);
+		}
+		x = next_x;
+		y = next_y;
+	}
+	write_run(run, (x, y));
+}
+
+// A rectangle around the line, filled as a polygon
+fn write_thick_line(x0: i32, y0: i32, x1: i32, y1: i32, thickness: i32, color: Color) {
+	let dx = (x1 - x0) as i64;
+	let dy = (y1 - y0) as i64;
+	let length = ((dx * dx + dy * dy) * (SUBPIXELS * SUBPIXELS) as i64).isqrt();
+	let half = (thickness * SUBPIXELS / 2) as i64;
+
+	// A line with no length is a square
+	let (normal_x, normal_y) = match length {
+		0 => (0, half as i32),
+		_ => ((-dy * half * SUBPIXELS as i64 / length) as i32, (dx * half * SUBPIXELS as i64 / length) as i32)
+	};
+	let (x0, y0, x1, y1) = (to_subpixels(x0), to_subpixels(y0), to_subpixels(x1), to_subpixels(y1));
+	let (x0, x1) = if length == 0 { (x0 - half as i32, x1 + half as i32) } else { (x0, x1) };
+
+	write_polygon(&[
+		(x0 + normal_x, y0 + normal_y),
+		(x1 + normal_x, y1 + normal_y),
+		(x1 - normal_x, y1 - normal_y),
+		(x0 - normal_x, y0 - normal_y)
+	], color);
+}
+
+// Fills the rows of the polygon between each pair of edges they cross
+fn write_polygon(corners: &[(i32, i32)], color: Color) {
+	let top = corners.iter().map(|corner| corner.1).min().unwrap_or(0);
+	let bottom = corners.iter().map(|corner| corner.1).max().unwrap_or(0);
+	let first_row = core::cmp::max(to_pixel(top), 0);
+	let last_row = core::cmp::min(to_pixel(bottom), DISPLAY_SIZE);
+
+	for row in first_row..last_row {
+		let center = to_subpixels(row);
+		let mut crossings = [0i32; MAX_POLYGON_POINTS];
+		let mut count = 0;
+
+		for (i, a) in corners.iter().enumerate() {
+			let b = corners[(i + 1) % corners.len()];
+			if (a.1 <= center) != (b.1 <= center) {
+				let x = a.0 as i64 + ((center - a.1) as i64 * (b.0 - a.0) as i64 / (b.1 - a.1) as i64);
+				crossings[count] = x as i32;
+				count += 1;
+			}
+		}
+
+		crossings[..count].sort_unstable();
+		for pair in crossings[..count].chunks_exact(2) {
+			write_span(to_pixel(pair[0]), to_pixel(pair[1]), row, color);
+		}
+	}
+}
+
+// Draws the outline of a rounded rectangle row by row, as the rows of the
+// rectangle less those of the one inside it. In an arc, only the parts of
+// each row in its sector.
+#[allow(clippy::too_many_arguments)]
+fn write_ring(x: i32, y: i32, width: i32, height: i32, radius: i32, thickness: i32, sector: Option<&Sector>, color: Color) {
+	let inner_width = width - (2 * thickness);
+	let inner_height = height - (2 * thickness);
+	let inner_radius = core::cmp::max(radius - thickness, 0);
+	let center = (x + (width / 2), y + (height / 2));
+
+	let write = |start: i32, end: i32, row: i32| match sector {
+		Some(sector) => write_sector_span(start, end, row, center, sector, color),
+		None => write_span(start, end, row, color)
+	};
+
+	for row in 0..height {
+		let inset = get_inset(row, height, radius);
+		let start = x + inset;
+		let end = x + width - inset;
+
+		let inner_row = row - thickness;
+		if inner_width > 0 && inner_height > 0 && (0..inner_height).contains(&inner_row) {
+			let inner_inset = get_inset(inner_row, inner_height, inner_radius);
+			write(start, x + thickness + inner_inset, y + row);
+			write(x + width - thickness - inner_inset, end, y + row);
+		}
+		else {
+			write(start, end, y + row);
+		}
+	}
+}
+
+// Splits the span into the runs of pixels inside the sector
+fn write_sector_span(start: i32, end: i32, row: i32, center: (i32, i32), sector: &Sector, color: Color) {
+	let mut run: Option<i32> = None;
+	for x in start..end {
+		let inside = sector.contains(x - center.0, row - center.1);
+		match (inside, run) {
+			(true, None) => run = Some(x),
+			(false, Some(run_start)) => {
+				write_span(run_start, x, row, color);
+				run = None;
+			},
+			_ => ()
+		}
+	}
+	if let Some(run_start) = run {
+		write_span(run_start, end, row, color);
+	}
+}
+
+// How far in from the sides of a rounded rectangle a row starts. The corners
+// are quarter circles through pixel edges, as a circle of the same radius is.
+fn get_inset(row: i32, height: i32, radius: i32) -> i32 {
+	let dy = if row < radius {
+		radius - row
+	}
+	else if row >= height - radius {
+		row - (height - 1 - radius)
+	}
+	else {
+		0
+	};
+
+	radius - ((radius * radius) + radius - (dy * dy)).max(0).isqrt()
+}
+
+// Corners can be no larger than half the rectangle
+fn get_corner_radius(width: u16, height: u16, radius: u16) -> i32 {
+	let largest = (core::cmp::min(width, height).saturating_sub(1)) / 2;
+	core::cmp::min(radius, largest) as i32
+}
+
+// A unit vector pointing at the angle, y down the display
+fn get_direction(angle: u16) -> (i32, i32) {
+	let sin = get_sine(angle as i32);
+	let cos = get_sine(angle as i32 + 90);
+	(sin, -cos)
+}
+
+fn get_sine(angle: i32) -> i32 {
+	let angle = angle % 360;
+	match angle {
+		0..=90 => SINE[angle as usize],
+		91..=180 => SINE[(180 - angle) as usize],
+		181..=270 => -SINE[(angle - 180) as usize],
+		_ => -SINE[(360 - angle) as usize]
+	}
+}
+
+// The center of the pixel
+fn to_subpixels(pixel: i32) -> i32 {
+	(pixel * SUBPIXELS) + (SUBPIXELS / 2)
+}
+
+// The first pixel whose center is at or past the position
+fn to_pixel(position: i32) -> i32 {
+	(position - (SUBPIXELS / 2) + (SUBPIXELS - 1)).div_euclid(SUBPIXELS)
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
@@ -6,11 +6,20 @@
 //==============================================================================
 // Crates and Mods
 //==============================================================================
+pub mod alarm;
+pub mod assets;
 pub mod battery;
 pub mod button;
+pub mod calendar;
 pub mod clock;
+pub mod countdown;
+pub mod crc;
+pub mod flash;
+pub mod fs;
 pub mod lcd;
 pub mod log;
+pub mod settings;
+pub mod timezone;
 pub mod touch;
 
 use crate::app::info;
@@ -46,6 +55,9 @@ pub fn init() {
 	battery::init();
 	button::init();
 	clock::init();
+	flash::init();
+	fs::init();
+	assets::init();
 	touch::init();
 }
 
@@ -70,5 +82,8 @@ pub fn task_handler(d: &mut info::DeviceInfo){
 	battery::task_handler(d);
 	button::task_handler(d);
 	clock::task_handler(d);
+	alarm::task_handler(d);
+	countdown::task_handler(d);
+	settings::task_handler(d);
 	touch::task_handler(d);
 }
\ No newline at end of file
@@ -0,0 +1,363 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::settings.rs
+// Keeps the user settings in DeviceInfo across restarts, in the internal flash
+// pages set aside in config.rs.
+//
+// Settings are saved as records appended one after the other, so a page is
+// only erased once it is full and the erases are spread over all the pages:
+//	header:		MAGIC << 16 | entry count
+//	sequence:	one more than the record before it
+//	entries:	key << 16 | value
+//	crc:		CRC-32 over the words above
+// On load the record with the highest sequence and a good CRC wins. Keys that
+// are missing keep their default, unknown keys are skipped, so settings can be
+// added later without losing the ones already saved.
+//
+// A change is saved SETTINGS_SAVE_DELAY_MS after it is made, so stepping
+// through a setting writes one record rather than one for every step.
+//
+// Each alarm takes a key for its time as hours << 8 | minutes, one for its
+// repeat days with ALARM_PRESENT and ALARM_ENABLED set as needed, and its
+// label two characters to a key. A repeat without ALARM_PRESENT is an empty
+// slot. Records list the keys in KEYS order, so the repeat has made the slot
+// by the time its label comes.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use crate::app::info;
+use crate::config;
+use crate::drivers::{alarm, clock};
+use crate::drivers::crc::Crc32;
+use crate::drivers::lcd::lcd_api;
+use crate::mcu::{nvmc, rtc, soft_timer};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy)]
+enum Key {
+	MilitaryTime,
+	DebugLogActive,
+	Backlight,
+	AlarmTime(usize),
+	AlarmRepeat(usize),
+	AlarmLabel(usize, usize)	// (alarm, pair of characters)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Settings {
+	military_time: bool,
+	debug_log_active: bool,
+	backlight: lcd_api::BacklightBrightness,
+	alarms: [Option<alarm::Alarm>; alarm::ALARM_COUNT]
+}
+
+struct Store {
+	saved: Option<Settings>,
+	next_address: u32,
+	sequence: u32,
+	timer: Option<soft_timer::TimerId>
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const MAGIC: u32 = 0x5E77;
+const LABEL_KEYS: usize = alarm::ALARM_LABEL_LEN / 2;
+const KEY_COUNT: usize = 3 + (alarm::ALARM_COUNT * (2 + LABEL_KEYS));
+const KEYS: [Key; KEY_COUNT] = get_keys();
+const RECORD_WORDS: u32 = 3 + KEYS.len() as u32;
+const MAX_ENTRIES: u32 = 64;
+
+const ALARM_PRESENT: u16 = 0x8000;
+const ALARM_ENABLED: u16 = 0x0100;
+
+const REGION_START: u32 = config::NVMC_SETTINGS_ADDRESS;
+const REGION_END: u32 = config::NVMC_SETTINGS_ADDRESS + (config::NVMC_SETTINGS_PAGES * nvmc::PAGE_SIZE);
+
+static STORE: Mutex<RefCell<Store>> = Mutex::new(RefCell::new(Store {
+	saved: None,
+	next_address: REGION_START,
+	sequence: 0,
+	timer: None
+}));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+impl Key {
+	// Ids already saved must never change
+	const fn get_id(&self) -> u32 {
+		match self {
+			Key::MilitaryTime => 1,
+			Key::DebugLogActive => 2,
+			Key::Backlight => 3,
+			Key::AlarmTime(index) => 4 + *index as u32,
+			Key::AlarmRepeat(index) => 8 + *index as u32,
+			Key::AlarmLabel(index, pair) => 16 + (*index as u32 * 8) + *pair as u32
+		}
+	}
+}
+
+impl Settings {
+	fn from_device_info(d: &info::DeviceInfo) -> Settings {
+		Settings {
+			military_time: d.flags.military_time,
+			debug_log_active: d.flags.debug_log_active,
+			backlight: d.backlight,
+			alarms: core::array::from_fn(alarm::get)
+		}
+	}
+
+	fn get_value(&self, key: Key) -> u16 {
+		match key {
+			Key::MilitaryTime => self.military_time as u16,
+			Key::DebugLogActive => self.debug_log_active as u16,
+			Key::Backlight => self.backlight as u16,
+			Key::AlarmTime(index) => {
+				self.alarms[index].map_or(0, |a| ((a.time.hours as u16) << 8) | a.time.minutes as u16)
+			},
+			Key::AlarmRepeat(index) => {
+				self.alarms[index].map_or(0, |a| ALARM_PRESENT | if a.enabled { ALARM_ENABLED } else { 0 } | a.repeat as u16)
+			},
+			Key::AlarmLabel(index, pair) => {
+				self.alarms[index].map_or(0, |a| u16::from_be_bytes([a.label[pair * 2], a.label[(pair * 2) + 1]]))
+			}
+		}
+	}
+
+	fn set_value(&mut self, key: Key, value: u16) {
+		match key {
+			Key::MilitaryTime => self.military_time = value != 0,
+			Key::DebugLogActive => self.debug_log_active = value != 0,
+			Key::Backlight => {
+				if let Some(backlight) = lcd_api::BacklightBrightness::from_level(value as u8) {
+					self.backlight = backlight;
+				}
+			},
+			Key::AlarmTime(index) => {
+				// Kept until the repeat key says whether the slot is used
+				if let Some(time) = clock::Time::new((value >> 8) as u8, value as u8, 0) {
+					self.alarms[index].get_or_insert(alarm::Alarm::new(time, alarm::REPEAT_ONCE, &[])).time = time;
+				}
+			},
+			Key::AlarmRepeat(index) => {
+				let slot = &mut self.alarms[index];
+				if value & ALARM_PRESENT == 0 {
+					*slot = None;
+				}
+				else {
+					let mut saved = slot.unwrap_or(alarm::Alarm::new(clock::Time::MIDNIGHT, alarm::REPEAT_ONCE, &[]));
+					saved.repeat = value as u8 & alarm::REPEAT_DAILY;
+					saved.enabled = value & ALARM_ENABLED != 0;
+					*slot = Some(saved);
+				}
+			},
+			Key::AlarmLabel(index, pair) => {
+				if let Some(saved) = self.alarms[index].as_mut() {
+					saved.label[pair * 2..(pair * 2) + 2].copy_from_slice(&value.to_be_bytes());
+				}
+			}
+		}
+	}
+}
+
+// Reads the newest record into DeviceInfo. Needs calling once at start up,
+// before the first page is shown.
+pub fn load(d: &mut info::DeviceInfo) {
+	let mut settings = Settings::from_device_info(d);
+	let mut newest: Option<(u32, u32, u32)> = None;	// (sequence, address, next address)
+
+	let mut page = REGION_START;
+	while page < REGION_END {
+		let page_end = page + nvmc::PAGE_SIZE;
+		let mut address = page;
+
+		while let Some(entries) = get_record_entries(address, page_end) {
+			let next = address + ((3 + entries) * 4);
+			if get_record_valid(address, entries) {
+				let record_sequence = nvmc::read_word(address + 4);
+				if newest.is_none_or(|(s, _, _)| record_sequence >= s) {
+					newest = Some((record_sequence, address, next));
+				}
+			}
+			address = next;
+		}
+
+		page = page_end;
+	}
+
+	if let Some((_, address, _)) = newest {
+		let entries = nvmc::read_word(address) & 0xFFFF;
+		for i in 0..entries {
+			let entry = nvmc::read_word(address + ((2 + i) * 4));
+			if let Some(key) = KEYS.iter().find(|k| k.get_id() == entry >> 16) {
+				settings.set_value(*key, entry as u16);
+			}
+		}
+
+		d.flags.military_time = settings.military_time;
+		d.flags.debug_log_active = settings.debug_log_active;
+		d.backlight = settings.backlight;
+
+		for (index, saved) in settings.alarms.iter().enumerate() {
+			let _ = match saved {
+				Some(saved) => alarm::set(index, *saved),
+				None => alarm::remove(index)
+			};
+		}
+	}
+
+	free(|cs| {
+		let mut store = STORE.borrow(cs).borrow_mut();
+		store.saved = Some(settings);
+		if let Some((sequence, _, next)) = newest {
+			store.sequence = sequence;
+			store.next_address = next;
+		}
+	});
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn get_crc(words: &[u32]) -> u32 {
+	let mut crc = Crc32::new();
+	for word in words {
+		crc.update(&word.to_le_bytes());
+	}
+	crc.get_value()
+}
+
+// Every key, alarm times before repeats before labels
+const fn get_keys() -> [Key; KEY_COUNT] {
+	let mut keys = [Key::MilitaryTime; KEY_COUNT];
+	keys[1] = Key::DebugLogActive;
+	keys[2] = Key::Backlight;
+
+	let mut index = 0;
+	while index < alarm::ALARM_COUNT {
+		keys[3 + index] = Key::AlarmTime(index);
+		keys[3 + alarm::ALARM_COUNT + index] = Key::AlarmRepeat(index);
+
+		let mut pair = 0;
+		while pair < LABEL_KEYS {
+			keys[3 + (alarm::ALARM_COUNT * 2) + (index * LABEL_KEYS) + pair] = Key::AlarmLabel(index, pair);
+			pair += 1;
+		}
+		index += 1;
+	}
+	keys
+}
+
+// Entry count of the record starting at `address`, None where records end
+fn get_record_entries(address: u32, page_end: u32) -> Option<u32> {
+	if address + (3 * 4) > page_end {
+		return None;
+	}
+
+	let header = nvmc::read_word(address);
+	let entries = header & 0xFFFF;
+	if (header >> 16) != MAGIC || entries > MAX_ENTRIES || address + ((3 + entries) * 4) > page_end {
+		return None;
+	}
+	Some(entries)
+}
+
+fn get_record_valid(address: u32, entries: u32) -> bool {
+	let mut words = [0; 2 + MAX_ENTRIES as usize];
+	let words = &mut words[..(2 + entries) as usize];
+	for (i, word) in words.iter_mut().enumerate() {
+		*word = nvmc::read_word(address + (i as u32 * 4));
+	}
+
+	get_crc(words) == nvmc::read_word(address + ((2 + entries) * 4))
+}
+
+fn get_erased(address: u32, words: u32) -> bool {
+	(0..words).all(|i| nvmc::read_word(address + (i * 4)) == nvmc::ERASED_WORD)
+}
+
+fn save(settings: &Settings) -> Result<(), nvmc::NvmcError> {
+	let (mut address, sequence) = free(|cs| {
+		let store = STORE.borrow(cs).borrow();
+		(store.next_address, store.sequence.wrapping_add(1))
+	});
+
+	// Move to the start of the next page when the record will not fit, or when
+	// a write was cut short here. That page is erased first, the one holding
+	// the newest record is left alone until the new record is in.
+	let page_end = (address - (address % nvmc::PAGE_SIZE)) + nvmc::PAGE_SIZE;
+	if address + (RECORD_WORDS * 4) > page_end || !get_erased(address, RECORD_WORDS) {
+		address = if page_end >= REGION_END { REGION_START } else { page_end };
+		nvmc::erase_page(address)?;
+	}
+
+	let mut record = [0; RECORD_WORDS as usize];
+	record[0] = (MAGIC << 16) | KEYS.len() as u32;
+	record[1] = sequence;
+	for (i, key) in KEYS.iter().enumerate() {
+		record[2 + i] = (key.get_id() << 16) | settings.get_value(*key) as u32;
+	}
+	record[RECORD_WORDS as usize - 1] = get_crc(&record[..RECORD_WORDS as usize - 1]);
+
+	nvmc::write_words(address, &record)?;
+
+	free(|cs| {
+		let mut store = STORE.borrow(cs).borrow_mut();
+		store.saved = Some(*settings);
+		store.sequence = sequence;
+		store.next_address = address + (RECORD_WORDS * 4);
+	});
+	Ok(())
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler(d: &mut info::DeviceInfo) {
+	let settings = Settings::from_device_info(d);
+	let (saved, timer) = free(|cs| {
+		let store = STORE.borrow(cs).borrow();
+		(store.saved, store.timer)
+	});
+
+	// Not loaded yet
+	let saved = match saved {
+		Some(saved) => saved,
+		None => return
+	};
+
+	match timer {
+		Some(id) => {
+			if soft_timer::take_expired(id) > 0 {
+				free(|cs| STORE.borrow(cs).borrow_mut().timer = None);
+				if settings != saved {
+					// A failed write is tried again after another delay
+					let _ = save(&settings);
+				}
+			}
+		},
+		None => {
+			if settings != saved {
+				let id = soft_timer::start(soft_timer::TimerConfig {
+					mode: soft_timer::TimerMode::OneShot,
+					interval: rtc::Duration::from_millis(config::SETTINGS_SAVE_DELAY_MS),
+					callback: None,
+					real_time_callback: false
+				}).ok();
+				free(|cs| STORE.borrow(cs).borrow_mut().timer = id);
+			}
+		}
+	}
+}
@@ -0,0 +1,228 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// drivers::timezone.rs
+// UTC offsets and daylight saving rules. The clock keeps UTC and converts with
+// these when it shows the time. Like drivers::calendar this only uses core.
+//
+// EU:	last Sunday of March to last Sunday of October, switching at 01:00 UTC
+// US:	second Sunday of March to first Sunday of November, switching at 02:00
+//		local time
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use super::calendar::{self, Weekday};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DstRule {
+	None,
+	Eu,
+	Us
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeZone {
+	pub name: &'static str,
+	pub offset_minutes: i16,
+	pub dst: DstRule
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const DST_SECONDS: i64 = 3600;
+
+#[allow(dead_code)] pub const UTC: TimeZone = TimeZone { name: "UTC", offset_minutes: 0, dst: DstRule::None };
+#[allow(dead_code)] pub const LONDON: TimeZone = TimeZone { name: "LON", offset_minutes: 0, dst: DstRule::Eu };
+#[allow(dead_code)] pub const PARIS: TimeZone = TimeZone { name: "PAR", offset_minutes: 60, dst: DstRule::Eu };
+#[allow(dead_code)] pub const HELSINKI: TimeZone = TimeZone { name: "HEL", offset_minutes: 120, dst: DstRule::Eu };
+#[allow(dead_code)] pub const NEW_YORK: TimeZone = TimeZone { name: "NYC", offset_minutes: -300, dst: DstRule::Us };
+#[allow(dead_code)] pub const CHICAGO: TimeZone = TimeZone { name: "CHI", offset_minutes: -360, dst: DstRule::Us };
+#[allow(dead_code)] pub const DENVER: TimeZone = TimeZone { name: "DEN", offset_minutes: -420, dst: DstRule::Us };
+#[allow(dead_code)] pub const LOS_ANGELES: TimeZone = TimeZone { name: "LAX", offset_minutes: -480, dst: DstRule::Us };
+#[allow(dead_code)] pub const KOLKATA: TimeZone = TimeZone { name: "IST", offset_minutes: 330, dst: DstRule::None };
+#[allow(dead_code)] pub const TOKYO: TimeZone = TimeZone { name: "TYO", offset_minutes: 540, dst: DstRule::None };
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl TimeZone {
+	// Seconds to add to UTC for local time, daylight saving included
+	pub fn get_offset(&self, utc: u64) -> i64 {
+		let standard = self.offset_minutes as i64 * 60;
+		if self.get_in_dst(utc) { standard + DST_SECONDS } else { standard }
+	}
+
+	pub fn get_in_dst(&self, utc: u64) -> bool {
+		let standard = self.offset_minutes as i64 * 60;
+		let year = calendar::from_unix_time(add_offset(utc, standard)).0.year;
+
+		let (start, end) = match self.dst {
+			DstRule::None => return false,
+			DstRule::Eu => (
+				get_seconds(calendar::get_last_weekday(year, 3, Weekday::Sunday), 3600),
+				get_seconds(calendar::get_last_weekday(year, 10, Weekday::Sunday), 3600)
+			),
+			DstRule::Us => (
+				get_seconds(calendar::get_nth_weekday(year, 3, Weekday::Sunday, 2), 7200 - standard),
+				get_seconds(calendar::get_nth_weekday(year, 11, Weekday::Sunday, 1), 7200 - standard - DST_SECONDS)
+			)
+		};
+
+		utc >= start && utc < end
+	}
+
+	pub fn to_local(self, utc: u64) -> u64 {
+		add_offset(utc, self.get_offset(utc))
+	}
+
+	// A local time skipped by the spring change comes out an hour later, one
+	// repeated by the autumn change resolves to the first time round
+	pub fn to_utc(self, local: u64) -> u64 {
+		let standard = self.offset_minutes as i64 * 60;
+		let utc = add_offset(local, -standard - DST_SECONDS);
+		if self.get_in_dst(utc) {
+			utc
+		}
+		else {
+			add_offset(local, -standard)
+		}
+	}
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn add_offset(seconds: u64, offset: i64) -> u64 {
+	if offset < 0 {
+		seconds.saturating_sub(offset.unsigned_abs())
+	}
+	else {
+		seconds.saturating_add(offset as u64)
+	}
+}
+
+fn get_seconds(days: u32, offset: i64) -> u64 {
+	add_offset(days as u64 * calendar::SECONDS_PER_DAY, offset)
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+
+//==============================================================================
+// Tests
+//==============================================================================
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::drivers::calendar::{Date, Time};
+
+	fn unix_time(year: u16, month: u8, day: u8, hours: u8, minutes: u8) -> u64 {
+		calendar::to_unix_time(&Date::new(year, month, day).unwrap(), &Time::new(hours, minutes, 0).unwrap())
+	}
+
+	#[test]
+	fn eu_switches_at_one_utc() {
+		// Last Sundays of March and October, the same instant in every zone
+		for (year, march, october) in [(2024, 31, 27), (2025, 30, 26)] {
+			for zone in [LONDON, PARIS, HELSINKI] {
+				let standard = zone.offset_minutes as i64 * 60;
+
+				assert!(!zone.get_in_dst(unix_time(year, 3, march, 0, 59)), "{} {}", zone.name, year);
+				assert!(zone.get_in_dst(unix_time(year, 3, march, 1, 0)), "{} {}", zone.name, year);
+				assert!(zone.get_in_dst(unix_time(year, 10, october, 0, 59)), "{} {}", zone.name, year);
+				assert!(!zone.get_in_dst(unix_time(year, 10, october, 1, 0)), "{} {}", zone.name, year);
+
+				assert_eq!(zone.get_offset(unix_time(year, 1, 15, 12, 0)), standard);
+				assert_eq!(zone.get_offset(unix_time(year, 7, 15, 12, 0)), standard + 3600);
+			}
+		}
+
+		// Paris goes from 01:59 to 03:00 and from 02:59 back to 02:00
+		assert_eq!(PARIS.to_local(unix_time(2024, 3, 31, 0, 59)), unix_time(2024, 3, 31, 1, 59));
+		assert_eq!(PARIS.to_local(unix_time(2024, 3, 31, 1, 0)), unix_time(2024, 3, 31, 3, 0));
+		assert_eq!(PARIS.to_local(unix_time(2024, 10, 27, 0, 59)), unix_time(2024, 10, 27, 2, 59));
+		assert_eq!(PARIS.to_local(unix_time(2024, 10, 27, 1, 0)), unix_time(2024, 10, 27, 2, 0));
+	}
+
+	#[test]
+	fn us_switches_at_two_local() {
+		// Second Sunday of March and first Sunday of November
+		for (year, march, november) in [(2024, 10, 3), (2025, 9, 2)] {
+			// 02:00 standard time in spring, 02:00 daylight time in autumn
+			assert!(!NEW_YORK.get_in_dst(unix_time(year, 3, march, 6, 59)), "{}", year);
+			assert!(NEW_YORK.get_in_dst(unix_time(year, 3, march, 7, 0)), "{}", year);
+			assert!(NEW_YORK.get_in_dst(unix_time(year, 11, november, 5, 59)), "{}", year);
+			assert!(!NEW_YORK.get_in_dst(unix_time(year, 11, november, 6, 0)), "{}", year);
+
+			assert!(!LOS_ANGELES.get_in_dst(unix_time(year, 3, march, 9, 59)), "{}", year);
+			assert!(LOS_ANGELES.get_in_dst(unix_time(year, 3, march, 10, 0)), "{}", year);
+			assert!(LOS_ANGELES.get_in_dst(unix_time(year, 11, november, 8, 59)), "{}", year);
+			assert!(!LOS_ANGELES.get_in_dst(unix_time(year, 11, november, 9, 0)), "{}", year);
+		}
+
+		assert_eq!(NEW_YORK.get_offset(unix_time(2024, 1, 15, 12, 0)), -5 * 3600);
+		assert_eq!(NEW_YORK.get_offset(unix_time(2024, 7, 15, 12, 0)), -4 * 3600);
+
+		// New York goes from 01:59 to 03:00 and from 01:59 back to 01:00
+		assert_eq!(NEW_YORK.to_local(unix_time(2024, 3, 10, 6, 59)), unix_time(2024, 3, 10, 1, 59));
+		assert_eq!(NEW_YORK.to_local(unix_time(2024, 3, 10, 7, 0)), unix_time(2024, 3, 10, 3, 0));
+		assert_eq!(NEW_YORK.to_local(unix_time(2024, 11, 3, 5, 59)), unix_time(2024, 11, 3, 1, 59));
+		assert_eq!(NEW_YORK.to_local(unix_time(2024, 11, 3, 6, 0)), unix_time(2024, 11, 3, 1, 0));
+	}
+
+	#[test]
+	fn skipped_hour_comes_out_an_hour_later() {
+		assert_eq!(PARIS.to_utc(unix_time(2024, 3, 31, 2, 30)), unix_time(2024, 3, 31, 1, 30));
+		assert_eq!(PARIS.to_local(PARIS.to_utc(unix_time(2024, 3, 31, 2, 30))), unix_time(2024, 3, 31, 3, 30));
+
+		assert_eq!(NEW_YORK.to_utc(unix_time(2024, 3, 10, 2, 30)), unix_time(2024, 3, 10, 7, 30));
+		assert_eq!(NEW_YORK.to_local(NEW_YORK.to_utc(unix_time(2024, 3, 10, 2, 30))), unix_time(2024, 3, 10, 3, 30));
+
+		// Either side of the gap is left alone
+		assert_eq!(NEW_YORK.to_utc(unix_time(2024, 3, 10, 1, 59)), unix_time(2024, 3, 10, 6, 59));
+		assert_eq!(NEW_YORK.to_utc(unix_time(2024, 3, 10, 3, 0)), unix_time(2024, 3, 10, 7, 0));
+	}
+
+	#[test]
+	fn repeated_hour_is_the_first_time_round() {
+		assert_eq!(PARIS.to_utc(unix_time(2024, 10, 27, 2, 30)), unix_time(2024, 10, 27, 0, 30));
+		assert_eq!(NEW_YORK.to_utc(unix_time(2024, 11, 3, 1, 30)), unix_time(2024, 11, 3, 5, 30));
+
+		// Both times round show the same local time
+		assert_eq!(NEW_YORK.to_local(unix_time(2024, 11, 3, 5, 30)), unix_time(2024, 11, 3, 1, 30));
+		assert_eq!(NEW_YORK.to_local(unix_time(2024, 11, 3, 6, 30)), unix_time(2024, 11, 3, 1, 30));
+
+		// After the repeated hour standard time is back
+		assert_eq!(NEW_YORK.to_utc(unix_time(2024, 11, 3, 2, 0)), unix_time(2024, 11, 3, 7, 0));
+	}
+
+	#[test]
+	fn fixed_offsets_have_no_dst() {
+		for (zone, offset) in [(UTC, 0), (KOLKATA, 19800), (TOKYO, 32400)] {
+			for month in 1..=12 {
+				for day in [1, 10, 31] {
+					let Some(date) = Date::new(2024, month, day) else { continue };
+					let utc = calendar::to_unix_time(&date, &Time::new(1, 30, 0).unwrap());
+
+					assert!(!zone.get_in_dst(utc), "{} {}/{}", zone.name, day, month);
+					assert_eq!(zone.get_offset(utc), offset);
+					assert_eq!(zone.to_local(utc), utc + offset as u64);
+					assert_eq!(zone.to_utc(zone.to_local(utc)), utc);
+				}
+			}
+		}
+	}
+}
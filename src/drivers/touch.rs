@@ -157,8 +157,8 @@ fn touch_handler() {
 	unsafe { 
 		// Attempt read touch event
 		let res = i2c::write_then_read(config::TOUCH_I2C_ADDRESS, &[0x00],  &mut LAST_EVENT_BUFFER);
-		if let Err(_e) = res {
-			log::push_log("Failed to read touch sensor");
+		if let Err(e) = res {
+			log::push_log(i2c::get_error_string(e));
 		}
 		else{
 			UNHANDLED_EVENTS = true;
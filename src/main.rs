@@ -37,6 +37,7 @@ fn main() -> ! {
 	init();
 	
 	let mut device_info = info::DeviceInfo::take().unwrap();
+	drivers::settings::load(&mut device_info);
 
 	loop {
 		task_handler(&mut device_info);
@@ -47,7 +48,7 @@ fn main() -> ! {
 // Private Functions
 //==============================================================================
 fn init() {
-	mcu::init(mcu::rtc::WakeInterval::Interval250MS);
+	mcu::init();
 	drivers::init();
 }
 
@@ -30,6 +30,10 @@ pub struct AdcLine {
 static ADC_HANDLE: Mutex<RefCell<Option<nrf52832_pac::SAADC>>> = 
 	Mutex::new(RefCell::new(None));
 
+// SAADC results are written here by EasyDMA
+#[link_section = ".dma"]
+static mut ADC_RAM_BUFFER: [i16; config::ADC_RAM_BUFFER_LEN] = [0; config::ADC_RAM_BUFFER_LEN];
+
 const ADC_LINE: AdcLine = AdcLine {
 	pin: config::BATTERY_ADC_PIN,
 	channel: config::BATTERY_ADC_CHANNEL,
@@ -63,9 +67,11 @@ pub fn read_adc() -> u16 {
 				.burst().disabled()
 			);
 
+			let buffer = ptr::addr_of_mut!(ADC_RAM_BUFFER) as *mut i16;
+
 			for s in 0..config::ADC_RAM_BUFFER_LEN {
 				// Define the result pointer and number of tranfers
-				adc.result.ptr.write(|w| unsafe { w.bits(config::ADC_RAM_BUFFER + (s * 2) as u32)});
+				adc.result.ptr.write(|w| unsafe { w.bits(buffer.add(s) as u32) });
 				adc.result.maxcnt.write(|w| unsafe { w.maxcnt().bits(1) });
 
 				// Clear sampling flags
@@ -83,8 +89,8 @@ pub fn read_adc() -> u16 {
 
 			let mut sample: u32 = 0;
 			for s in 0..config::ADC_RAM_BUFFER_LEN {
-				let address: *const u16 = (config::ADC_RAM_BUFFER as u32 + (s as u32 * 2)) as *const u16;
-				sample = sample + unsafe { *ptr::read(ptr::addr_of!(address)) as u32 };
+				// Written behind the compiler's back, always read it from RAM
+				sample = sample + unsafe { ptr::read_volatile(buffer.add(s)) as u16 as u32 };
 			}
 			(sample / config::ADC_RAM_BUFFER_LEN as u32) as u16
 		}
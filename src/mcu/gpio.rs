@@ -8,8 +8,10 @@
 // Crates and Mods
 //==============================================================================
 use core::cell::RefCell;
+use core::convert::Infallible;
 use core::ops::DerefMut;
 use cortex_m::interrupt::{free, Mutex};
+use embedded_hal::digital::v2::{InputPin, OutputPin};
 use nrf52832_pac;
 
 use nrf52832_pac::p0::pin_cnf::DIR_A as DIR;
@@ -33,6 +35,13 @@ pub enum PinPull{
 	PullDisabled
 }
 
+// embedded-hal handle for a single P0 pin, configure it with pin_setup first
+#[allow(dead_code)]
+#[derive(Copy, Clone)]
+pub struct Pin {
+	pin: u8
+}
+
 #[allow(dead_code)]
 #[derive(Copy, Clone, PartialEq)]
 pub enum PinState{
@@ -49,6 +58,39 @@ static GPIO_HANDLE: Mutex<RefCell<Option<nrf52832_pac::P0>>> =
 //==============================================================================
 // Public Functions
 //==============================================================================
+#[allow(dead_code)]
+impl Pin {
+	pub fn new(pin: u8) -> Self {
+		Pin { pin }
+	}
+}
+
+impl InputPin for Pin {
+	type Error = Infallible;
+
+	fn is_high(&self) -> Result<bool, Infallible> {
+		Ok(get_pin_state(self.pin) == PinState::PinHigh)
+	}
+
+	fn is_low(&self) -> Result<bool, Infallible> {
+		Ok(get_pin_state(self.pin) == PinState::PinLow)
+	}
+}
+
+impl OutputPin for Pin {
+	type Error = Infallible;
+
+	fn set_high(&mut self) -> Result<(), Infallible> {
+		set_pin_state(self.pin, PinState::PinHigh);
+		Ok(())
+	}
+
+	fn set_low(&mut self) -> Result<(), Infallible> {
+		set_pin_state(self.pin, PinState::PinLow);
+		Ok(())
+	}
+}
+
 pub fn init(p0: nrf52832_pac::P0) {
 	free(|cs| GPIO_HANDLE.borrow(cs).replace(Some(p0)));
 }
@@ -2,16 +2,27 @@
 // Notes
 //==============================================================================
 // mcu::i2c.rs
+// Every wait on a TWI event is bounded by I2C_TIMEOUT_MS, measured on the RTC
+// ticks which keep counting with interrupts masked. A transaction that times
+// out is assumed to have a slave stuck holding SDA low: SCL is clocked by hand
+// until it lets go and the bus is handed back to the TWI peripheral.
+//
+// A transaction takes the TWI out of I2C_HANDLE and runs with interrupts
+// enabled, so a stuck slave delays only the caller. While it is out, any other
+// transaction, say from an interrupt handler, fails with I2cError::Busy.
 
 //==============================================================================
 // Crates and Mods
 //==============================================================================
-use core::cell::RefCell;
-use core::ops::DerefMut;
+use core::cell::{Cell, RefCell};
+use cortex_m::asm;
 use cortex_m::interrupt::{free, Mutex};
 use crate::config;
+use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
 use nrf52832_pac;
-use super::gpio;
+use nrf52832_pac::p0::pin_cnf::DIR_A as DIR;
+use nrf52832_pac::p0::pin_cnf::PULL_A as PULL;
+use super::{gpio, rtc};
 
 //==============================================================================
 // Enums, Structs, and Types
@@ -23,17 +34,35 @@ pub struct I2cLine {
 	pub frequency: nrf52832_pac::twi0::frequency::FREQUENCY_A,
 }
 
+// embedded-hal handle for the shared TWI1 bus
+#[allow(dead_code)]
+pub struct I2c;
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
 pub enum I2cError {
-	HANDLER,
-	RECEIVE,
-	STOP,
-	TRANSMIT
+	AddressNack,
+	Busy,
+	DataNack,
+	Handler,
+	Overrun,
+	Receive,
+	Stop,
+	Timeout,
+	Transmit
+}
+
+struct Timeout {
+	deadline: rtc::Instant
 }
+
 //==============================================================================
 // Variables
 //==============================================================================
-static I2C_HANDLE: Mutex<RefCell<Option<nrf52832_pac::TWI1>>> = 
+static I2C_HANDLE: Mutex<RefCell<Option<nrf52832_pac::TWI1>>> =
 	Mutex::new(RefCell::new(None));
+// Set while a transaction has the TWI out of I2C_HANDLE
+static I2C_BUSY: Mutex<Cell<bool>> = Mutex::new(Cell::new(false));
 
 const I2C_LINE: I2cLine = I2cLine {
 	scl_pin: config::I2C_SCL_PIN,
@@ -41,154 +70,117 @@ const I2C_LINE: I2cLine = I2cLine {
 	frequency: config::I2C_FREQUENCY,
 };
 
+// A slave can be at most 8 bits into a byte, 9 clocks always frees it
+const RECOVERY_CLOCKS: u8 = 9;
+// Half of a 100kHz SCL period at 64MHz
+const RECOVERY_HALF_PERIOD_CYCLES: u32 = 320;
+
 //==============================================================================
 // Public Functions
 //==============================================================================
 pub fn init(i2c: nrf52832_pac::TWI1){
+	// Free the bus in case a slave was left mid-transfer by a reset
+	if let gpio::PinState::PinLow = gpio::get_pin_state(I2C_LINE.sda_pin) {
+		recover(&i2c);
+	}
+
 	configure(&i2c);
 
 	free(|cs| I2C_HANDLE.borrow(cs).replace(Some(i2c)));
 }
 
+pub fn get_error_string(error: I2cError) -> &'static str {
+	match error {
+		I2cError::AddressNack => 	"i2c: address nack",
+		I2cError::Busy => 			"i2c: busy",
+		I2cError::DataNack => 		"i2c: data nack",
+		I2cError::Handler => 		"i2c: not initialized",
+		I2cError::Overrun => 		"i2c: rx overrun",
+		I2cError::Receive => 		"i2c: receive failed",
+		I2cError::Stop => 			"i2c: stop failed",
+		I2cError::Timeout => 		"i2c: timeout, bus reset",
+		I2cError::Transmit => 		"i2c: transmit failed",
+	}
+}
+
 #[allow(dead_code)]
 pub fn read(address: u8, buffer: &mut [u8]) -> Result<(), I2cError> {
-	free(|cs| {
-		if let Some(ref mut i2c) = I2C_HANDLE.borrow(cs).borrow_mut().deref_mut() {
-			// Disable all shorcuts
-			i2c.shorts.write(|w| w
-				.bb_stop().disabled()
-				.bb_suspend().disabled()
-			);
-
-			// Make sure the right address is being used
-			i2c.address.write(|w| unsafe { w.address().bits(address.into()) });
-			
-			// Split the transaction into the bulk of the read and the last byte to be read
-			if let Some((last, data)) = buffer.split_last_mut() {
-				if !data.is_empty() {
-					i2c.shorts.write(|w| w.bb_suspend().enabled());
-				}
-				else{
-					i2c.shorts.write(|w| w.bb_stop().enabled());
-				}
-
-				// Clear flag showing new data in register
-				i2c.events_rxdready.write(|w| unsafe { w.bits(0) });
-
-				// Start the first Rx task
-				i2c.tasks_startrx.write(|w| unsafe { w.bits(1) } );
-
-				for byte in &mut data.into_iter() {
-					i2c.tasks_resume.write(|w| unsafe { w.bits(1) });
-					*byte = rx_byte(i2c)?;
-				}
-				
-				i2c.shorts.write(|w| w.bb_stop().enabled());
-				i2c.tasks_resume.write(|w| unsafe { w.bits(1) });
-				*last = rx_byte(i2c)?;
-			}
-			else{
-				send_stop(i2c)?;
-			}
-			
-			Ok(())
-		}
-		else {
-			Err(I2cError::HANDLER)
-		}
+	transaction(address, |i2c, timeout| {
+		receive(i2c, timeout, buffer)
 	})
 }
 
 #[allow(dead_code)]
 pub fn write(address: u8, data: &[u8]) -> Result<(), I2cError> {
-	free(|cs| {
-		if let Some(ref mut i2c) = I2C_HANDLE.borrow(cs).borrow_mut().deref_mut() {
-			// Disable all shorcuts
-			i2c.shorts.write(|w| w
-				.bb_stop().disabled()
-				.bb_suspend().disabled()
-			);
-
-			// Make sure the right address is being used
-			i2c.address.write(|w| unsafe { w.address().bits(address.into()) });
-
-			// Start the transaction
-			i2c.tasks_starttx.write(|w| unsafe { w.bits(1) });
-
-			for byte in data.into_iter() {
-				tx_byte(i2c, *byte)?;
-			}
-			
-			send_stop(i2c)?;
-			Ok(())
-		}
-		else {
-			Err(I2cError::HANDLER)
+	transaction(address, |i2c, timeout| {
+		// Start the transaction
+		i2c.tasks_starttx.write(|w| unsafe { w.bits(1) });
+
+		for byte in data.iter() {
+			tx_byte(i2c, timeout, *byte)?;
 		}
+
+		send_stop(i2c, timeout)
 	})
 }
 
 pub fn write_then_read(address: u8, tx_buffer: &[u8], rx_buffer: &mut [u8]) -> Result<(), I2cError> {
-	free(|cs| {
-		if let Some(ref mut i2c) = I2C_HANDLE.borrow(cs).borrow_mut().deref_mut() {
-			// Disable all shorcuts
-			i2c.shorts.write(|w| w
-				.bb_stop().disabled()
-				.bb_suspend().disabled()
-			);
-
-			// Make sure the right address is being used
-			i2c.address.write(|w| unsafe { w.address().bits(address.into()) });
-
-			// Start the transaction
-			i2c.tasks_starttx.write(|w| unsafe { w.bits(1) });
-
-			// Send out all tx buffer contents
-			for byte in tx_buffer {
-				tx_byte(i2c, *byte)?;
-			}
-
-			if let Some((last, data)) = rx_buffer.split_last_mut() {
-				if !data.is_empty() {
-					i2c.shorts.write(|w| w.bb_suspend().enabled());
-				}
-				else{
-					i2c.shorts.write(|w| w.bb_stop().enabled());
-				}
-
-				// Clear flag showing new data in register
-				i2c.events_rxdready.write(|w| unsafe { w.bits(0) });
-
-				// Start the first Rx task
-				i2c.tasks_startrx.write(|w| unsafe { w.bits(1) } );
-
-				for byte in &mut data.into_iter() {
-					i2c.tasks_resume.write(|w| unsafe { w.bits(1) });
-					*byte = rx_byte(i2c)?;
-				}
-				
-				i2c.shorts.write(|w| w.bb_stop().enabled());
-				i2c.tasks_resume.write(|w| unsafe { w.bits(1) });
-				*last = rx_byte(i2c)?;
-			}
-			else{
-				send_stop(i2c)?;
-			}
-
-			Ok(())
+	transaction(address, |i2c, timeout| {
+		// Start the transaction
+		i2c.tasks_starttx.write(|w| unsafe { w.bits(1) });
+
+		// Send out all tx buffer contents
+		for byte in tx_buffer {
+			tx_byte(i2c, timeout, *byte)?;
 		}
-			else {
-				Err(I2cError::HANDLER)
-			}
-		})
+
+		// Repeated start into the read
+		receive(i2c, timeout, rx_buffer)
+	})
+}
+
+impl Read for I2c {
+	type Error = I2cError;
+
+	fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), I2cError> {
+		read(address, buffer)
+	}
+}
+
+impl Write for I2c {
+	type Error = I2cError;
+
+	fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), I2cError> {
+		write(address, bytes)
+	}
+}
+
+impl WriteRead for I2c {
+	type Error = I2cError;
+
+	fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), I2cError> {
+		write_then_read(address, bytes, buffer)
+	}
 }
 
 //==============================================================================
 // Private Functions
 //==============================================================================
+impl Timeout {
+	fn new(milliseconds: u32) -> Self {
+		Timeout {
+			deadline: rtc::Instant::after(rtc::Duration::from_millis(milliseconds as u64))
+		}
+	}
+
+	fn is_expired(&self) -> bool {
+		self.deadline.has_passed()
+	}
+}
+
 fn configure(i2c: &nrf52832_pac::TWI1) {
 	i2c.enable.write(|w| w.enable().disabled());
-	
+
 	gpio::pin_setup(
 		I2C_LINE.scl_pin,
 		nrf52832_pac::p0::pin_cnf::DIR_A::INPUT,
@@ -204,53 +196,122 @@ fn configure(i2c: &nrf52832_pac::TWI1) {
 	i2c.pselsda.write(|w| unsafe { w.bits( I2C_LINE.sda_pin as u32) });
 
 	i2c.frequency.write(|w| w.frequency().variant( I2C_LINE.frequency));
-	
+
 	i2c.enable.write(|w| w.enable().enabled());
 }
 
-fn rx_byte(i2c: &nrf52832_pac::TWI1) -> Result<u8, I2cError> {
-	// Wait for rx event or error out
-	while (i2c.events_rxdready.read().bits() == 0) && 
-		(i2c.events_error.read().bits() == 0) {}
-	
-	// If error, bail out
-	if i2c.events_error.read().bits() > 0 {
-		i2c.events_error.write(|w| unsafe { w.bits(0) });
-		return Err(I2cError::RECEIVE);
+fn get_error(i2c: &nrf52832_pac::TWI1, fallback: I2cError) -> I2cError {
+	let source = i2c.errorsrc.read();
+
+	// Error sources are cleared by writing a 1 back
+	i2c.errorsrc.write(|w| unsafe { w.bits(source.bits()) });
+	i2c.events_error.write(|w| unsafe { w.bits(0) });
+
+	if source.anack().is_present() {
+		I2cError::AddressNack
 	}
-	
-	let byte = i2c.rxd.read().rxd().bits();
+	else if source.dnack().is_present() {
+		I2cError::DataNack
+	}
+	else if source.overrun().is_present() {
+		I2cError::Overrun
+	}
+	else {
+		fallback
+	}
+}
 
-	// Clear out the Rx event flag
-	i2c.events_rxdready.write(|w| unsafe { w.bits(0) });
-	
-	Ok(byte)
+fn half_clock_delay() {
+	asm::delay(RECOVERY_HALF_PERIOD_CYCLES);
+}
+
+fn receive(i2c: &nrf52832_pac::TWI1, timeout: &mut Timeout, buffer: &mut [u8]) -> Result<(), I2cError> {
+	// Split the transaction into the bulk of the read and the last byte to be read
+	if let Some((last, data)) = buffer.split_last_mut() {
+		if !data.is_empty() {
+			i2c.shorts.write(|w| w.bb_suspend().enabled());
+		}
+		else{
+			i2c.shorts.write(|w| w.bb_stop().enabled());
+		}
+
+		// Clear flag showing new data in register
+		i2c.events_rxdready.write(|w| unsafe { w.bits(0) });
+
+		// Start the first Rx task
+		i2c.tasks_startrx.write(|w| unsafe { w.bits(1) } );
+
+		for byte in data.iter_mut() {
+			i2c.tasks_resume.write(|w| unsafe { w.bits(1) });
+			*byte = rx_byte(i2c, timeout)?;
+		}
+
+		i2c.shorts.write(|w| w.bb_stop().enabled());
+		i2c.tasks_resume.write(|w| unsafe { w.bits(1) });
+		*last = rx_byte(i2c, timeout)?;
+
+		Ok(())
+	}
+	else{
+		send_stop(i2c, timeout)
+	}
 }
 
-fn tx_byte(i2c: &nrf52832_pac::TWI1, byte: u8) -> Result<(), I2cError> {
+fn recover(i2c: &nrf52832_pac::TWI1) {
+	// Take the pins back from the TWI to drive them by hand
+	i2c.tasks_stop.write(|w| unsafe { w.bits(1) });
+	i2c.enable.write(|w| w.enable().disabled());
+
+	gpio::pin_setup(I2C_LINE.scl_pin, DIR::OUTPUT, gpio::PinState::PinHigh, PULL::DISABLED);
+	gpio::pin_setup(I2C_LINE.sda_pin, DIR::INPUT, gpio::PinState::PinHigh, PULL::DISABLED);
+
+	// Clock SCL until the slave finishes its byte and releases SDA
+	for _ in 0..RECOVERY_CLOCKS {
+		if let gpio::PinState::PinHigh = gpio::get_pin_state(I2C_LINE.sda_pin) {
+			break;
+		}
+
+		gpio::set_pin_state(I2C_LINE.scl_pin, gpio::PinState::PinLow);
+		half_clock_delay();
+		gpio::set_pin_state(I2C_LINE.scl_pin, gpio::PinState::PinHigh);
+		half_clock_delay();
+	}
+
+	// Finish with a stop condition: SDA rising while SCL is high
+	gpio::set_pin_state(I2C_LINE.scl_pin, gpio::PinState::PinLow);
+	gpio::pin_setup(I2C_LINE.sda_pin, DIR::OUTPUT, gpio::PinState::PinLow, PULL::DISABLED);
+	half_clock_delay();
+	gpio::set_pin_state(I2C_LINE.scl_pin, gpio::PinState::PinHigh);
+	half_clock_delay();
+	gpio::set_pin_state(I2C_LINE.sda_pin, gpio::PinState::PinHigh);
+	half_clock_delay();
+
+	configure(i2c);
+}
+
+fn rx_byte(i2c: &nrf52832_pac::TWI1, timeout: &mut Timeout) -> Result<u8, I2cError> {
 	// Wait for rx event or error out
-	i2c.events_txdsent.write(|w| unsafe { w.bits(0) });
+	while (i2c.events_rxdready.read().bits() == 0) &&
+		(i2c.events_error.read().bits() == 0) {
+		if timeout.is_expired() {
+			return Err(I2cError::Timeout);
+		}
+	}
 
-	// Load in the byte to be sent
-	i2c.txd.write(|w| unsafe { w.bits(byte.into()) });
-	
-	// Wait until transmission is completed
-	while (i2c.events_txdsent.read().bits() == 0) && 
-		(i2c.events_error.read().bits() == 0) {}
-		
 	// If error, bail out
 	if i2c.events_error.read().bits() > 0 {
-		i2c.events_error.write(|w| unsafe { w.bits(0) });
-		return Err(I2cError::TRANSMIT);
+		return Err(get_error(i2c, I2cError::Receive));
 	}
-	
+
+	let byte = i2c.rxd.read().rxd().bits();
+
 	// Clear out the Rx event flag
-	i2c.events_txdsent.write(|w| unsafe { w.bits(0) });
-	
-	Ok(())
+	i2c.events_rxdready.write(|w| unsafe { w.bits(0) });
+
+	Ok(byte)
 }
 
-fn send_stop(i2c: &nrf52832_pac::TWI1) -> Result<(), I2cError> {
+fn send_stop(i2c: &nrf52832_pac::TWI1, timeout: &mut Timeout) -> Result<(), I2cError> {
 	// Clear stopped event.
 	i2c.events_stopped.write(|w| unsafe { w.bits(0) });
 
@@ -259,14 +320,85 @@ fn send_stop(i2c: &nrf52832_pac::TWI1) -> Result<(), I2cError> {
 
 	// Wait until stop was sent.
 	while i2c.events_stopped.read().bits() == 0 &&
-		(i2c.events_error.read().bits() == 0) {}
+		(i2c.events_error.read().bits() == 0) {
+		if timeout.is_expired() {
+			return Err(I2cError::Timeout);
+		}
+	}
 
 	// Bail out if we get an error instead.
 	if i2c.events_error.read().bits() != 0 {
-		i2c.events_error.write(|w| unsafe { w.bits(0) });
-		return Err(I2cError::STOP);
+		return Err(get_error(i2c, I2cError::Stop));
+	}
+
+	Ok(())
+}
+
+fn transaction<F>(address: u8, f: F) -> Result<(), I2cError>
+	where F: FnOnce(&nrf52832_pac::TWI1, &mut Timeout) -> Result<(), I2cError> {
+	// Interrupts are only masked to take the handle, not for the waits
+	let i2c = free(|cs| {
+		let i2c = I2C_HANDLE.borrow(cs).borrow_mut().take();
+		match i2c {
+			Some(i2c) => {
+				I2C_BUSY.borrow(cs).set(true);
+				Ok(i2c)
+			},
+			None if I2C_BUSY.borrow(cs).get() => Err(I2cError::Busy),
+			None => Err(I2cError::Handler)
+		}
+	})?;
+
+	// Disable all shorcuts
+	i2c.shorts.write(|w| w
+		.bb_stop().disabled()
+		.bb_suspend().disabled()
+	);
+
+	// Make sure the right address is being used
+	i2c.address.write(|w| unsafe { w.address().bits(address) });
+
+	let mut timeout = Timeout::new(config::I2C_TIMEOUT_MS);
+	let result = f(&i2c, &mut timeout);
+
+	match result {
+		// Nothing is moving on the bus, assume a slave is holding it
+		Err(I2cError::Timeout) => recover(&i2c),
+		// The TWI does not release the bus by itself after an error
+		Err(_) => i2c.tasks_stop.write(|w| unsafe { w.bits(1) }),
+		Ok(()) => (),
+	}
+
+	free(|cs| {
+		I2C_HANDLE.borrow(cs).replace(Some(i2c));
+		I2C_BUSY.borrow(cs).set(false);
+	});
+	result
+}
+
+fn tx_byte(i2c: &nrf52832_pac::TWI1, timeout: &mut Timeout, byte: u8) -> Result<(), I2cError> {
+	// Wait for rx event or error out
+	i2c.events_txdsent.write(|w| unsafe { w.bits(0) });
+
+	// Load in the byte to be sent
+	i2c.txd.write(|w| unsafe { w.bits(byte.into()) });
+
+	// Wait until transmission is completed
+	while (i2c.events_txdsent.read().bits() == 0) &&
+		(i2c.events_error.read().bits() == 0) {
+		if timeout.is_expired() {
+			return Err(I2cError::Timeout);
+		}
 	}
-	
+
+	// If error, bail out
+	if i2c.events_error.read().bits() > 0 {
+		return Err(get_error(i2c, I2cError::Transmit));
+	}
+
+	// Clear out the Rx event flag
+	i2c.events_txdsent.write(|w| unsafe { w.bits(0) });
+
 	Ok(())
 }
 
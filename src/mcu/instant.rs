@@ -0,0 +1,268 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// mcu::instant.rs
+// Monotonic time in RTC ticks (1/4096s), re-exported through `rtc`. Ticks are
+// kept in 64 bits so they never wrap; arithmetic comes in checked and
+// saturating forms and the operators saturate instead of panicking.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::ops::{Add, Sub};
+use super::rtc;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Instant {
+	ticks: u64
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Duration {
+	ticks: u64
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const MILLIS_PER_SECOND: u64 = 1000;
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+#[allow(dead_code)]
+impl Instant {
+	pub fn now() -> Self {
+		Instant { ticks: rtc::get_ticks() }
+	}
+
+	// Deadline `timeout` from now
+	pub fn after(timeout: Duration) -> Self {
+		Instant::now().saturating_add(timeout)
+	}
+
+	pub const fn from_ticks(ticks: u64) -> Self {
+		Instant { ticks }
+	}
+
+	pub const fn as_ticks(&self) -> u64 {
+		self.ticks
+	}
+
+	pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+		self.ticks.checked_add(duration.ticks).map(Instant::from_ticks)
+	}
+
+	pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+		self.ticks.checked_sub(duration.ticks).map(Instant::from_ticks)
+	}
+
+	pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+		self.ticks.checked_sub(earlier.ticks).map(Duration::from_ticks)
+	}
+
+	pub fn duration_since(&self, earlier: Instant) -> Duration {
+		Duration::from_ticks(self.ticks.saturating_sub(earlier.ticks))
+	}
+
+	pub fn elapsed(&self) -> Duration {
+		Instant::now().duration_since(*self)
+	}
+
+	pub fn has_passed(&self) -> bool {
+		Instant::now() >= *self
+	}
+
+	// Time left until this deadline, zero once it has passed
+	pub fn remaining(&self) -> Duration {
+		self.duration_since(Instant::now())
+	}
+
+	pub fn saturating_add(&self, duration: Duration) -> Instant {
+		Instant::from_ticks(self.ticks.saturating_add(duration.ticks))
+	}
+
+	pub fn saturating_sub(&self, duration: Duration) -> Instant {
+		Instant::from_ticks(self.ticks.saturating_sub(duration.ticks))
+	}
+}
+
+impl Add<Duration> for Instant {
+	type Output = Instant;
+
+	fn add(self, duration: Duration) -> Instant {
+		self.saturating_add(duration)
+	}
+}
+
+impl Sub<Duration> for Instant {
+	type Output = Instant;
+
+	fn sub(self, duration: Duration) -> Instant {
+		self.saturating_sub(duration)
+	}
+}
+
+impl Sub<Instant> for Instant {
+	type Output = Duration;
+
+	fn sub(self, earlier: Instant) -> Duration {
+		self.duration_since(earlier)
+	}
+}
+
+#[allow(dead_code)]
+impl Duration {
+	pub const ZERO: Duration = Duration { ticks: 0 };
+
+	pub const fn from_ticks(ticks: u64) -> Self {
+		Duration { ticks }
+	}
+
+	// Rounded up to the next tick so a wait is never shorter than asked for.
+	// Saturates at the longest Duration.
+	pub const fn from_millis(milliseconds: u64) -> Self {
+		match Duration::checked_from_millis(milliseconds) {
+			Some(duration) => duration,
+			None => Duration { ticks: u64::MAX }
+		}
+	}
+
+	pub const fn from_secs(seconds: u64) -> Self {
+		Duration { ticks: seconds.saturating_mul(rtc::TICKS_PER_SECOND) }
+	}
+
+	// None if the Duration would not fit
+	pub const fn checked_from_millis(milliseconds: u64) -> Option<Self> {
+		// Whole seconds and the rest apart, so only a result too large overflows
+		let part = ((milliseconds % MILLIS_PER_SECOND) * rtc::TICKS_PER_SECOND).div_ceil(MILLIS_PER_SECOND);
+		match (milliseconds / MILLIS_PER_SECOND).checked_mul(rtc::TICKS_PER_SECOND) {
+			Some(ticks) => match ticks.checked_add(part) {
+				Some(ticks) => Some(Duration { ticks }),
+				None => None
+			},
+			None => None
+		}
+	}
+
+	pub const fn checked_from_secs(seconds: u64) -> Option<Self> {
+		match seconds.checked_mul(rtc::TICKS_PER_SECOND) {
+			Some(ticks) => Some(Duration { ticks }),
+			None => None
+		}
+	}
+
+	pub const fn as_ticks(&self) -> u64 {
+		self.ticks
+	}
+
+	pub const fn as_millis(&self) -> u64 {
+		(self.as_secs() * MILLIS_PER_SECOND) + ((self.subsec_ticks() * MILLIS_PER_SECOND) / rtc::TICKS_PER_SECOND)
+	}
+
+	pub const fn as_secs(&self) -> u64 {
+		self.ticks / rtc::TICKS_PER_SECOND
+	}
+
+	// Ticks past the last whole second
+	pub const fn subsec_ticks(&self) -> u64 {
+		self.ticks % rtc::TICKS_PER_SECOND
+	}
+
+	pub fn checked_add(&self, other: Duration) -> Option<Duration> {
+		self.ticks.checked_add(other.ticks).map(Duration::from_ticks)
+	}
+
+	pub fn checked_sub(&self, other: Duration) -> Option<Duration> {
+		self.ticks.checked_sub(other.ticks).map(Duration::from_ticks)
+	}
+
+	pub fn checked_mul(&self, factor: u32) -> Option<Duration> {
+		self.ticks.checked_mul(factor as u64).map(Duration::from_ticks)
+	}
+
+	pub fn saturating_add(&self, other: Duration) -> Duration {
+		Duration::from_ticks(self.ticks.saturating_add(other.ticks))
+	}
+
+	pub fn saturating_sub(&self, other: Duration) -> Duration {
+		Duration::from_ticks(self.ticks.saturating_sub(other.ticks))
+	}
+
+	pub fn saturating_mul(&self, factor: u32) -> Duration {
+		Duration::from_ticks(self.ticks.saturating_mul(factor as u64))
+	}
+}
+
+impl Add<Duration> for Duration {
+	type Output = Duration;
+
+	fn add(self, other: Duration) -> Duration {
+		self.saturating_add(other)
+	}
+}
+
+impl Sub<Duration> for Duration {
+	type Output = Duration;
+
+	fn sub(self, other: Duration) -> Duration {
+		self.saturating_sub(other)
+	}
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+
+//==============================================================================
+// Tests
+//==============================================================================
+#[cfg(test)]
+mod tests {
+	use super::Duration;
+	use super::super::rtc;
+
+	#[test]
+	fn millis_round_up_to_a_tick() {
+		assert_eq!(Duration::from_millis(0).as_ticks(), 0);
+		assert_eq!(Duration::from_millis(1).as_ticks(), 5);
+		assert_eq!(Duration::from_millis(1000).as_ticks(), rtc::TICKS_PER_SECOND);
+		assert_eq!(Duration::from_millis(1500).as_ticks(), rtc::TICKS_PER_SECOND * 3 / 2);
+		assert_eq!(Duration::from_millis(1500).as_millis(), 1500);
+	}
+
+	#[test]
+	fn large_values_saturate() {
+		assert_eq!(Duration::from_millis(u64::MAX).as_ticks(), u64::MAX);
+		assert_eq!(Duration::from_secs(u64::MAX).as_ticks(), u64::MAX);
+		assert_eq!(Duration::from_ticks(u64::MAX).as_millis(), u64::MAX / rtc::TICKS_PER_SECOND * 1000 + 999);
+		assert_eq!(Duration::from_secs(1).saturating_mul(u32::MAX).as_secs(), u32::MAX as u64);
+		assert_eq!(Duration::from_ticks(u64::MAX) + Duration::from_ticks(1), Duration::from_ticks(u64::MAX));
+		assert_eq!(Duration::ZERO - Duration::from_ticks(1), Duration::ZERO);
+	}
+
+	#[test]
+	fn checked_values_overflow_to_none() {
+		let max_secs = u64::MAX / rtc::TICKS_PER_SECOND;
+		assert_eq!(Duration::checked_from_secs(max_secs), Some(Duration::from_ticks(max_secs * rtc::TICKS_PER_SECOND)));
+		assert_eq!(Duration::checked_from_secs(max_secs + 1), None);
+		assert_eq!(Duration::checked_from_millis(max_secs * 1000), Some(Duration::from_secs(max_secs)));
+		assert_eq!(Duration::checked_from_millis(u64::MAX), None);
+		assert_eq!(Duration::from_ticks(u64::MAX).checked_add(Duration::from_ticks(1)), None);
+		assert_eq!(Duration::ZERO.checked_sub(Duration::from_ticks(1)), None);
+		assert_eq!(Duration::from_ticks(u64::MAX).checked_mul(2), None);
+	}
+}
@@ -10,7 +10,10 @@ pub mod adc;
 pub mod gpio;
 pub mod i2c;
 pub mod input;
+mod instant;
+pub mod nvmc;
 pub mod rtc;
+pub mod soft_timer;
 pub mod spi;
 pub mod spim;
 pub mod timer;
@@ -39,7 +42,7 @@ pub enum McuState {
 //==============================================================================
 // Public Functions
 //==============================================================================
-pub fn init(wake_interval: rtc::WakeInterval) {
+pub fn init() {
 	let peripherals = nrf52832_pac::Peripherals::take().unwrap();
 	// let cortex = cortex_m::Peripherals::take().unwrap();
 
@@ -50,7 +53,8 @@ pub fn init(wake_interval: rtc::WakeInterval) {
 	gpio::init(peripherals.P0);
 	input::init(peripherals.GPIOTE);
 	i2c::init(peripherals.TWI1);
-	rtc::init(peripherals.RTC0, &peripherals.CLOCK, wake_interval);
+	nvmc::init(peripherals.NVMC);
+	rtc::init(peripherals.RTC0, &peripherals.CLOCK);
 	spi::init(peripherals.SPI0);
 	spim::init(peripherals.SPIM0);
 	timer::init(peripherals.TIMER0);
@@ -60,7 +64,10 @@ pub fn get_busy() -> McuState {
 	if adc::get_busy() {
 		return McuState::AdcBusy;
 	}
-	if timer::get_busy() {
+	if spim::get_busy() {
+		return McuState::SpiBusy;
+	}
+	if timer::get_busy() || soft_timer::get_busy() {
 		return McuState::TimerBusy;
 	}
 	McuState::Idle
@@ -80,4 +87,5 @@ pub fn restart() {
 //==============================================================================
 pub fn task_handler(_d: &info::DeviceInfo) {
 	input::task_handler();
+	soft_timer::task_handler();
 }
\ No newline at end of file
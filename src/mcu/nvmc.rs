@@ -0,0 +1,137 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// mcu::nvmc.rs
+// Internal flash erase and write. Only the pages set aside by
+// NVMC_SETTINGS_ADDRESS and NVMC_SETTINGS_PAGES in config.rs can be touched,
+// the linker keeps code out of them.
+//
+// The CPU stalls while the flash is busy, up to ~85ms for a page erase and
+// ~41us for a word write, so there is no need to wait on interrupts here.
+// A word can only be written once after its page has been erased: bits are
+// only ever cleared from 1 to 0.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use core::ptr;
+use cortex_m::interrupt::{free, Mutex};
+use nrf52832_pac;
+use crate::config;
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+pub enum NvmcError {
+	Alignment,
+	NotInitialized,
+	OutOfRange
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+pub const PAGE_SIZE: u32 = 4096;
+pub const ERASED_WORD: u32 = 0xFFFF_FFFF;
+
+const REGION_START: u32 = config::NVMC_SETTINGS_ADDRESS;
+const REGION_END: u32 = config::NVMC_SETTINGS_ADDRESS + (config::NVMC_SETTINGS_PAGES * PAGE_SIZE);
+
+static NVMC_HANDLE: Mutex<RefCell<Option<nrf52832_pac::NVMC>>> =
+	Mutex::new(RefCell::new(None));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn init(nvmc: nrf52832_pac::NVMC) {
+	nvmc.config.write(|w| w.wen().ren());
+
+	free(|cs| NVMC_HANDLE.borrow(cs).replace(Some(nvmc)));
+}
+
+#[allow(dead_code)]
+pub fn get_error_string(error: NvmcError) -> &'static str {
+	match error {
+		NvmcError::Alignment => 		"nvmc: address not aligned",
+		NvmcError::NotInitialized => 	"nvmc: not initialized",
+		NvmcError::OutOfRange => 		"nvmc: address outside of settings pages",
+	}
+}
+
+pub fn erase_page(address: u32) -> Result<(), NvmcError> {
+	if !address.is_multiple_of(PAGE_SIZE) {
+		return Err(NvmcError::Alignment);
+	}
+	check_range(address, PAGE_SIZE)?;
+
+	free(|cs| {
+		let handle = NVMC_HANDLE.borrow(cs).borrow();
+		let nvmc = handle.as_ref().ok_or(NvmcError::NotInitialized)?;
+
+		nvmc.config.write(|w| w.wen().een());
+		wait_ready(nvmc);
+		nvmc.erasepage().write(|w| unsafe { w.bits(address) });
+		wait_ready(nvmc);
+		nvmc.config.write(|w| w.wen().ren());
+
+		Ok(())
+	})
+}
+
+pub fn read_word(address: u32) -> u32 {
+	// Flash is memory mapped, volatile so a read after a write is not cached
+	unsafe { ptr::read_volatile(address as *const u32) }
+}
+
+#[allow(dead_code)]
+pub fn write_word(address: u32, value: u32) -> Result<(), NvmcError> {
+	write_words(address, &[value])
+}
+
+pub fn write_words(address: u32, values: &[u32]) -> Result<(), NvmcError> {
+	if !address.is_multiple_of(4) {
+		return Err(NvmcError::Alignment);
+	}
+	check_range(address, values.len() as u32 * 4)?;
+
+	free(|cs| {
+		let handle = NVMC_HANDLE.borrow(cs).borrow();
+		let nvmc = handle.as_ref().ok_or(NvmcError::NotInitialized)?;
+
+		nvmc.config.write(|w| w.wen().wen());
+		for (i, value) in values.iter().enumerate() {
+			wait_ready(nvmc);
+			unsafe { ptr::write_volatile((address as *mut u32).add(i), *value); }
+		}
+		wait_ready(nvmc);
+		nvmc.config.write(|w| w.wen().ren());
+
+		Ok(())
+	})
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn check_range(address: u32, len: u32) -> Result<(), NvmcError> {
+	match address.checked_add(len) {
+		Some(end) if address >= REGION_START && end <= REGION_END => Ok(()),
+		_ => Err(NvmcError::OutOfRange)
+	}
+}
+
+fn wait_ready(nvmc: &nrf52832_pac::NVMC) {
+	while nvmc.ready.read().ready().is_busy() {}
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
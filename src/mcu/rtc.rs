@@ -2,6 +2,11 @@
 // Notes
 //==============================================================================
 // mcu::rtc.rs
+// Tickless time keeping. The 24-bit COUNTER runs freely at 4096Hz and is
+// extended to 64 bits by counting OVRFLW events, so timestamps are read
+// straight from the hardware. CC0 is only armed for the next software timer
+// deadline; with nothing due the RTC only wakes the CPU to count an overflow,
+// once every ~68 minutes.
 
 //==============================================================================
 // Crates and Mods
@@ -11,35 +16,36 @@ use core::ops::DerefMut;
 use cortex_m::interrupt::{free, Mutex};
 use nrf52832_pac;
 use nrf52832_pac::interrupt;
+use super::soft_timer;
+
+pub use super::instant::{Duration, Instant};
 
 //==============================================================================
 // Enums, Structs, and Types
 //==============================================================================
-#[allow(dead_code)]
-pub enum WakeInterval {
-	Interval125MS	= 512,
-	Interval250MS	= 1024,
-	Interval500MS	= 2048,
-	Interval1S 		= 4096
-}
+
 
 //==============================================================================
 // Variables
 //==============================================================================
-static WAKE_INTERVAL: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
-static FRACTION: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
-static SECONDS: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
+pub const TICKS_PER_SECOND: u64 = 4096;
 
-static RTC_HANDLE: Mutex<RefCell<Option<nrf52832_pac::RTC0>>> = 
+const COUNTER_BITS: u32 = 24;
+const COUNTER_MASK: u64 = (1 << COUNTER_BITS) - 1;
+
+// The counter has to be at least this far behind a compare value for it to hit
+const COMPARE_MIN_DISTANCE: u64 = 2;
+
+static OVERFLOWS: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
+
+static RTC_HANDLE: Mutex<RefCell<Option<nrf52832_pac::RTC0>>> =
 	Mutex::new(RefCell::new(None));
 
 //==============================================================================
 // Public Functions
 //==============================================================================
 #[allow(dead_code)]
-pub fn init(rtc: nrf52832_pac::RTC0, clock: &nrf52832_pac::CLOCK, interval: WakeInterval) {
-	free(|cs| WAKE_INTERVAL.borrow(cs).set(interval as u32));
-
+pub fn init(rtc: nrf52832_pac::RTC0, clock: &nrf52832_pac::CLOCK) {
 	// Enable after HANDLE has been initialized so the mutex is not 'None'
 	configure(&rtc, clock);
 
@@ -47,28 +53,65 @@ pub fn init(rtc: nrf52832_pac::RTC0, clock: &nrf52832_pac::CLOCK, interval: Wake
 	free(|cs| RTC_HANDLE.borrow(cs).replace(Some(rtc)));
 }
 
+pub fn get_ticks() -> u64 {
+	// Safe with interrupts masked: an overflow that has not been counted yet
+	// is picked up from the pending event
+	free(|cs| {
+		if let Some(rtc) = RTC_HANDLE.borrow(cs).borrow().as_ref() {
+			let mut overflows = OVERFLOWS.borrow(cs).get() as u64;
+			let mut counter = rtc.counter.read().counter().bits();
+
+			if rtc.events_ovrflw.read().bits() != 0 {
+				// Read again, the first read may have been just before the wrap
+				counter = rtc.counter.read().counter().bits();
+				overflows += 1;
+			}
+
+			(overflows << COUNTER_BITS) | counter as u64
+		}
+		else {
+			0
+		}
+	})
+}
+
 #[allow(dead_code)]
 pub fn get_timestamp() -> u32 {
-	free(|cs| SECONDS.borrow(cs).get())
+	(get_ticks() / TICKS_PER_SECOND) as u32
 }
 
 #[allow(dead_code)]
 pub fn get_timediff(seconds: u32) -> u32 {
-	let app_seconds = free(|cs| SECONDS.borrow(cs).get());
-	app_seconds - seconds
+	// A timestamp from the future counts as no time at all
+	get_timestamp().saturating_sub(seconds)
 }
 
 #[allow(dead_code)]
 pub fn get_timestamp_fraction() -> u32 {
-	free(|cs| FRACTION.borrow(cs).get())
+	(get_ticks() % TICKS_PER_SECOND) as u32
 }
 
-#[allow(dead_code)]
-pub fn get_timediff_fraction(seconds: u32, fraction: u32) -> u32 {
-	let app_seconds = free(|cs| SECONDS.borrow(cs).get());
-	let app_fraction = free(|cs| FRACTION.borrow(cs).get());
-
-	((app_seconds * 1000) + app_fraction) - ((seconds * 1000) + fraction)
+pub fn set_deadline(deadline: Option<Instant>) {
+	free(|cs| {
+		if let Some(rtc) = RTC_HANDLE.borrow(cs).borrow().as_ref() {
+			let now = get_ticks();
+
+			match deadline.map(|d| d.as_ticks()) {
+				// CC0 only holds 24 bits, further deadlines are looked at again
+				// from the overflow interrupt
+				Some(deadline) if deadline < now + COUNTER_MASK - COMPARE_MIN_DISTANCE => {
+					let compare = deadline.max(now + COMPARE_MIN_DISTANCE) & COUNTER_MASK;
+
+					rtc.events_compare[0].write(|w| unsafe { w.bits(0) });
+					rtc.cc[0].write(|w| unsafe { w.bits(compare as u32) });
+					rtc.intenset.write(|w| w.compare0().set_bit());
+				},
+				_ => {
+					rtc.intenclr.write(|w| w.compare0().set_bit());
+				}
+			}
+		}
+	});
 }
 
 //==============================================================================
@@ -85,18 +128,15 @@ fn configure(rtc: &nrf52832_pac::RTC0, clock: &nrf52832_pac::CLOCK) {
 
 	//Disable RTC
 	rtc.tasks_stop.write(|w| unsafe { w.bits(1) });
-	
+	rtc.tasks_clear.write(|w| unsafe { w.bits(1) });
+
 	//prescale by 8 : 32768 / 8 = 4096 Hz
 	rtc.prescaler.write(|w| unsafe { w.prescaler().bits(7) });
 
-
-	//define the wake interval
-	rtc.cc[0].write(|w| unsafe { w.bits(
-		free(|cs| WAKE_INTERVAL.borrow(cs).get())
-	) });
-
-	//connect the interrupt event signal on compare0 match
-	rtc.intenset.write(|w| w.compare0().set_bit());
+	//only the overflow is always on, compare0 is armed per deadline
+	rtc.intenclr.write(|w| w.compare0().set_bit());
+	rtc.events_ovrflw.write(|w| unsafe { w.bits(0) });
+	rtc.intenset.write(|w| w.ovrflw().set_bit());
 
 	unsafe {
 		nrf52832_pac::NVIC::unpend(nrf52832_pac::Interrupt::RTC0);
@@ -106,8 +146,7 @@ fn configure(rtc: &nrf52832_pac::RTC0, clock: &nrf52832_pac::CLOCK) {
 	//Enable RTC
 	rtc.tasks_start.write(|w| unsafe { w.bits(1) });
 
-	free(|cs| SECONDS.borrow(cs).set(0));
-	free(|cs| FRACTION.borrow(cs).set(0));
+	free(|cs| OVERFLOWS.borrow(cs).set(0));
 }
 
 // =============================================================================
@@ -115,28 +154,22 @@ fn configure(rtc: &nrf52832_pac::RTC0, clock: &nrf52832_pac::CLOCK) {
 //==============================================================================
 #[interrupt]
 fn RTC0() {
-	let mut fraction: u32 = free(|cs| FRACTION.borrow(cs).get());
-	let mut seconds: u32 = free(|cs| SECONDS.borrow(cs).get());
-	let wake_interval: u32 = free(|cs| WAKE_INTERVAL.borrow(cs).get());
-
 	free(|cs| {
 		if let Some(ref mut rtc) = RTC_HANDLE.borrow(cs).borrow_mut().deref_mut() {
-			if rtc.events_compare[0].read().bits() > 0 {
-					fraction += wake_interval;
-			
-				if fraction >= WakeInterval::Interval1S as u32 {
-					seconds += fraction / WakeInterval::Interval1S as u32;
-					fraction = 0;
-				}
+			if rtc.events_ovrflw.read().bits() > 0 {
+				rtc.events_ovrflw.write(|w| unsafe { w.bits(0) });
+				OVERFLOWS.borrow(cs).set(OVERFLOWS.borrow(cs).get() + 1);
 			}
 
-			rtc.events_compare[0].write(|w| unsafe { w.bits(0) });
-			rtc.tasks_clear.write(|w| unsafe { w.bits(1) });
+			// Software timer deadline, re-armed below if anything is still due
+			if rtc.events_compare[0].read().bits() > 0 {
+				rtc.events_compare[0].write(|w| unsafe { w.bits(0) });
+				rtc.intenclr.write(|w| w.compare0().set_bit());
+			}
 		}
 	});
 
-	free(|cs| FRACTION.borrow(cs).set(fraction));
-	free(|cs| SECONDS.borrow(cs).set(seconds));
+	soft_timer::expire_handler();
 }
 
 //==============================================================================
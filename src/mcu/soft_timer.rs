@@ -0,0 +1,244 @@
+//==============================================================================
+// Notes
+//==============================================================================
+// mcu::soft_timer.rs
+// One-shot and periodic software timers on top of the RTC. Only the nearest
+// deadline is handed to the RTC, so the CPU sleeps until it is due. An expiry
+// calls back (in the RTC interrupt for a real time callback, otherwise from
+// the task handler) or, without a callback, is counted until the owner picks
+// it up with `take_expired`.
+
+//==============================================================================
+// Crates and Mods
+//==============================================================================
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use super::rtc::{self, Duration, Instant};
+
+//==============================================================================
+// Enums, Structs, and Types
+//==============================================================================
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum TimerMode {
+	OneShot,
+	Periodic
+}
+
+#[derive(Clone, Copy)]
+pub struct TimerConfig {
+	pub mode: TimerMode,
+	pub interval: Duration,
+	pub callback: Option<&'static (dyn Fn() + Sync)>,
+	pub real_time_callback: bool
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct TimerId {
+	index: usize,
+	generation: u32
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+pub enum SoftTimerError {
+	InvalidInterval,
+	NoFreeTimer
+}
+
+#[derive(Clone, Copy)]
+struct SoftTimer {
+	config: Option<TimerConfig>,
+	generation: u32,
+	running: bool,
+	deadline: Instant,
+	interval: Duration,
+	expired: u32
+}
+
+//==============================================================================
+// Variables
+//==============================================================================
+const SOFT_TIMER_LEN: usize = 12;
+
+static TIMERS: Mutex<RefCell<[SoftTimer; SOFT_TIMER_LEN]>> = Mutex::new(RefCell::new([
+	SoftTimer {
+		config: None,
+		generation: 0,
+		running: false,
+		deadline: Instant::from_ticks(0),
+		interval: Duration::ZERO,
+		expired: 0
+	};
+	SOFT_TIMER_LEN
+]));
+
+//==============================================================================
+// Public Functions
+//==============================================================================
+pub fn start(config: TimerConfig) -> Result<TimerId, SoftTimerError> {
+	if config.interval == Duration::ZERO {
+		return Err(SoftTimerError::InvalidInterval);
+	}
+
+	let id = free(|cs| {
+		let mut timers = TIMERS.borrow(cs).borrow_mut();
+		let index = timers.iter().position(|t| t.config.is_none())
+			.ok_or(SoftTimerError::NoFreeTimer)?;
+
+		let timer = &mut timers[index];
+		timer.config = Some(config);
+		timer.generation = timer.generation.wrapping_add(1);
+		timer.running = true;
+		timer.deadline = Instant::after(config.interval);
+		timer.interval = config.interval;
+		timer.expired = 0;
+
+		Ok(TimerId { index, generation: timer.generation })
+	})?;
+
+	schedule();
+	Ok(id)
+}
+
+#[allow(dead_code)]
+pub fn get_busy() -> bool {
+	// Callbacks waiting for the task handler
+	free(|cs| {
+		TIMERS.borrow(cs).borrow().iter().any(|t| match t.config {
+			Some(config) => t.expired > 0 && config.callback.is_some() && !config.real_time_callback,
+			None => false
+		})
+	})
+}
+
+#[allow(dead_code)]
+pub fn get_running(id: TimerId) -> bool {
+	free(|cs| {
+		let timers = TIMERS.borrow(cs).borrow();
+		timers[id.index].config.is_some() && timers[id.index].generation == id.generation &&
+			timers[id.index].running
+	})
+}
+
+#[allow(dead_code)]
+pub fn stop(id: TimerId) {
+	free(|cs| {
+		let mut timers = TIMERS.borrow(cs).borrow_mut();
+		if timers[id.index].generation == id.generation {
+			timers[id.index].config = None;
+			timers[id.index].running = false;
+		}
+	});
+
+	schedule();
+}
+
+#[allow(dead_code)]
+pub fn take_expired(id: TimerId) -> u32 {
+	// Number of expiries since the last call, a finished one-shot is released here
+	free(|cs| {
+		let mut timers = TIMERS.borrow(cs).borrow_mut();
+		let timer = &mut timers[id.index];
+		if timer.config.is_none() || timer.generation != id.generation {
+			return 0;
+		}
+
+		let expired = timer.expired;
+		timer.expired = 0;
+		if !timer.running {
+			timer.config = None;
+		}
+		expired
+	})
+}
+
+// Called by the RTC interrupt on every wake and at the armed deadline
+pub fn expire_handler() {
+	let now = Instant::now();
+	let mut callbacks: [Option<&'static (dyn Fn() + Sync)>; SOFT_TIMER_LEN] = [None; SOFT_TIMER_LEN];
+
+	free(|cs| {
+		let mut timers = TIMERS.borrow(cs).borrow_mut();
+		for (timer, callback) in timers.iter_mut().zip(callbacks.iter_mut()) {
+			let config = match timer.config {
+				Some(config) if timer.running && timer.deadline <= now => config,
+				_ => continue
+			};
+
+			// Periodic timers stay on their original grid, missed periods are counted
+			if config.mode == TimerMode::Periodic {
+				let late = now.duration_since(timer.deadline);
+				let periods = (late.as_ticks() / timer.interval.as_ticks()) + 1;
+				timer.deadline = timer.deadline + Duration::from_ticks(periods * timer.interval.as_ticks());
+				timer.expired = timer.expired.saturating_add(periods as u32);
+			}
+			else {
+				timer.running = false;
+				timer.expired = timer.expired.saturating_add(1);
+			}
+
+			if config.real_time_callback && config.callback.is_some() {
+				*callback = config.callback;
+				timer.expired = 0;
+				if !timer.running {
+					timer.config = None;
+				}
+			}
+		}
+	});
+
+	schedule();
+
+	for f in callbacks.iter().flatten() {
+		f();
+	}
+}
+
+//==============================================================================
+// Private Functions
+//==============================================================================
+fn schedule() {
+	let deadline = free(|cs| {
+		TIMERS.borrow(cs).borrow().iter()
+			.filter(|t| t.config.is_some() && t.running)
+			.map(|t| t.deadline)
+			.min()
+	});
+
+	rtc::set_deadline(deadline);
+}
+
+//==============================================================================
+// Interrupt Handler
+//==============================================================================
+
+
+//==============================================================================
+// Task Handler
+//==============================================================================
+pub fn task_handler() {
+	for index in 0..SOFT_TIMER_LEN {
+		let callback = free(|cs| {
+			let mut timers = TIMERS.borrow(cs).borrow_mut();
+			let timer = &mut timers[index];
+			match timer.config {
+				Some(config) if timer.expired > 0 && !config.real_time_callback => {
+					if config.callback.is_some() {
+						timer.expired = 0;
+						if !timer.running {
+							timer.config = None;
+						}
+					}
+					config.callback
+				},
+				_ => None
+			}
+		});
+
+		// Outside of the critical section, the callback may start or stop timers
+		if let Some(f) = callback {
+			f();
+		}
+	}
+}
@@ -9,9 +9,10 @@
 use core::cell::RefCell;
 use core::ops::DerefMut;
 use cortex_m::interrupt::{free, Mutex};
+use embedded_hal::blocking::spi::{Transfer, Write};
 use nrf52832_pac::spi0;
 use crate::config;
-use crate::mcu::gpio;
+use crate::mcu::{gpio, spim};
 use nrf52832_pac::p0::pin_cnf::DIR_A as DIR;
 use nrf52832_pac::p0::pin_cnf::PULL_A as PULL;
 
@@ -28,7 +29,12 @@ pub struct SpiLine{
 	pub cpol: spi0::config::CPOL_A
 }
 
+// embedded-hal handle for SPI0, chip select is left to the caller
 #[allow(dead_code)]
+pub struct Spi;
+
+#[allow(dead_code)]
+#[derive(Debug)]
 pub enum SpiError{
 	HANDLER,
 	RECEIVE, 
@@ -61,6 +67,9 @@ pub fn init(spi0: nrf52832_pac::SPI0) {
 }
 
 pub fn write(buf: &[u8]) -> Result<(), SpiError> {
+	// SPI0 and SPIM0 are the same peripheral, let queued DMA finish first
+	spim::wait_idle();
+
 	free(|cs| {
 		if let Some(ref mut spi) = SPI_HANDLE.borrow(cs).borrow_mut().deref_mut() {
 			spi.enable.write(|w| w.enable().enabled());
@@ -100,6 +109,31 @@ pub fn write(buf: &[u8]) -> Result<(), SpiError> {
 	})
 }
 
+#[allow(dead_code)]
+pub fn transfer(buf: &mut [u8]) -> Result<(), SpiError> {
+	spim::wait_idle();
+
+	free(|cs| {
+		if let Some(ref mut spi) = SPI_HANDLE.borrow(cs).borrow_mut().deref_mut() {
+			spi.enable.write(|w| w.enable().enabled());
+
+			// One byte at a time so every received byte lands in place
+			for byte in buf.iter_mut() {
+				tx_byte(spi, *byte)?;
+				*byte = rx_byte(spi)?;
+			}
+
+			spi.enable.write(|w| w.enable().disabled());
+			gpio::set_pin_state(config::SPI_SCLK_PIN, gpio::PinState::PinHigh);
+
+			Ok(())
+		}
+		else {
+			Err(SpiError::HANDLER)
+		}
+	})
+}
+
 #[allow(dead_code)]
 pub fn write_u16(data: u16, len: u32) -> Result<(), SpiError> {
 	// Build a single block and setup the DMA once
@@ -121,6 +155,23 @@ pub fn write_u16(data: u16, len: u32) -> Result<(), SpiError> {
 	Ok(())
 }
 
+impl Transfer<u8> for Spi {
+	type Error = SpiError;
+
+	fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], SpiError> {
+		transfer(words)?;
+		Ok(words)
+	}
+}
+
+impl Write<u8> for Spi {
+	type Error = SpiError;
+
+	fn write(&mut self, words: &[u8]) -> Result<(), SpiError> {
+		write(words)
+	}
+}
+
 //==============================================================================
 // Private Functions
 //==============================================================================
@@ -152,6 +203,7 @@ fn configure(spi: &nrf52832_pac::SPI0) {
 fn rx_byte(spi: &nrf52832_pac::SPI0) -> Result<u8, SpiError> {
 	// Wait for byte to be received
 	while spi.events_ready.read().bits() == 0 {}
+	spi.events_ready.write(|w| unsafe { w.bits(0) });
 
 	Ok(spi.rxd.read().bits() as u8)
 }
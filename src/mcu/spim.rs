@@ -1,15 +1,26 @@
 //==============================================================================
 // Notes
 //==============================================================================
-// mcu::spi.rs
+// mcu::spim.rs
+// `write_async` queues blocks for the display without waiting on them. Each
+// block is copied into one of the two DMA TX buffers so the next one can be
+// prepared while the other is on the wire, and the END interrupt starts the
+// queued block or releases chip select once there is nothing left to send.
+//
+// Waiting on the queue (flush, wait_idle, or write_async with both buffers
+// taken) polls the END event as well, so it also finishes when the interrupt
+// cannot run: inside a critical section or a handler of the same or higher
+// priority.
 
 //==============================================================================
 // Crates and Mods
 //==============================================================================
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use core::ops::DerefMut;
 use core::ptr;
-use cortex_m::interrupt::{free, Mutex};
+use cortex_m::interrupt::{free, CriticalSection, Mutex};
+use embedded_hal::blocking::spi::{Transfer, Write};
+use nrf52832_pac::interrupt;
 use nrf52832_pac::spim0;
 use crate::config;
 use crate::mcu::gpio;
@@ -29,12 +40,33 @@ pub struct SpiLine{
 	pub cpol: spim0::config::CPOL_A
 }
 
+// embedded-hal handle for SPIM0, chip select is left to the caller
+#[allow(dead_code)]
+pub struct Spim;
+
+#[derive(Clone, Copy, Debug)]
 pub enum SpimError{
 	Handler,
 	Receive, 
 	Transmit,
 }
 
+// EasyDMA buffer, word aligned for the DMA
+#[repr(C, align(4))]
+struct DmaBuffer([u8; config::SPIM_DMA_SIZE as usize]);
+
+#[derive(Clone, Copy)]
+struct DmaBlock {
+	ptr: u32,
+	len: u32
+}
+
+struct DmaQueue {
+	active: Option<DmaBlock>,
+	pending: Option<DmaBlock>,
+	cs_pin: Option<u8>
+}
+
 //==============================================================================
 // Variables
 //==============================================================================
@@ -48,9 +80,26 @@ const SPIM_LINE: SpiLine = SpiLine {
 	cpol: config::SPIM_CPOL,
 };
 
+// MAXCNT is 8 bits wide, which also keeps blocks inside the DMA buffers
+const SPIM_MAX_TRANSFER: usize = 255;
+
 static SPIM_HANDLE: Mutex<RefCell<Option<nrf52832_pac::SPIM0>>> = 
 	Mutex::new(RefCell::new(None));
 
+#[link_section = ".dma"]
+static mut DMA_RX: DmaBuffer = DmaBuffer([0; config::SPIM_DMA_SIZE as usize]);
+#[link_section = ".dma"]
+static mut DMA_TX_A: DmaBuffer = DmaBuffer([0; config::SPIM_DMA_SIZE as usize]);
+#[link_section = ".dma"]
+static mut DMA_TX_B: DmaBuffer = DmaBuffer([0; config::SPIM_DMA_SIZE as usize]);
+
+static DMA_QUEUE: Mutex<RefCell<DmaQueue>> = Mutex::new(RefCell::new(DmaQueue {
+	active: None,
+	pending: None,
+	cs_pin: None
+}));
+static DMA_ERROR: Mutex<Cell<Option<SpimError>>> = Mutex::new(Cell::new(None));
+
 //==============================================================================
 // Public Functions
 //==============================================================================
@@ -60,45 +109,94 @@ pub fn init(spim0: nrf52832_pac::SPIM0) {
 	free(|cs| SPIM_HANDLE.borrow(cs).replace(Some(spim0)));
 }
 
+#[allow(dead_code)]
+pub fn flush() -> Result<(), SpimError> {
+	wait_idle();
+
+	// Report anything that went wrong since the last flush
+	match free(|cs| DMA_ERROR.borrow(cs).take()) {
+		Some(e) => Err(e),
+		None => Ok(())
+	}
+}
+
+pub fn get_busy() -> bool {
+	free(|cs| DMA_QUEUE.borrow(cs).borrow().active.is_some())
+}
+
+// Returns once every queued block is out, leaving any error for flush
+pub fn wait_idle() {
+	while get_busy() {
+		free(handle_end);
+	}
+}
+
+// Blocks of any length are sent SPIM_MAX_TRANSFER bytes at a time
+#[allow(dead_code)]
+pub fn transfer(block: &mut [u8]) -> Result<(), SpimError> {
+	// The DMA buffers and the peripheral are shared with queued writes
+	flush()?;
+
+	for chunk in block.chunks_mut(SPIM_MAX_TRANSFER) {
+		let (tx_ptr, len) = make_ram(chunk);
+		start_transfer(tx_ptr, len)?;
+
+		// Copy what was clocked in back over what was sent
+		for (i, byte) in chunk.iter_mut().enumerate() {
+			*byte = unsafe { ptr::read_volatile((get_rx_ptr() as usize + i) as *const u8) };
+		}
+	}
+
+	Ok(())
+}
+
 #[allow(dead_code)]
 pub fn write(tx_block: &[u8]) -> Result<(), SpimError> {
-	let rx_ptr = config::SPIM_DMA_RX_PTR;
-	let (tx_ptr, len) = make_ram(tx_block);
+	flush()?;
 
-	free(|cs| {
-		if let Some(ref mut spim) = SPIM_HANDLE.borrow(cs).borrow_mut().deref_mut() {
-			spim.enable.write(|w| w.enable().enabled());
-
-			// Setup the DMA transfer
-			spim.txd.ptr.write(|w| unsafe { w.ptr().bits(tx_ptr) });
-			spim.txd.maxcnt.write(|w| 
-				unsafe { w.maxcnt().bits(len as u8) });
-			spim.rxd.ptr.write(|w| unsafe { w.ptr().bits(rx_ptr) });
-			spim.rxd.maxcnt.write(|w|
-				unsafe { w.maxcnt().bits(len as u8) });
-	
-			// Clear the end flag in case it wasn't properly cleared last time
-			spim.events_end.write(|w| unsafe { w.bits(0) });
+	for chunk in tx_block.chunks(SPIM_MAX_TRANSFER) {
+		let (tx_ptr, len) = make_ram(chunk);
+		start_transfer(tx_ptr, len)?;
+	}
 
-			// Start SPI transaction.
-			spim.tasks_start.write(|w| unsafe { w.bits(1) });
-	
-			// Wait for END event - triggered when tx and rx are done
-			while spim.events_end.read().bits() == 0 {}
-	
-			// Clear the end flag
-			spim.events_end.write(|w| unsafe { w.bits(0) });
-	
-			if spim.txd.amount.read().bits() != len {
-				return Err(SpimError::Transmit);
-			}
-			if spim.rxd.amount.read().bits() != len {
-				return Err(SpimError::Receive);
-			}
+	Ok(())
+}
 
-			spim.enable.write(|w| w.enable().disabled());
-			gpio::set_pin_state(config::SPI_SCLK_PIN, gpio::PinState::PinHigh);
+pub fn write_async(tx_block: &[u8], cs_pin: u8) -> Result<(), SpimError> {
+	if tx_block.len() > SPIM_MAX_TRANSFER {
+		return Err(SpimError::Transmit);
+	}
 
+	// Chip select is only released once the queue drains, so finish with
+	// whichever device was selected before starting on another
+	if free(|cs| DMA_QUEUE.borrow(cs).borrow().cs_pin) != Some(cs_pin) {
+		flush()?;
+	}
+
+	// Both buffers are taken, wait for the one on the wire to go out
+	while free(|cs| DMA_QUEUE.borrow(cs).borrow().pending.is_some()) {
+		free(handle_end);
+	}
+
+	let block = DmaBlock {
+		ptr: free(|cs| get_free_buffer(&DMA_QUEUE.borrow(cs).borrow())),
+		len: tx_block.len() as u32
+	};
+	copy_to_ram(tx_block, block.ptr);
+
+	free(|cs| {
+		let mut queue = DMA_QUEUE.borrow(cs).borrow_mut();
+		queue.cs_pin = Some(cs_pin);
+
+		if queue.active.is_some() {
+			queue.pending = Some(block);
+			Ok(())
+		}
+		else if let Some(ref mut spim) = SPIM_HANDLE.borrow(cs).borrow_mut().deref_mut() {
+			// The queue may have drained and released chip select since the last block
+			gpio::set_pin_state(cs_pin, gpio::PinState::PinLow);
+			start_dma(spim, block.ptr, block.len, 0, true);
+			queue.active = Some(block);
 			Ok(())
 		}
 		else {
@@ -107,6 +205,23 @@ pub fn write(tx_block: &[u8]) -> Result<(), SpimError> {
 	})
 }
 
+impl Transfer<u8> for Spim {
+	type Error = SpimError;
+
+	fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], SpimError> {
+		transfer(words)?;
+		Ok(words)
+	}
+}
+
+impl Write<u8> for Spim {
+	type Error = SpimError;
+
+	fn write(&mut self, words: &[u8]) -> Result<(), SpimError> {
+		write(words)
+	}
+}
+
 //==============================================================================
 // Private Functions
 //==============================================================================
@@ -135,41 +250,148 @@ fn configure(spim: &nrf52832_pac::SPIM0) {
 	// Ensure we are using the ArrayList structure
 	spim.rxd.list.write(|w| w.list().variant(nrf52832_pac::spim0::rxd::list::LIST_A::ARRAYLIST));
 	spim.txd.list.write(|w| w.list().variant(nrf52832_pac::spim0::txd::list::LIST_A::ARRAYLIST));
+
+	unsafe {
+		nrf52832_pac::NVIC::unpend(nrf52832_pac::Interrupt::SPIM0_SPIS0_TWIM0_TWIS0_SPI0_TWI0);
+		nrf52832_pac::NVIC::unmask(nrf52832_pac::Interrupt::SPIM0_SPIS0_TWIM0_TWIS0_SPI0_TWI0);
+	}
+}
+
+fn copy_to_ram(block: &[u8], ptr: u32) {
+	unsafe { for (i, byte) in block.iter().enumerate() {
+		ptr::write((ptr as usize + i) as *mut u8, *byte);
+	} }
+}
+
+fn get_free_buffer(queue: &DmaQueue) -> u32 {
+	// Only called with no block pending, so at most one buffer is in use
+	match queue.active {
+		Some(block) if block.ptr == get_tx_ptr_a() => get_tx_ptr_b(),
+		_ => get_tx_ptr_a()
+	}
+}
+
+fn get_rx_ptr() -> u32 {
+	ptr::addr_of!(DMA_RX) as u32
+}
+
+fn get_tx_ptr_a() -> u32 {
+	ptr::addr_of!(DMA_TX_A) as u32
+}
+
+fn get_tx_ptr_b() -> u32 {
+	ptr::addr_of!(DMA_TX_B) as u32
 }
 
-fn get_tx_ptr() -> u32 {
-	static mut LAST_PTR: bool = true;
-	unsafe { 
-		if LAST_PTR {
-			LAST_PTR = false;
-			config::SPIM_DMA_TX_PTR_A
+fn start_dma(spim: &nrf52832_pac::SPIM0, tx_ptr: u32, tx_len: u32, rx_len: u32, interrupt: bool) {
+	// Callers split blocks so they fit MAXCNT and the RAM buffers
+	debug_assert!(tx_len as usize <= SPIM_MAX_TRANSFER && rx_len as usize <= SPIM_MAX_TRANSFER);
+
+	spim.enable.write(|w| w.enable().enabled());
+
+	// Setup the DMA transfer
+	spim.txd.ptr.write(|w| unsafe { w.ptr().bits(tx_ptr) });
+	spim.txd.maxcnt.write(|w| 
+		unsafe { w.maxcnt().bits(tx_len as u8) });
+	spim.rxd.ptr.write(|w| unsafe { w.ptr().bits(get_rx_ptr()) });
+	spim.rxd.maxcnt.write(|w|
+		unsafe { w.maxcnt().bits(rx_len as u8) });
+
+	// Clear the end flag in case it wasn't properly cleared last time
+	spim.events_end.write(|w| unsafe { w.bits(0) });
+
+	if interrupt {
+		spim.intenset.write(|w| w.end().set_bit());
+	}
+	else {
+		spim.intenclr.write(|w| w.end().set_bit());
+	}
+
+	// Start SPI transaction.
+	spim.tasks_start.write(|w| unsafe { w.bits(1) });
+}
+
+fn start_transfer(tx_ptr: u32, len: u32) -> Result<(), SpimError> {
+	free(|cs| {
+		if let Some(ref mut spim) = SPIM_HANDLE.borrow(cs).borrow_mut().deref_mut() {
+			start_dma(spim, tx_ptr, len, len, false);
+	
+			// Wait for END event - triggered when tx and rx are done
+			while spim.events_end.read().bits() == 0 {}
+	
+			// Clear the end flag
+			spim.events_end.write(|w| unsafe { w.bits(0) });
+	
+			if spim.txd.amount.read().bits() != len {
+				return Err(SpimError::Transmit);
+			}
+			if spim.rxd.amount.read().bits() != len {
+				return Err(SpimError::Receive);
+			}
+
+			stop_dma(spim);
+
+			Ok(())
 		}
 		else {
-			LAST_PTR = false;
-			config::SPIM_DMA_TX_PTR_B
+			Err(SpimError::Handler)
 		}
-	}
+	})
 }
 
-fn is_block_valid(ptr: u32) -> bool {
-	if (ptr >= config::SPIM_DMA_MIN) && 
-		(ptr < (config::SPIM_DMA_MAX - config::SPIM_DMA_SIZE)) {
-		true
+fn stop_dma(spim: &nrf52832_pac::SPIM0) {
+	spim.intenclr.write(|w| w.end().set_bit());
+	spim.enable.write(|w| w.enable().disabled());
+	gpio::set_pin_state(config::SPI_SCLK_PIN, gpio::PinState::PinHigh);
+}
+
+// EasyDMA can only reach data RAM, and the whole block must be in it
+fn is_block_valid(ptr: u32, len: u32) -> bool {
+	match ptr.checked_add(len) {
+		Some(end) => (ptr >= config::SPIM_DMA_MIN) && (end <= config::SPIM_DMA_MAX),
+		None => false
 	}
-	else {
-		false
+}
+
+// Moves the queue on once the block on the wire is out. Called from the END
+// interrupt and polled by anything waiting on the queue, whichever sees the
+// event first handles it.
+fn handle_end(cs: &CriticalSection) {
+	if let Some(ref mut spim) = SPIM_HANDLE.borrow(cs).borrow_mut().deref_mut() {
+		if spim.events_end.read().bits() == 0 {
+			return;
+		}
+		spim.events_end.write(|w| unsafe { w.bits(0) });
+
+		let mut queue = DMA_QUEUE.borrow(cs).borrow_mut();
+		if let Some(block) = queue.active.take() {
+			if spim.txd.amount.read().bits() != block.len {
+				DMA_ERROR.borrow(cs).set(Some(SpimError::Transmit));
+			}
+		}
+
+		// Keep the bus busy with the other buffer if it is ready
+		if let Some(block) = queue.pending.take() {
+			start_dma(spim, block.ptr, block.len, 0, true);
+			queue.active = Some(block);
+		}
+		else {
+			stop_dma(spim);
+			if let Some(pin) = queue.cs_pin {
+				gpio::set_pin_state(pin, gpio::PinState::PinHigh);
+			}
+		}
 	}
 }
 
+// Only called with blocks of at most SPIM_MAX_TRANSFER bytes
 fn make_ram(block: &[u8]) -> (u32, u32) {
 	let len = block.len();
 
-	if !is_block_valid(block.as_ptr() as u32) {
-		let ptr = get_tx_ptr() as usize;
-		unsafe { for i in 0..len {
-			ptr::write((ptr+i) as *mut u8, block[i]);
-		} }
-		(ptr as u32, len as u32)
+	if !is_block_valid(block.as_ptr() as u32, len as u32) {
+		// Only used once the queue is flushed, so either buffer is free
+		copy_to_ram(block, get_tx_ptr_a());
+		(get_tx_ptr_a(), len as u32)
 	}
 	else {
 		(block.as_ptr() as u32, len as u32)
@@ -179,6 +401,10 @@ fn make_ram(block: &[u8]) -> (u32, u32) {
 //==============================================================================
 // Interrupt Handler
 //==============================================================================
+#[interrupt]
+fn SPIM0_SPIS0_TWIM0_TWIS0_SPI0_TWI0() {
+	free(handle_end);
+}
 
 
 //==============================================================================
@@ -11,11 +11,15 @@
 use core::cell::{Cell, RefCell};
 use core::ops::DerefMut;
 use cortex_m::interrupt::{free, Mutex};
+use embedded_hal::blocking::delay::DelayMs;
 use nrf52832_pac::interrupt;
 
 //==============================================================================
 // Enums, Structs, and Types
 //==============================================================================
+// embedded-hal handle for the blocking millisecond delay
+#[allow(dead_code)]
+pub struct Delay;
 
 
 //==============================================================================
@@ -60,6 +64,24 @@ pub fn delay(milliseconds: u32) {
 	stop();
 }
 
+impl DelayMs<u32> for Delay {
+	fn delay_ms(&mut self, ms: u32) {
+		delay(ms);
+	}
+}
+
+impl DelayMs<u16> for Delay {
+	fn delay_ms(&mut self, ms: u16) {
+		delay(ms.into());
+	}
+}
+
+impl DelayMs<u8> for Delay {
+	fn delay_ms(&mut self, ms: u8) {
+		delay(ms.into());
+	}
+}
+
 //==============================================================================
 // Private Functions
 //==============================================================================